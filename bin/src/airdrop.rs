@@ -0,0 +1,161 @@
+use serde_json::{json, Value};
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+use std::str::FromStr;
+use token_launch::merkle::{hash_leaf, hash_pair};
+
+/// One recipient of an airdrop
+pub struct AirdropEntry {
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+/// Merkle tree over airdrop entries, leaf index = position in the CSV
+pub struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    pub fn new(entries: &[AirdropEntry]) -> Self {
+        let leaves: Vec<[u8; 32]> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| hash_leaf(index as u64, &entry.wallet, entry.amount))
+            .collect();
+
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            // An unpaired node is carried up to the next layer unchanged
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        MerkleTree { layers }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last().unwrap()[0]
+    }
+
+    pub fn proof(&self, mut index: usize) -> Vec<[u8; 32]> {
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+/// Parses `wallet,amount` lines, with an optional header row
+pub fn parse_csv(contents: &str) -> Result<Vec<AirdropEntry>, String> {
+    let mut entries = Vec::new();
+
+    for (line_number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut fields = line.split(',').map(str::trim);
+        let wallet = fields.next().unwrap_or_default();
+        let amount = fields.next().unwrap_or_default();
+
+        let wallet = match Pubkey::from_str(wallet) {
+            Ok(wallet) => wallet,
+            Err(_) if line_number == 0 => continue,
+            Err(_) => return Err(format!("line {}: invalid wallet '{}'", line_number + 1, wallet)),
+        };
+        let amount = amount
+            .parse::<u64>()
+            .map_err(|_| format!("line {}: invalid amount '{}'", line_number + 1, amount))?;
+
+        entries.push(AirdropEntry { wallet, amount });
+    }
+
+    if entries.is_empty() {
+        return Err("CSV contains no entries".to_string());
+    }
+
+    Ok(entries)
+}
+
+/// Proofs file handed out to claimants, hashes are base58 encoded
+pub fn proofs_to_json(tree: &MerkleTree, entries: &[AirdropEntry]) -> Value {
+    let claims: Vec<Value> = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let proof: Vec<String> = tree
+                .proof(index)
+                .iter()
+                .map(|node| Hash::new_from_array(*node).to_string())
+                .collect();
+
+            json!({
+                "index": index,
+                "wallet": entry.wallet.to_string(),
+                "amount": entry.amount,
+                "proof": proof,
+            })
+        })
+        .collect();
+
+    json!({
+        "merkle_root": Hash::new_from_array(tree.root()).to_string(),
+        "total_amount": entries.iter().map(|entry| entry.amount).sum::<u64>(),
+        "num_leaves": entries.len(),
+        "claims": claims,
+    })
+}
+
+/// Reads a base58 hash out of a proofs file value
+pub fn parse_hash(value: &Value) -> Result<[u8; 32], Box<dyn std::error::Error>> {
+    let hash = value.as_str().ok_or("Expected a base58 hash")?;
+    Ok(Hash::from_str(hash)?.to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use token_launch::merkle::verify_proof;
+
+    #[test]
+    fn test_every_proof_verifies() {
+        for size in 1..10 {
+            let entries: Vec<AirdropEntry> = (0..size)
+                .map(|i| AirdropEntry { wallet: Pubkey::new_unique(), amount: 100 * (i + 1) })
+                .collect();
+            let tree = MerkleTree::new(&entries);
+
+            for (index, entry) in entries.iter().enumerate() {
+                let leaf = hash_leaf(index as u64, &entry.wallet, entry.amount);
+                assert!(verify_proof(&tree.proof(index), &tree.root(), leaf), "size {} index {}", size, index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let wallet = Pubkey::new_unique();
+        let contents = format!("wallet,amount\n{},500\n\n{}, 7\n", wallet, wallet);
+
+        let entries = parse_csv(&contents).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].amount, 500);
+        assert_eq!(entries[1].amount, 7);
+
+        assert!(parse_csv(&format!("{},abc", wallet)).is_err());
+        assert!(parse_csv("wallet,amount\nnot-a-wallet,5").is_err());
+    }
+}
@@ -0,0 +1,606 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_sdk::{hash::hash, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use token_launch::util::{get_deterministic_mint_pda, get_launched_asset_pda};
+use token_launch_client::{ClientError, LaunchOptions, LaunchpadClient, Transport};
+use tokio::time::{interval, Interval, MissedTickBehavior};
+
+use crate::error::{invalid, CliError, ErrorKind, Failure};
+use crate::launch_file::{LaunchFile, LaunchSpec, Origin};
+use crate::output::Report;
+
+/// Keys a manifest column can set, the cluster and fee payer are shared by the whole batch
+const MANIFEST_COLUMNS: [&str; 13] = [
+    "type",
+    "name",
+    "symbol",
+    "decimals",
+    "supply",
+    "max-supply",
+    "uri",
+    "mutable",
+    "unique-symbol",
+    "deterministic-mint",
+    "emission.per-epoch",
+    "emission.treasury",
+    "authorities.creator",
+];
+
+/// A manifest row, tied to its journal entries by line number and checked against them by fingerprint
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestRow {
+    pub line: usize,
+    pub fingerprint: String,
+    pub launch: LaunchFile,
+}
+
+/// Parses a manifest whose header names each column's launch config key, empty cells are left unset
+pub fn parse_manifest(path: &Path, contents: &str) -> Result<Vec<ManifestRow>, CliError> {
+    let mut lines = contents.lines().enumerate().filter(|(_, text)| !text.trim().is_empty());
+    let (_, header) = lines.next().ok_or_else(|| invalid(format!("{} is empty", path.display())))?;
+    let header = split_csv_line(header).map_err(|e| invalid(format!("{} header: {}", path.display(), e)))?;
+    for (index, column) in header.iter().enumerate() {
+        if !MANIFEST_COLUMNS.contains(&column.as_str()) {
+            return Err(invalid(format!("{}: unknown column `{}`, expected {}", path.display(), column, MANIFEST_COLUMNS.join(", "))));
+        }
+        if header[..index].contains(column) {
+            return Err(invalid(format!("{}: column `{}` appears twice", path.display(), column)));
+        }
+    }
+
+    let mut rows = Vec::new();
+    for (index, text) in lines {
+        let line = index + 1;
+        let fields = split_csv_line(text).map_err(|e| invalid(format!("line {} of {}: {}", line, path.display(), e)))?;
+        if fields.len() != header.len() {
+            return Err(invalid(format!("line {} of {}: {} fields, the header has {}", line, path.display(), fields.len(), header.len())));
+        }
+
+        let mut cells: Vec<(&str, &str)> = header.iter().map(String::as_str).zip(fields.iter().map(String::as_str)).filter(|(_, value)| !value.is_empty()).collect();
+        let mut launch = LaunchFile::default();
+        for (column, value) in &cells {
+            launch.set(column, value).map_err(|e| invalid(format!("`{}` on line {} of {}: {}", column, line, path.display(), e)))?;
+        }
+
+        // Column order and empty columns don't change what a row launches
+        cells.sort();
+        let canonical: String = cells.iter().map(|(column, value)| format!("{}={}\n", column, value)).collect();
+        rows.push(ManifestRow { line, fingerprint: hash(canonical.as_bytes()).to_string(), launch });
+    }
+
+    if rows.is_empty() {
+        return Err(invalid(format!("{} has no rows", path.display())));
+    }
+    Ok(rows)
+}
+
+// Splits a CSV line, quoted fields may hold commas and "" for a quote
+fn split_csv_line(text: &str) -> Result<Vec<String>, String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let field = fields.last_mut().unwrap();
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            }
+            ',' if !quoted => fields.push(String::new()),
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err("unterminated quote".to_string());
+    }
+    Ok(fields.into_iter().map(|field| field.trim().to_string()).collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// Written before the launch is sent, so an interrupted run knows which mint to look for
+    Pending,
+    Confirmed,
+    Failed,
+}
+
+/// A line of the journal, the last one for a manifest line is that row's state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub line: usize,
+    pub fingerprint: String,
+    pub status: Status,
+    pub mint: Option<String>,
+    /// Secret key of a generated mint in keypair file form, reused by retries so a launch can't land twice
+    pub mint_keypair: Option<Vec<u8>>,
+    pub launched_asset: Option<String>,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+}
+
+impl JournalEntry {
+    fn new(row: &ManifestRow, status: Status) -> Self {
+        JournalEntry { line: row.line, fingerprint: row.fingerprint.clone(), status, mint: None, mint_keypair: None, launched_asset: None, signature: None, error: None }
+    }
+}
+
+/// Journal next to the manifest, `items.csv` journals to `items.csv.journal.jsonl`
+pub fn default_journal(manifest: &Path) -> PathBuf {
+    let mut path = manifest.as_os_str().to_owned();
+    path.push(".journal.jsonl");
+    PathBuf::from(path)
+}
+
+/// Latest entry of every manifest line in the journal at `path`, empty when it doesn't exist yet
+pub fn read_journal(path: &Path) -> Result<HashMap<usize, JournalEntry>, CliError> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => parse_journal(&contents).map_err(|e| invalid(format!("{}: {}", path.display(), e))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(e) => Err(invalid(format!("Failed to read {}: {}", path.display(), e))),
+    }
+}
+
+fn parse_journal(contents: &str) -> Result<HashMap<usize, JournalEntry>, String> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let mut entries = HashMap::new();
+    for (index, text) in lines.iter().enumerate() {
+        if text.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<JournalEntry>(text) {
+            Ok(entry) => {
+                entries.insert(entry.line, entry);
+            }
+            // A run killed mid-write cuts off its last line, the row's earlier entry still names the mint to look for
+            Err(_) if index + 1 == lines.len() => {}
+            Err(e) => return Err(format!("line {}: {}", index + 1, e)),
+        }
+    }
+    Ok(entries)
+}
+
+/// Append-only journal, every entry is synced to disk before the launch it describes goes out
+pub struct Journal {
+    file: Mutex<File>,
+}
+
+impl Journal {
+    pub fn open(path: &Path) -> Result<Self, CliError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| invalid(format!("Failed to open {}: {}", path.display(), e)))?;
+
+        // Start on a fresh line after a cut off entry
+        let contents = std::fs::read(path).map_err(|e| invalid(format!("Failed to read {}: {}", path.display(), e)))?;
+        if contents.last().is_some_and(|last| *last != b'\n') {
+            writeln!(file).map_err(|e| invalid(format!("Failed to write {}: {}", path.display(), e)))?;
+        }
+        Ok(Journal { file: Mutex::new(file) })
+    }
+
+    pub fn record(&self, entry: &JournalEntry) -> std::io::Result<()> {
+        let mut file = self.file.lock().unwrap();
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        file.sync_data()
+    }
+}
+
+/// A row to launch, resolved and checked before anything is sent
+#[derive(Debug)]
+pub struct BatchItem {
+    pub row: ManifestRow,
+    pub spec: LaunchSpec,
+    /// Journal entry of an earlier run that didn't confirm
+    pub previous: Option<JournalEntry>,
+}
+
+#[derive(Debug, Default)]
+pub struct BatchPlan {
+    pub items: Vec<BatchItem>,
+    /// Rows an earlier run already launched
+    pub confirmed: Vec<JournalEntry>,
+}
+
+/// Resolves every row over the shared `layers` and sorts out what the journal says is already launched
+pub fn plan(manifest: &Path, rows: Vec<ManifestRow>, layers: &[(LaunchFile, Origin)], journal: &HashMap<usize, JournalEntry>) -> Result<BatchPlan, CliError> {
+    let mut plan = BatchPlan::default();
+    for row in rows {
+        let previous = journal.get(&row.line).cloned();
+        if let Some(previous) = previous.as_ref().filter(|previous| previous.status == Status::Confirmed) {
+            if previous.fingerprint != row.fingerprint {
+                return Err(invalid(format!(
+                    "line {} of {} changed since it was launched as {}, keep launched rows as they are and add new ones at the end",
+                    row.line,
+                    manifest.display(),
+                    previous.mint.as_deref().unwrap_or("an unrecorded mint"),
+                )));
+            }
+            plan.confirmed.push(previous.clone());
+            continue;
+        }
+
+        let mut row_layers = vec![(row.launch.clone(), Origin::Row(manifest.to_path_buf(), row.line))];
+        row_layers.extend_from_slice(layers);
+        let spec = LaunchSpec::resolve(&row_layers)?;
+        if spec.fee_payer.is_some() {
+            return Err(invalid("launch-batch sends every launch itself, remove `authorities.fee-payer` from the config file"));
+        }
+        plan.items.push(BatchItem { row, spec, previous });
+    }
+    Ok(plan)
+}
+
+/// How a row's launch went in this run
+#[derive(Debug)]
+pub enum Outcome {
+    Launched,
+    /// An earlier run's launch had landed without being journaled as confirmed
+    Recovered,
+    Failed(Failure),
+}
+
+/// Sends the planned launches, at most `concurrency` at once and `rate` per second
+pub struct Launcher<'a, T: Transport> {
+    client: &'a LaunchpadClient<T, Keypair>,
+    journal: &'a Journal,
+    // Launch indexes are handed out in order, so a creator's launches can't overlap
+    creator_turns: HashMap<Pubkey, tokio::sync::Mutex<()>>,
+    limiter: tokio::sync::Mutex<Interval>,
+}
+
+impl<'a, T: Transport> Launcher<'a, T> {
+    pub fn new(client: &'a LaunchpadClient<T, Keypair>, journal: &'a Journal, rate: f64) -> Self {
+        let mut limiter = interval(Duration::from_secs_f64(1.0 / rate));
+        limiter.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Launcher { client, journal, creator_turns: HashMap::new(), limiter: tokio::sync::Mutex::new(limiter) }
+    }
+
+    /// Launches `items`, returning each one's final journal entry and outcome in manifest order
+    pub async fn launch_all(mut self, items: Vec<BatchItem>, concurrency: usize) -> Vec<(JournalEntry, Outcome)> {
+        for item in &items {
+            self.creator_turns.entry(self.creator(item).pubkey()).or_default();
+        }
+
+        let items = interleave(items, |item| self.creator(item).pubkey());
+        let mut results: Vec<(JournalEntry, Outcome)> = stream::iter(&items).map(|item| self.launch(item)).buffer_unordered(concurrency).collect().await;
+        results.sort_by_key(|(entry, _)| entry.line);
+        results
+    }
+
+    fn creator<'b>(&'b self, item: &'b BatchItem) -> &'b Keypair {
+        item.spec.creator.as_ref().unwrap_or(self.client.payer())
+    }
+
+    async fn launch(&self, item: &BatchItem) -> (JournalEntry, Outcome) {
+        let creator = self.creator(item);
+        let _turn = self.creator_turns[&creator.pubkey()].lock().await;
+
+        match self.try_launch(item, creator).await {
+            Ok(result) => result,
+            // Failed before sending, keep what the last attempt recorded so its mint is still checked next time
+            Err(error) => {
+                let failure = Failure::from_error(error.as_ref());
+                let mut entry = item.previous.clone().unwrap_or_else(|| JournalEntry::new(&item.row, Status::Failed));
+                entry.fingerprint = item.row.fingerprint.clone();
+                entry.status = Status::Failed;
+                entry.error = Some(failure.message.clone());
+                let _ = self.journal.record(&entry);
+                (entry, Outcome::Failed(failure))
+            }
+        }
+    }
+
+    async fn try_launch(&self, item: &BatchItem, creator: &Keypair) -> Result<(JournalEntry, Outcome), Box<dyn std::error::Error>> {
+        let program_id = *self.client.program_id();
+
+        // An interrupted or timed out launch may have landed, its recorded mint tells
+        if let Some(mint) = item.previous.as_ref().and_then(|previous| previous.mint.as_ref()) {
+            match self.client.fetch_asset(&Pubkey::from_str(mint)?).await {
+                Ok(_) => {
+                    let mut entry = item.previous.clone().unwrap();
+                    entry.status = Status::Confirmed;
+                    entry.error = None;
+                    self.journal.record(&entry)?;
+                    return Ok((entry, Outcome::Recovered));
+                }
+                Err(ClientError::AccountNotFound(_)) => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        let config = item.spec.launch_config(creator.pubkey());
+        let mint_keypair = match item.previous.as_ref().and_then(|previous| previous.mint_keypair.as_ref()) {
+            _ if config.deterministic_mint => None,
+            Some(bytes) => Some(Keypair::from_bytes(bytes)?),
+            None => Some(Keypair::new()),
+        };
+        // Holding the creator's turn, the next launch index is the one this launch gets
+        let mint = match &mint_keypair {
+            Some(mint_keypair) => mint_keypair.pubkey(),
+            None => get_deterministic_mint_pda(&program_id, &creator.pubkey(), self.client.next_launch_index(&creator.pubkey()).await?).0,
+        };
+
+        let mut entry = JournalEntry::new(&item.row, Status::Pending);
+        entry.mint = Some(mint.to_string());
+        entry.mint_keypair = mint_keypair.as_ref().map(|mint_keypair| mint_keypair.to_bytes().to_vec());
+        entry.launched_asset = Some(get_launched_asset_pda(&program_id, &mint).0.to_string());
+        self.journal.record(&entry)?;
+
+        self.limiter.lock().await.tick().await;
+        let options = LaunchOptions { creator: Some(creator), relayer: None, mint: mint_keypair.as_ref() };
+        let outcome = match self.client.launch(config, options).await {
+            Ok(result) => {
+                entry.status = Status::Confirmed;
+                entry.signature = result.signature().map(|signature| signature.to_string());
+                Outcome::Launched
+            }
+            Err(error) => {
+                let failure = Failure::from_error(&error);
+                entry.status = Status::Failed;
+                entry.error = Some(failure.message.clone());
+                Outcome::Failed(failure)
+            }
+        };
+        self.journal.record(&entry)?;
+        Ok((entry, outcome))
+    }
+}
+
+// Round-robin over the groups `key` puts items in, keeping each group's order
+fn interleave<T, K: PartialEq>(items: Vec<T>, key: impl Fn(&T) -> K) -> Vec<T> {
+    let mut groups: Vec<(K, VecDeque<T>)> = Vec::new();
+    for item in items {
+        let item_key = key(&item);
+        match groups.iter_mut().find(|(group_key, _)| *group_key == item_key) {
+            Some((_, group)) => group.push_back(item),
+            None => groups.push((item_key, VecDeque::from([item]))),
+        }
+    }
+
+    let mut ordered = Vec::new();
+    while !groups.is_empty() {
+        for (_, group) in &mut groups {
+            ordered.extend(group.pop_front());
+        }
+        groups.retain(|(_, group)| !group.is_empty());
+    }
+    ordered
+}
+
+/// Counts of the run and every row's state, the command exits as partly failed when any launch failed
+pub fn summary(confirmed: &[JournalEntry], results: &[(JournalEntry, Outcome)], journal: &Path) -> Report {
+    let count = |wanted: fn(&Outcome) -> bool| results.iter().filter(|(_, outcome)| wanted(outcome)).count();
+    let launched = count(|outcome| matches!(outcome, Outcome::Launched));
+    let recovered = count(|outcome| matches!(outcome, Outcome::Recovered));
+    let failures: Vec<(&JournalEntry, &Failure)> = results
+        .iter()
+        .filter_map(|(entry, outcome)| match outcome {
+            Outcome::Failed(failure) => Some((entry, failure)),
+            _ => None,
+        })
+        .collect();
+
+    let mut report = Report::new();
+    report.line(format!(
+        "Launched {}, recovered {}, skipped {} already launched, failed {} of {} rows",
+        launched,
+        recovered,
+        confirmed.len(),
+        failures.len(),
+        confirmed.len() + results.len()
+    ));
+    for (entry, failure) in &failures {
+        report.line(format!("Line {}: {}", entry.line, failure.message));
+    }
+    report.insert("launched", launched);
+    report.insert("recovered", recovered);
+    report.insert("skipped", confirmed.len());
+    report.insert("failed", failures.len());
+    report.field("Journal", "journal", journal.display().to_string());
+
+    let mut rows: Vec<(&JournalEntry, &str)> = confirmed.iter().map(|entry| (entry, "skipped")).collect();
+    rows.extend(results.iter().map(|(entry, outcome)| {
+        let status = match outcome {
+            Outcome::Launched => "launched",
+            Outcome::Recovered => "recovered",
+            Outcome::Failed(_) => "failed",
+        };
+        (entry, status)
+    }));
+    rows.sort_by_key(|(entry, _)| entry.line);
+    let rows: Vec<_> = rows
+        .iter()
+        .map(|(entry, status)| {
+            json!({
+                "line": entry.line,
+                "status": status,
+                "mint": entry.mint,
+                "launched_asset": entry.launched_asset,
+                "signature": entry.signature,
+                "error": entry.error,
+            })
+        })
+        .collect();
+    report.insert("rows", rows);
+
+    if let Some((_, first)) = failures.first() {
+        report.line("Re-run the same command to retry the failed rows");
+        let kind = match failures.iter().all(|(_, failure)| failure.kind == first.kind) {
+            true => first.kind,
+            false => ErrorKind::Other,
+        };
+        report.partial(kind);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program_test::{processor, BanksClient, ProgramTest};
+    use token_launch::entrypoint::process_instruction;
+
+    const MANIFEST: &str = "type,name,symbol,supply,authorities.creator\n\
+        spl-legacy,\"Batch, Token\",BT1,1000,\n\
+        \n\
+        nft, \"Say \"\"hi\"\"\" ,BT2,,\n";
+
+    fn manifest() -> Vec<ManifestRow> {
+        parse_manifest(Path::new("items.csv"), MANIFEST).unwrap()
+    }
+
+    // Shared layer standing in for the flag defaults
+    fn layers() -> Vec<(LaunchFile, Origin)> {
+        let mut defaults = LaunchFile::default();
+        for (key, value) in [("name", "MyCliToken"), ("symbol", "CLI"), ("decimals", "6"), ("supply", "1000000"), ("uri", "https://example.com/metadata.json"), ("cluster.rpc-url", "http://localhost:8899"), ("cluster.program-id", &token_launch::id().to_string())] {
+            defaults.set(key, value).unwrap();
+        }
+        vec![(defaults, Origin::Default)]
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let rows = manifest();
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].line, rows[1].line), (2, 4));
+        assert_eq!(rows[0].launch.name.as_deref(), Some("Batch, Token"));
+        assert_eq!(rows[0].launch.supply, Some(1_000));
+        assert_eq!(rows[1].launch.name.as_deref(), Some("Say \"hi\""));
+        assert_eq!(rows[1].launch.supply, None);
+        assert_eq!(rows[1].launch.authorities.creator, None);
+
+        // Reordering columns keeps fingerprints, editing a row changes its own
+        let reordered = parse_manifest(Path::new("items.csv"), "symbol,type,supply,name\nBT1,spl-legacy,1000,\"Batch, Token\"\nBT3,nft,,x\n").unwrap();
+        assert_eq!(reordered[0].fingerprint, rows[0].fingerprint);
+        assert_ne!(reordered[1].fingerprint, rows[1].fingerprint);
+    }
+
+    #[test]
+    fn test_manifest_errors() {
+        let error = parse_manifest(Path::new("items.csv"), "type,amount\nnft,1\n").unwrap_err();
+        assert!(error.to_string().starts_with("items.csv: unknown column `amount`"));
+
+        let error = parse_manifest(Path::new("items.csv"), "type,decimals\nnft,0\nnft,many\n").unwrap_err();
+        assert!(error.to_string().starts_with("`decimals` on line 3 of items.csv: invalid value \"many\""));
+
+        let error = parse_manifest(Path::new("items.csv"), "type,name\nnft\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2 of items.csv: 1 fields, the header has 2");
+
+        let error = parse_manifest(Path::new("items.csv"), "type,name\nnft,\"open\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2 of items.csv: unterminated quote");
+    }
+
+    #[test]
+    fn test_plan_resumes_from_journal() {
+        let rows = manifest();
+        let mut confirmed = JournalEntry::new(&rows[0], Status::Confirmed);
+        confirmed.mint = Some(Pubkey::new_unique().to_string());
+        let failed = JournalEntry::new(&rows[1], Status::Failed);
+        let journal = HashMap::from([(2, confirmed.clone()), (4, failed.clone())]);
+
+        let batch = plan(Path::new("items.csv"), rows.clone(), &layers(), &journal).unwrap();
+        assert_eq!(batch.confirmed, vec![confirmed.clone()]);
+        assert_eq!(batch.items.len(), 1);
+        assert_eq!(batch.items[0].previous, Some(failed));
+        assert_eq!((batch.items[0].spec.decimals, batch.items[0].spec.total_supply), (0, 1));
+
+        // A launched row can't be edited, its line would then launch something else
+        confirmed.fingerprint = "edited".to_string();
+        let error = plan(Path::new("items.csv"), rows.clone(), &layers(), &HashMap::from([(2, confirmed)])).unwrap_err();
+        assert!(error.to_string().starts_with("line 2 of items.csv changed since it was launched"));
+
+        let mut nft = rows[1].clone();
+        nft.launch.decimals = Some(2);
+        let error = plan(Path::new("items.csv"), vec![nft], &layers(), &HashMap::new()).unwrap_err();
+        assert_eq!(error.to_string(), "`decimals` on line 4 of items.csv: NFTs have 0 decimals");
+    }
+
+    #[test]
+    fn test_parse_journal() {
+        let rows = manifest();
+        let pending = JournalEntry::new(&rows[0], Status::Pending);
+        let confirmed = JournalEntry::new(&rows[0], Status::Confirmed);
+        let failed = JournalEntry::new(&rows[1], Status::Failed);
+        let line = |entry: &JournalEntry| serde_json::to_string(entry).unwrap();
+
+        let contents = format!("{}\n{}\n{}\n{{\"line\":4,\"fing", line(&pending), line(&failed), line(&confirmed));
+        let entries = parse_journal(&contents).unwrap();
+        assert_eq!(entries[&2], confirmed);
+        assert_eq!(entries[&4], failed);
+
+        let contents = format!("{{\"line\":4,\"fing\n{}\n", line(&pending));
+        assert!(parse_journal(&contents).unwrap_err().starts_with("line 1:"));
+    }
+
+    #[test]
+    fn test_interleave() {
+        let items = vec![("a", 1), ("a", 2), ("b", 3), ("a", 4), ("c", 5), ("b", 6)];
+        let ordered: Vec<i32> = interleave(items, |(group, _)| *group).into_iter().map(|(_, item)| item).collect();
+        assert_eq!(ordered, [1, 3, 5, 2, 6, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_launch_all_and_resume() {
+        let context = ProgramTest::new("token_launch", token_launch::id(), processor!(process_instruction)).start_with_context().await;
+        let client: LaunchpadClient<BanksClient, Keypair> = LaunchpadClient::new(context.banks_client.clone(), context.payer.insecure_clone(), token_launch::id());
+        let dir = std::env::temp_dir().join(format!("launch-batch-{}", Pubkey::new_unique()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let journal_path = dir.join("items.csv.journal.jsonl");
+
+        // The last row claims a symbol the first one already claimed
+        let contents = "type,symbol,unique-symbol\nspl-legacy,BA1,true\nspl-2022,BA2,\nnft,BA3,\nspl-legacy,BA1,true\n";
+        let rows = parse_manifest(Path::new("items.csv"), contents).unwrap();
+
+        // An earlier run was killed after sending the second row, so it's pending though it landed
+        let mint = Keypair::new();
+        let mut pending = JournalEntry::new(&rows[1], Status::Pending);
+        pending.mint = Some(mint.pubkey().to_string());
+        pending.mint_keypair = Some(mint.to_bytes().to_vec());
+        let journal = Journal::open(&journal_path).unwrap();
+        journal.record(&pending).unwrap();
+        let spec = plan(Path::new("items.csv"), vec![rows[1].clone()], &layers(), &HashMap::new()).unwrap().items.remove(0).spec;
+        let options = LaunchOptions { mint: Some(&mint), ..LaunchOptions::default() };
+        client.launch(spec.launch_config(context.payer.pubkey()), options).await.unwrap();
+
+        let batch = plan(Path::new("items.csv"), rows.clone(), &layers(), &read_journal(&journal_path).unwrap()).unwrap();
+        let results = Launcher::new(&client, &journal, 1_000.0).launch_all(batch.items, 3).await;
+        let outcomes: Vec<&str> = results.iter().map(|(_, outcome)| match outcome {
+            Outcome::Launched => "launched",
+            Outcome::Recovered => "recovered",
+            Outcome::Failed(_) => "failed",
+        }).collect();
+        assert_eq!(outcomes, ["launched", "recovered", "launched", "failed"]);
+        assert!(matches!(&results[3].1, Outcome::Failed(failure) if failure.program_error.as_deref() == Some("SymbolTaken")));
+        assert_eq!(client.next_launch_index(&context.payer.pubkey()).await.unwrap(), 3);
+        assert_eq!(client.fetch_asset(&Pubkey::from_str(results[2].0.mint.as_ref().unwrap()).unwrap()).await.unwrap().symbol(), "BA3");
+
+        // Re-running skips the confirmed rows and retries the failed one with its recorded mint keypair
+        let recorded = read_journal(&journal_path).unwrap();
+        let batch = plan(Path::new("items.csv"), rows, &layers(), &recorded).unwrap();
+        assert_eq!(batch.confirmed.iter().map(|entry| entry.line).collect::<Vec<_>>(), [2, 3, 4]);
+        let results = Launcher::new(&client, &journal, 1_000.0).launch_all(batch.items, 3).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.status, Status::Failed);
+        assert_eq!(results[0].0.mint_keypair, recorded[&5].mint_keypair);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
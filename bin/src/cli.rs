@@ -1,17 +1,28 @@
+use borsh::BorshSerialize;
+use crate::airdrop::{parse_csv, parse_hash, proofs_to_json, MerkleTree};
+use crate::error::invalid;
 use crate::helper::get_payer_keypair;
-use crate::instruction::{launch_spl_token_2022, launch_spl_token_legacy, launch_standard_nft};
+use crate::batch;
+use crate::launch_file::{launch_layers, LaunchSpec};
+use crate::listing::{assets_to_json, page_to_csv, page_to_table, paginate, SortOrder};
+use crate::output::Report;
+use crate::instruction::{admin, creator, distributor, presale, supply, voucher};
 use solana_client::nonblocking::rpc_client;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature};
 use solana_sdk::signer::Signer;
 
+use std::path::PathBuf;
 use std::str::FromStr;
 
 // Import from our library
-use token_launch::{AssetType, LaunchConfig};
-use token_launch::util::{validate_launch_config, get_launched_asset_pda};
+use token_launch::{AssetType, LaunchConfig, event::LaunchpadEvent, state::{FeatureToggles, GlobalConfig, LaunchFees, PauseClass, Voucher}};
+use token_launch::instruction::{decode_asset, decode_creator_profile, decode_global_config, decode_launch_index};
+use token_launch_client::{decode_transaction, AssetFilter, LaunchOptions, LaunchpadClient, Submission};
+use token_launch::util::{validate_launch_config, get_creator_profile_pda, get_deterministic_mint_pda, get_global_config_pda, get_launch_index_pda, get_launched_asset_pda};
 
 
-pub fn handle_validate(matches: &clap::ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+pub fn handle_validate(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
     let name = matches.get_one::<String>("name").unwrap();
     let symbol = matches.get_one::<String>("symbol").unwrap();
     let decimals: u8 = matches.get_one::<String>("decimals").unwrap().parse()?;
@@ -26,26 +37,25 @@ pub fn handle_validate(matches: &clap::ArgMatches) -> Result<(), Box<dyn std::er
         metadata_uri: uri.clone(),
         creator: Pubkey::new_unique(),
         is_mutable: true,
+        max_supply: 1_000_000,
+        emission: None,
+        unique_symbol: false,
+        deterministic_mint: false,
+        extensions: Vec::new(),
     };
 
-    match validate_launch_config(&config) {
-        Ok(()) => {
-            println!("✅ Configuration is valid!");
-            println!("Name: {}", name);
-            println!("Symbol: {}", symbol);
-            println!("Decimals: {}", decimals);
-            println!("URI: {}", uri);
-        }
-        Err(e) => {
-            println!("❌ Configuration is invalid: {:?}", e);
-            return Err(e.into());
-        }
-    }
+    validate_launch_config(&config).map_err(|e| invalid(format!("Configuration is invalid: {:?}", e)))?;
 
-    Ok(())
+    let mut report = Report::new();
+    report.line("✅ Configuration is valid!");
+    report.field("Name", "name", name.as_str());
+    report.field("Symbol", "symbol", symbol.as_str());
+    report.field("Decimals", "decimals", decimals);
+    report.field("URI", "uri", uri.as_str());
+    Ok(report)
 }
 
-pub fn handle_get_pda(matches: &clap::ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+pub fn handle_get_pda(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
     let mint_str = matches.get_one::<String>("mint").unwrap();
     let program_id_str = matches.get_one::<String>("program-id").unwrap();
 
@@ -54,19 +64,156 @@ pub fn handle_get_pda(matches: &clap::ArgMatches) -> Result<(), Box<dyn std::err
 
     let (pda, bump) = get_launched_asset_pda(&program_id, &mint);
 
-    println!("Mint: {}", mint);
-    println!("Program ID: {}", program_id);
-    println!("PDA: {}", pda);
-    println!("Bump: {}", bump);
+    let mut report = Report::new();
+    report.field("Mint", "mint", mint.to_string());
+    report.field("Program ID", "program_id", program_id.to_string());
+    report.field("PDA", "pda", pda.to_string());
+    report.field("Bump", "bump", bump);
+    Ok(report)
+}
+
+/// Mint address of a creator's launch with `--deterministic-mint`, the next launch unless `--index` is given
+pub async fn handle_get_mint_address(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let creator = Pubkey::from_str(matches.get_one::<String>("creator").unwrap())?;
 
-    Ok(())
+    let program_id_string = matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    let index = match matches.get_one::<String>("index") {
+        Some(index) => index.parse()?,
+        None => {
+            let rpc_client_string = matches.get_one::<String>("rpc-url").unwrap();
+            let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+            // Only reads, so a throwaway payer does
+            LaunchpadClient::new(rpc_client, Keypair::new(), program_id).next_launch_index(&creator).await?
+        }
+    };
+
+    let (mint, bump) = get_deterministic_mint_pda(&program_id, &creator, index);
+
+    let mut report = Report::new();
+    report.field("Creator", "creator", creator.to_string());
+    report.field("Launch Index", "launch_index", index);
+    report.field("Mint", "mint", mint.to_string());
+    report.field("Bump", "bump", bump);
+    Ok(report)
 }
 
-pub async fn handle_launch(matches: &clap::ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
-    let asset_type_str = matches.get_one::<String>("type").unwrap();
-    let name = matches.get_one::<String>("name").unwrap();
-    let symbol = matches.get_one::<String>("symbol").unwrap();
-    let uri = matches.get_one::<String>("uri").unwrap();
+pub async fn handle_launch(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    // Everything is checked before the first RPC call, errors name the flag or config key at fault
+    let spec = LaunchSpec::from_matches(matches)?;
+
+    let rpc_client = rpc_client::RpcClient::new(spec.rpc_url.clone());
+    let payer = get_payer_keypair()?;
+    let creator = match &spec.creator {
+        Some(creator) => creator.insecure_clone(),
+        None => payer.insecure_clone(),
+    };
+    let config = spec.launch_config(creator.pubkey());
+
+    // With a relayer paying, the local keypairs only sign and the relayer co-signs with `cosign`
+    let relayer = spec.fee_payer;
+    let program_id = spec.program_id;
+
+    let client = LaunchpadClient::new(rpc_client, payer, program_id);
+    let launch_result = client.launch(config, LaunchOptions { creator: Some(&creator), relayer, mint: None }).await?;
+
+    let mut report = Report::new();
+    if launch_result.signature().is_some() {
+        report.line(match launch_result.asset_type {
+            AssetType::SplTokenLegacy => "✅ SPL Token (legacy) launched successfully!",
+            AssetType::SplToken2022 => "✅ SPL Token 2022 launched successfully!",
+            AssetType::StandardNft => "✅ Standard NFT launched successfully!",
+        });
+    }
+
+    report.insert("asset_type", format!("{:?}", launch_result.asset_type));
+    report.field("Mint", "mint", launch_result.mint.to_string());
+    report.field("Token Account", "token_account", launch_result.token_account.to_string());
+    report.field("Metadata Account", "launched_asset", launch_result.launched_asset.to_string());
+    report.insert("launch_index", launch_result.launch_index);
+    match launch_result.submission {
+        Submission::Sent(signature) => {
+            report.field("Transaction Signature", "signature", signature.to_string());
+            add_events(&mut report, &launch_result.events);
+        }
+        Submission::PartiallySigned(transaction) => {
+            report.line("Partially signed transaction, send it to the relayer to co-sign before the blockhash expires:");
+            report.line(transaction.as_str());
+            report.insert("partially_signed_transaction", transaction);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Launch every row of a manifest, skipping rows the journal has as launched
+pub async fn handle_launch_batch(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let manifest = PathBuf::from(matches.get_one::<String>("manifest").unwrap());
+    let journal_path = match matches.get_one::<String>("journal") {
+        Some(path) => PathBuf::from(path),
+        None => batch::default_journal(&manifest),
+    };
+    let concurrency: usize = matches.get_one::<String>("concurrency").unwrap().parse()?;
+    if concurrency == 0 {
+        return Err(invalid("--concurrency: at least one launch has to be in flight").into());
+    }
+    let rate: f64 = matches.get_one::<String>("rate").unwrap().parse()?;
+    if !rate.is_finite() || rate <= 0.0 {
+        return Err(invalid("--rate: must be a positive number of launches per second").into());
+    }
+
+    // Every row is resolved and checked before the first RPC call
+    let contents = std::fs::read_to_string(&manifest).map_err(|e| invalid(format!("Failed to read {}: {}", manifest.display(), e)))?;
+    let rows = batch::parse_manifest(&manifest, &contents)?;
+    let plan = batch::plan(&manifest, rows, &launch_layers(matches)?, &batch::read_journal(&journal_path)?)?;
+
+    let journal = batch::Journal::open(&journal_path)?;
+    let results = match plan.items.first() {
+        Some(item) => {
+            let rpc_client = rpc_client::RpcClient::new(item.spec.rpc_url.clone());
+            let client = LaunchpadClient::new(rpc_client, get_payer_keypair()?, item.spec.program_id);
+            batch::Launcher::new(&client, &journal, rate).launch_all(plan.items, concurrency).await
+        }
+        None => Vec::new(),
+    };
+
+    Ok(batch::summary(&plan.confirmed, &results, &journal_path))
+}
+
+/// Co-sign a launch as its relayer and send it, the relayer pays the fees
+pub async fn handle_cosign(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let tx = decode_transaction(matches.get_one::<String>("transaction").unwrap())?;
+
+    let rpc_client_string = matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let relayer = get_payer_keypair()?;
+
+    let program_id_string = matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    let client = LaunchpadClient::new(rpc_client, relayer, program_id);
+    let signature = client.cosign(tx).await?;
+    let mut report = Report::new();
+    report.field("Transaction Signature", "signature", signature.to_string());
+    add_events(&mut report, &client.transaction_events(&signature).await?);
+
+    Ok(report)
+}
+
+// Events one line each in text, their debug rendering in JSON
+fn add_events(report: &mut Report, events: &[LaunchpadEvent]) {
+    let events: Vec<String> = events.iter().map(|event| format!("{:?}", event)).collect();
+    for event in &events {
+        report.line(format!("Event: {}", event));
+    }
+    report.insert("events", events);
+}
+
+pub async fn handle_emit(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let mint = Pubkey::from_str(matches.get_one::<String>("mint").unwrap())?;
+    let treasury = Pubkey::from_str(matches.get_one::<String>("token-account").unwrap())?;
 
     let rpc_client_string = matches.get_one::<String>("rpc-url").unwrap();
     let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
@@ -76,64 +223,666 @@ pub async fn handle_launch(matches: &clap::ArgMatches) -> Result<(), Box<dyn std
     let program_id_string = matches.get_one::<String>("program-id").unwrap();
     let program_id = Pubkey::from_str(program_id_string)?;
 
-    let config = match asset_type_str.as_str() {
-        "spl-legacy" => {
-            LaunchConfig {
-                asset_type: AssetType::SplTokenLegacy,
-                name: name.clone(),
-                symbol: symbol.clone(),
-                decimals: matches.get_one::<String>("decimals").unwrap().parse()?,
-                total_supply: matches.get_one::<String>("supply").unwrap().parse()?,
-                metadata_uri: uri.clone(),
-                creator: Pubkey::new_unique(),
-                is_mutable: true,
-            }   
-        },
-        "spl-2022" => {            
-            LaunchConfig {
-                asset_type: AssetType::SplToken2022,
-                name: name.clone(),
-                symbol: symbol.clone(),
-                decimals: matches.get_one::<String>("decimals").unwrap().parse()?,
-                total_supply: matches.get_one::<String>("supply").unwrap().parse()?,
-                metadata_uri: uri.clone(),
-                creator: Pubkey::new_unique(),
-                is_mutable: true,
-            }
-        },
-        "nft" => {
-            LaunchConfig {
-                asset_type: AssetType::StandardNft,
-                name: name.clone(),
-                symbol: symbol.clone(),
-                decimals: 0,
-                total_supply: 1,
-                metadata_uri: uri.clone(),
-                creator: payer.pubkey(),
-                is_mutable: true,
-            }
+    let token_program = rpc_client.get_account(&mint).await?.owner;
+    supply::emit(program_id, &rpc_client, &payer, mint, treasury, token_program).await
+}
+
+pub async fn handle_mint_additional(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let mint = Pubkey::from_str(matches.get_one::<String>("mint").unwrap())?;
+    let destination = Pubkey::from_str(matches.get_one::<String>("token-account").unwrap())?;
+    let amount: u64 = matches.get_one::<String>("amount").unwrap().parse()?;
+
+    let rpc_client_string = matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let payer = get_payer_keypair()?;
+
+    let program_id_string = matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    let token_program = rpc_client.get_account(&mint).await?.owner;
+    supply::mint_additional(program_id, &rpc_client, &payer, mint, destination, token_program, amount).await
+}
+
+pub async fn handle_burn(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let mint = Pubkey::from_str(matches.get_one::<String>("mint").unwrap())?;
+    let token_account = Pubkey::from_str(matches.get_one::<String>("token-account").unwrap())?;
+    let amount: u64 = matches.get_one::<String>("amount").unwrap().parse()?;
+
+    let rpc_client_string = matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let payer = get_payer_keypair()?;
+
+    let program_id_string = matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    let token_program = rpc_client.get_account(&mint).await?.owner;
+    supply::burn(program_id, &rpc_client, &payer, mint, token_account, token_program, amount).await
+}
+
+pub async fn handle_supply(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let mint = Pubkey::from_str(matches.get_one::<String>("mint").unwrap())?;
+
+    let rpc_client_string = matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let program_id_string = matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    // Only reads, so a throwaway payer does
+    let client = LaunchpadClient::new(rpc_client, Keypair::new(), program_id);
+    let asset = client.fetch_asset(&mint).await?;
+
+    let mint_supply = client.fetch_mint(&mint).await?.supply;
+
+    let mut report = Report::new();
+    report.field("Mint", "mint", mint.to_string());
+    report.field("Total Minted", "total_minted", asset.total_supply.get());
+    report.field("Burned", "burned", asset.burned.get());
+    report.field("Circulating (record)", "circulating_supply", asset.circulating_supply.get());
+    report.field("Supply (mint)", "mint_supply", mint_supply);
+    report.field("Max Supply", "max_supply", asset.max_supply.get());
+
+    let matches_mint = asset.circulating_supply.get() == mint_supply;
+    report.insert("matches_mint", matches_mint);
+    if matches_mint {
+        report.line("✅ Record matches the mint");
+    } else {
+        report.line(format!(
+            "⚠️ Record differs from the mint by {}, tokens were burned outside BurnTracked",
+            asset.circulating_supply.get().abs_diff(mint_supply)
+        ));
+    }
+
+    Ok(report)
+}
+
+/// Launched asset record of `--mint` next to its mint account, flagging where they disagree
+pub async fn handle_show(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let mint = Pubkey::from_str(matches.get_one::<String>("mint").unwrap())?;
+
+    let rpc_client_string = matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let program_id_string = matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    // Only reads, so a throwaway payer does
+    let client = LaunchpadClient::new(rpc_client, Keypair::new(), program_id);
+    let launched_asset = get_launched_asset_pda(&program_id, &mint).0;
+    let asset = client.fetch_asset(&mint).await?;
+    let mint_state = client.fetch_mint(&mint).await?;
+    let discrepancies = mint_state.discrepancies(&program_id, &mint, &asset);
+
+    let mut report = Report::new();
+    report.field("Mint", "mint", mint.to_string());
+    report.field("Launched Asset", "launched_asset", launched_asset.to_string());
+
+    report.line(format!("Asset Type: {:?}", asset.asset_type()?));
+    report.line(format!("Name: {}", asset.name()));
+    report.line(format!("Symbol: {}", asset.symbol()));
+    report.line(format!("URI: {}", asset.uri()));
+    report.line(format!("Creator: {}", asset.creator));
+    report.line(format!("Launched At: {}", asset.launch_timestamp.get()));
+    report.line(format!("Total Minted: {}", asset.total_supply.get()));
+    report.line(format!("Burned: {}", asset.burned.get()));
+    report.line(format!("Circulating (record): {}", asset.circulating_supply.get()));
+    report.line(format!("Max Supply: {}", asset.max_supply.get()));
+    if let Some(emission) = asset.emission() {
+        report.line(format!("Emission: {} per epoch to {}, last emitted in epoch {}", emission.amount_per_epoch, emission.treasury, asset.last_emission_epoch.get()));
+    }
+    report.insert("record", serde_json::json!({
+        "asset_type": format!("{:?}", asset.asset_type()?),
+        "name": asset.name(),
+        "symbol": asset.symbol(),
+        "uri": asset.uri(),
+        "creator": asset.creator.to_string(),
+        "launch_timestamp": asset.launch_timestamp.get(),
+        "total_minted": asset.total_supply.get(),
+        "burned": asset.burned.get(),
+        "circulating_supply": asset.circulating_supply.get(),
+        "max_supply": asset.max_supply.get(),
+        "emission": asset.emission().map(|emission| serde_json::json!({
+            "amount_per_epoch": emission.amount_per_epoch,
+            "treasury": emission.treasury.to_string(),
+            "last_emission_epoch": asset.last_emission_epoch.get(),
+        })),
+    }));
+
+    let display_authority = |authority: Option<Pubkey>| authority.map_or("none".to_string(), |authority| authority.to_string());
+    report.line(format!("Token Program: {}", mint_state.token_program));
+    report.line(format!("Supply (mint): {}", mint_state.supply));
+    report.line(format!("Decimals: {}", mint_state.decimals));
+    report.line(format!("Mint Authority: {}", display_authority(mint_state.mint_authority)));
+    report.line(format!("Freeze Authority: {}", display_authority(mint_state.freeze_authority)));
+    report.insert("mint_account", serde_json::json!({
+        "token_program": mint_state.token_program.to_string(),
+        "supply": mint_state.supply,
+        "decimals": mint_state.decimals,
+        "mint_authority": mint_state.mint_authority.map(|authority| authority.to_string()),
+        "freeze_authority": mint_state.freeze_authority.map(|authority| authority.to_string()),
+    }));
+
+    if discrepancies.is_empty() {
+        report.line("✅ Record matches the mint");
+    }
+    for discrepancy in &discrepancies {
+        report.line(format!("⚠️ {}", discrepancy));
+    }
+    report.insert("discrepancies", discrepancies.iter().map(ToString::to_string).collect::<Vec<_>>());
+
+    Ok(report)
+}
+
+/// Launched assets matching the filters, one page at a time sorted by launch time
+pub async fn handle_list(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let filter = AssetFilter {
+        creator: matches.get_one::<String>("creator").map(|creator| Pubkey::from_str(creator)).transpose()?,
+        asset_type: match matches.get_one::<String>("type").map(String::as_str) {
+            None => None,
+            Some("spl-legacy") => Some(AssetType::SplTokenLegacy),
+            Some("spl-2022") => Some(AssetType::SplToken2022),
+            Some("nft") => Some(AssetType::StandardNft),
+            Some(_) => return Err(invalid("Invalid asset type. Use: spl-legacy, spl-2022, or nft").into()),
         },
-        _ => return Err("Invalid asset type".into()),
+        launched_after: matches.get_one::<String>("launched-after").map(|after| after.parse()).transpose()?,
+        launched_before: matches.get_one::<String>("launched-before").map(|before| before.parse()).transpose()?,
+    };
+    let order = match matches.get_one::<String>("sort").unwrap().as_str() {
+        "oldest" => SortOrder::Oldest,
+        "newest" => SortOrder::Newest,
+        _ => return Err(invalid("Invalid sort order. Use: oldest or newest").into()),
     };
+    let limit: usize = matches.get_one::<String>("limit").unwrap().parse()?;
+    let offset: usize = matches.get_one::<String>("offset").unwrap().parse()?;
 
-    let result = match asset_type_str.as_str() {
-        "spl-legacy" => launch_spl_token_legacy::launch_spl_token_legacy(program_id,rpc_client, &payer, config).await,
-        "spl-2022" => launch_spl_token_2022::launch_spl_token_2022(program_id, rpc_client, &payer, config).await,
-        "nft" => launch_standard_nft::launch_standard_nft(program_id,rpc_client, &payer, config).await,
-        _ => return Err("Invalid asset type".into()),
+    let rpc_client_string = matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let program_id_string = matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    // Only reads, so a throwaway payer does
+    let client = LaunchpadClient::new(rpc_client, Keypair::new(), program_id);
+    let page = paginate(client.list_assets(&filter).await?, order, offset, limit);
+
+    // `--format` picks the text rendering, JSON output always carries the page
+    let text = match matches.get_one::<String>("format").unwrap().as_str() {
+        "table" => page_to_table(&page),
+        "csv" => page_to_csv(&page),
+        _ => return Err(invalid("Invalid format. Use: table or csv").into()),
     };
 
-    match result {
-        Ok(launch_result) => {
-            println!("Mint: {}", launch_result.mint);
-            println!("Token Account: {}", launch_result.token_account);
-            println!("Metadata Account: {}", launch_result.metadata_account);
-            println!("Transaction Signature: {}", launch_result.signature);
+    let mut report = Report::new();
+    report.line(text.trim_end());
+    report.insert("total", page.total);
+    report.insert("offset", page.offset);
+    report.insert("assets", assets_to_json(&page));
+    Ok(report)
+}
+
+pub async fn handle_presale(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let (action, sub_matches) = matches.subcommand().ok_or("No presale action provided")?;
+
+    let mint = Pubkey::from_str(sub_matches.get_one::<String>("mint").unwrap())?;
+
+    let rpc_client_string = sub_matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let payer = get_payer_keypair()?;
+
+    let program_id_string = sub_matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    let report = match action {
+        "create" => {
+            let token_account = Pubkey::from_str(sub_matches.get_one::<String>("token-account").unwrap())?;
+            let token_program = rpc_client.get_account(&mint).await?.owner;
+            let soft_cap: u64 = sub_matches.get_one::<String>("soft-cap").unwrap().parse()?;
+            let tokens_for_sale: u64 = sub_matches.get_one::<String>("tokens").unwrap().parse()?;
+            let deadline: i64 = sub_matches.get_one::<String>("deadline").unwrap().parse()?;
+
+            presale::create_presale(program_id, &rpc_client, &payer, mint, token_account, token_program, soft_cap, tokens_for_sale, deadline).await?
+        }
+        "contribute" => {
+            let amount: u64 = sub_matches.get_one::<String>("amount").unwrap().parse()?;
+
+            presale::contribute(program_id, &rpc_client, &payer, mint, amount).await?
+        }
+        "claim" => {
+            let token_account = Pubkey::from_str(sub_matches.get_one::<String>("token-account").unwrap())?;
+            let token_program = rpc_client.get_account(&mint).await?.owner;
+
+            presale::claim(program_id, &rpc_client, &payer, mint, token_account, token_program).await?
+        }
+        "withdraw" => {
+            let token_account = Pubkey::from_str(sub_matches.get_one::<String>("token-account").unwrap())?;
+            let token_program = rpc_client.get_account(&mint).await?.owner;
+
+            presale::withdraw(program_id, &rpc_client, &payer, mint, token_account, token_program).await?
+        }
+        "refund" => presale::refund(program_id, &rpc_client, &payer, mint).await?,
+        _ => return Err(invalid("Invalid presale action").into()),
+    };
+
+    Ok(report)
+}
+
+pub async fn handle_airdrop(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let (action, sub_matches) = matches.subcommand().ok_or("No airdrop action provided")?;
+
+    if action == "build-tree" {
+        let csv_path = sub_matches.get_one::<String>("csv").unwrap();
+        let output_path = sub_matches.get_one::<String>("proofs").unwrap();
+
+        let entries = parse_csv(&std::fs::read_to_string(csv_path)?)?;
+        let tree = MerkleTree::new(&entries);
+        let proofs = proofs_to_json(&tree, &entries);
+        std::fs::write(output_path, serde_json::to_string_pretty(&proofs)?)?;
+
+        let mut report = Report::new();
+        report.line(format!("✅ Merkle tree built for {} wallets", entries.len()));
+        report.insert("wallets", entries.len());
+        report.field("Merkle Root", "merkle_root", proofs["merkle_root"].clone());
+        report.field("Total Amount", "total_amount", proofs["total_amount"].clone());
+        report.field("Proofs written to", "proofs_path", output_path.as_str());
+        return Ok(report);
+    }
+
+    let mint = Pubkey::from_str(sub_matches.get_one::<String>("mint").unwrap())?;
+
+    let rpc_client_string = sub_matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let payer = get_payer_keypair()?;
+
+    let program_id_string = sub_matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    let token_account = Pubkey::from_str(sub_matches.get_one::<String>("token-account").unwrap())?;
+    let token_program = rpc_client.get_account(&mint).await?.owner;
+
+    let report = match action {
+        "create" => {
+            let proofs: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(sub_matches.get_one::<String>("proofs").unwrap())?)?;
+            let merkle_root = parse_hash(&proofs["merkle_root"])?;
+            let total_amount = proofs["total_amount"].as_u64().ok_or_else(|| invalid("Proofs file is missing total_amount"))?;
+            let num_leaves = proofs["num_leaves"].as_u64().ok_or_else(|| invalid("Proofs file is missing num_leaves"))?;
+            let expiry: i64 = sub_matches.get_one::<String>("expiry").unwrap().parse()?;
+
+            distributor::create_distributor(program_id, &rpc_client, &payer, mint, token_account, token_program, merkle_root, total_amount, num_leaves, expiry).await?
+        }
+        "claim" => {
+            let proofs: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(sub_matches.get_one::<String>("proofs").unwrap())?)?;
+            let wallet = payer.pubkey().to_string();
+            let entry = proofs["claims"]
+                .as_array()
+                .ok_or_else(|| invalid("Proofs file is missing claims"))?
+                .iter()
+                .find(|claim| claim["wallet"].as_str() == Some(wallet.as_str()))
+                .ok_or_else(|| invalid("Wallet is not part of this airdrop"))?;
+
+            let index = entry["index"].as_u64().ok_or_else(|| invalid("Claim is missing index"))?;
+            let amount = entry["amount"].as_u64().ok_or_else(|| invalid("Claim is missing amount"))?;
+            let proof = entry["proof"]
+                .as_array()
+                .ok_or_else(|| invalid("Claim is missing proof"))?
+                .iter()
+                .map(parse_hash)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            distributor::claim(program_id, &rpc_client, &payer, mint, token_account, token_program, index, amount, proof).await?
+        }
+        "clawback" => distributor::clawback(program_id, &rpc_client, &payer, mint, token_account, token_program).await?,
+        _ => return Err(invalid("Invalid airdrop action").into()),
+    };
+
+    Ok(report)
+}
+
+pub async fn handle_voucher(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let (action, sub_matches) = matches.subcommand().ok_or("No voucher action provided")?;
+
+    if action == "sign" {
+        let keypair_path = sub_matches.get_one::<String>("keypair").unwrap();
+        let signer = read_keypair_file(keypair_path)
+            .map_err(|e| format!("Failed to read keypair file: {}", e))?;
+
+        let voucher = Voucher {
+            mint: Pubkey::from_str(sub_matches.get_one::<String>("mint").unwrap())?,
+            wallet: Pubkey::from_str(sub_matches.get_one::<String>("wallet").unwrap())?,
+            amount: sub_matches.get_one::<String>("amount").unwrap().parse()?,
+            nonce: sub_matches.get_one::<String>("nonce").unwrap().parse()?,
+            expiry: sub_matches.get_one::<String>("expiry").unwrap().parse()?,
+        };
+        let signature = signer.sign_message(&voucher.try_to_vec()?);
+
+        let signed_voucher = serde_json::json!({
+            "mint": voucher.mint.to_string(),
+            "wallet": voucher.wallet.to_string(),
+            "amount": voucher.amount,
+            "nonce": voucher.nonce,
+            "expiry": voucher.expiry,
+            "signer": signer.pubkey().to_string(),
+            "signature": signature.to_string(),
+        });
+        // The text output is the voucher file handed to the wallet
+        let mut report = Report::new();
+        report.line(serde_json::to_string_pretty(&signed_voucher)?);
+        report.insert("voucher", signed_voucher);
+        return Ok(report);
+    }
+
+    let rpc_client_string = sub_matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let payer = get_payer_keypair()?;
+
+    let program_id_string = sub_matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    let token_account = Pubkey::from_str(sub_matches.get_one::<String>("token-account").unwrap())?;
+
+    let report = match action {
+        "register" => {
+            let mint = Pubkey::from_str(sub_matches.get_one::<String>("mint").unwrap())?;
+            let token_program = rpc_client.get_account(&mint).await?.owner;
+            let voucher_signer = Pubkey::from_str(sub_matches.get_one::<String>("signer").unwrap())?;
+            let amount: u64 = sub_matches.get_one::<String>("amount").unwrap().parse()?;
+
+            voucher::register_voucher_signer(program_id, &rpc_client, &payer, mint, token_account, token_program, voucher_signer, amount).await?
+        }
+        "claim" => {
+            let signed_voucher: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(sub_matches.get_one::<String>("voucher").unwrap())?)?;
+            let field = |name: &str| signed_voucher[name].as_str().map(str::to_string).ok_or_else(|| invalid(format!("Voucher is missing {}", name)));
+            let number = |name: &str| signed_voucher[name].as_i64().ok_or_else(|| invalid(format!("Voucher is missing {}", name)));
+
+            let voucher = Voucher {
+                mint: Pubkey::from_str(&field("mint")?)?,
+                wallet: Pubkey::from_str(&field("wallet")?)?,
+                amount: u64::try_from(number("amount")?)?,
+                nonce: u64::try_from(number("nonce")?)?,
+                expiry: number("expiry")?,
+            };
+            let voucher_signer = Pubkey::from_str(&field("signer")?)?;
+            let voucher_signature = Signature::from_str(&field("signature")?)?;
+            let token_program = rpc_client.get_account(&voucher.mint).await?.owner;
+
+            voucher::claim_with_voucher(program_id, &rpc_client, &payer, token_account, token_program, voucher, voucher_signer, voucher_signature).await?
+        }
+        "reclaim" => {
+            let mint = Pubkey::from_str(sub_matches.get_one::<String>("mint").unwrap())?;
+            let token_program = rpc_client.get_account(&mint).await?.owner;
+
+            voucher::reclaim_voucher_tokens(program_id, &rpc_client, &payer, mint, token_account, token_program).await?
+        }
+        _ => return Err(invalid("Invalid voucher action").into()),
+    };
+
+    Ok(report)
+}
+
+pub async fn handle_creator(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let (action, sub_matches) = matches.subcommand().ok_or("No creator action provided")?;
+
+    let rpc_client_string = sub_matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let program_id_string = sub_matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    match action {
+        "show" => {
+            let creator = Pubkey::from_str(sub_matches.get_one::<String>("creator").unwrap())?;
+            creator_launches(&rpc_client, &program_id, &creator).await
+        }
+        "update" => {
+            let payer = get_payer_keypair()?;
+            let name = sub_matches.get_one::<String>("name").unwrap().clone();
+            let website = sub_matches.get_one::<String>("website").unwrap().clone();
+            let socials = sub_matches.get_one::<String>("socials").unwrap().clone();
+
+            creator::update_creator_profile(program_id, &rpc_client, &payer, name, website, socials).await
+        }
+        _ => Err(invalid("Invalid creator action").into()),
+    }
+}
+
+async fn creator_launches(rpc_client: &rpc_client::RpcClient, program_id: &Pubkey, creator: &Pubkey) -> Result<Report, Box<dyn std::error::Error>> {
+    let creator_profile = get_creator_profile_pda(program_id, creator).0;
+    let data = rpc_client
+        .get_account_data(&creator_profile)
+        .await
+        .map_err(|_| invalid("Creator has no profile, nothing has been launched"))?;
+    let profile = decode_creator_profile(&data)?;
+
+    let mut report = Report::new();
+    report.field("Creator", "creator", profile.creator.to_string());
+    for (label, key, value) in [("Name", "name", &profile.name), ("Website", "website", &profile.website), ("Socials", "socials", &profile.socials)] {
+        if value.is_empty() {
+            report.insert(key, value.as_str());
+        } else {
+            report.field(label, key, value.as_str());
+        }
+    }
+    report.field("Launches", "launch_count", profile.launch_count);
+
+    let launch_indexes: Vec<Pubkey> = (0..profile.launch_count)
+        .map(|index| get_launch_index_pda(program_id, creator, index).0)
+        .collect();
+
+    let mut launches = Vec::new();
+    // getMultipleAccounts takes at most 100 keys per request
+    for chunk in launch_indexes.chunks(100) {
+        for account in rpc_client.get_multiple_accounts(chunk).await? {
+            let launch_index = decode_launch_index(&account.ok_or("Launch index account is missing")?.data)?;
+
+            let launched_asset = get_launched_asset_pda(program_id, &launch_index.mint).0;
+            match rpc_client.get_account_data(&launched_asset).await {
+                Ok(data) => {
+                    let asset = decode_asset(&data)?;
+                    report.line(format!("  #{} {} {} ({}) {:?}", launch_index.index, launch_index.mint, asset.name(), asset.symbol(), asset.asset_type()?));
+                    launches.push(serde_json::json!({
+                        "index": launch_index.index,
+                        "mint": launch_index.mint.to_string(),
+                        "name": asset.name(),
+                        "symbol": asset.symbol(),
+                        "asset_type": format!("{:?}", asset.asset_type()?),
+                    }));
+                }
+                Err(_) => {
+                    report.line(format!("  #{} {}", launch_index.index, launch_index.mint));
+                    launches.push(serde_json::json!({ "index": launch_index.index, "mint": launch_index.mint.to_string() }));
+                }
+            }
+        }
+    }
+
+    report.insert("launches", launches);
+    Ok(report)
+}
+
+pub async fn handle_admin(matches: &clap::ArgMatches) -> Result<Report, Box<dyn std::error::Error>> {
+    let (action, sub_matches) = matches.subcommand().ok_or("No admin action provided")?;
+
+    let rpc_client_string = sub_matches.get_one::<String>("rpc-url").unwrap();
+    let rpc_client = rpc_client::RpcClient::new(rpc_client_string.clone());
+
+    let program_id_string = sub_matches.get_one::<String>("program-id").unwrap();
+    let program_id = Pubkey::from_str(program_id_string)?;
+
+    if action == "status" {
+        return global_config_status(&rpc_client, &program_id).await;
+    }
+
+    let payer = get_payer_keypair()?;
+
+    let report = match action {
+        "init" => {
+            let admin = match sub_matches.get_one::<String>("admin") {
+                Some(admin) => Pubkey::from_str(admin)?,
+                None => payer.pubkey(),
+            };
+            let guardian = match sub_matches.get_one::<String>("guardian") {
+                Some(guardian) => Pubkey::from_str(guardian)?,
+                None => admin,
+            };
+            let treasury = Pubkey::from_str(sub_matches.get_one::<String>("treasury").unwrap())?;
+            let launch_fees = parse_launch_fees(sub_matches, &LaunchFees { spl_token_legacy: 0, spl_token_2022: 0, standard_nft: 0 })?;
+            let toggles = parse_toggles(sub_matches, &FeatureToggles::default())?;
+
+            admin::initialize_global_config(program_id, &rpc_client, &payer, admin, guardian, treasury, launch_fees, toggles).await?
+        }
+        "update" => {
+            let current = fetch_global_config(&rpc_client, &program_id).await?;
+
+            let launch_fees = ["fee-legacy", "fee-2022", "fee-nft"]
+                .iter()
+                .any(|fee| sub_matches.contains_id(fee))
+                .then(|| parse_launch_fees(sub_matches, &current.launch_fees))
+                .transpose()?;
+            let treasury = sub_matches
+                .get_one::<String>("treasury")
+                .map(|treasury| Pubkey::from_str(treasury))
+                .transpose()?;
+            let toggles = ["collect-fees", "enabled-types"]
+                .iter()
+                .any(|toggle| sub_matches.contains_id(toggle))
+                .then(|| parse_toggles(sub_matches, &current.toggles))
+                .transpose()?;
+            let guardian = sub_matches
+                .get_one::<String>("guardian")
+                .map(|guardian| Pubkey::from_str(guardian))
+                .transpose()?;
+
+            admin::update_global_config(program_id, &rpc_client, &payer, launch_fees, treasury, toggles, guardian).await?
+        }
+        "rotate" => {
+            let new_admin = Pubkey::from_str(sub_matches.get_one::<String>("new-admin").unwrap())?;
+
+            admin::rotate_admin(program_id, &rpc_client, &payer, new_admin).await?
         }
-        Err(e) => {
-            return Err(e.into());
+        "withdraw" => {
+            let treasury = fetch_global_config(&rpc_client, &program_id).await?.treasury;
+            let amount: u64 = sub_matches.get_one::<String>("amount").unwrap().parse()?;
+
+            admin::withdraw_fees(program_id, &rpc_client, &payer, treasury, amount).await?
+        }
+        "reserve" | "release" => {
+            let symbol = sub_matches.get_one::<String>("symbol").unwrap().clone();
+
+            admin::set_symbol_reserved(program_id, &rpc_client, &payer, symbol, action == "reserve").await?
+        }
+        "pause" | "unpause" => {
+            let class = parse_pause_class(sub_matches.get_one::<String>("class").unwrap())?;
+
+            admin::set_paused(program_id, &rpc_client, &payer, class, action == "pause").await?
+        }
+        _ => return Err(invalid("Invalid admin action").into()),
+    };
+
+    Ok(report)
+}
+
+async fn fetch_global_config(rpc_client: &rpc_client::RpcClient, program_id: &Pubkey) -> Result<GlobalConfig, Box<dyn std::error::Error>> {
+    let global_config = get_global_config_pda(program_id).0;
+    let data = rpc_client
+        .get_account_data(&global_config)
+        .await
+        .map_err(|_| invalid("Global config has not been initialized"))?;
+    Ok(decode_global_config(&data)?)
+}
+
+async fn global_config_status(rpc_client: &rpc_client::RpcClient, program_id: &Pubkey) -> Result<Report, Box<dyn std::error::Error>> {
+    let global_config_address = get_global_config_pda(program_id).0;
+    let global_config = fetch_global_config(rpc_client, program_id).await?;
+
+    let balance = rpc_client.get_balance(&global_config_address).await?;
+    let rent_exempt = rpc_client
+        .get_minimum_balance_for_rent_exemption(GlobalConfig::LEN)
+        .await?;
+
+    let paused = |paused: bool| if paused { "paused" } else { "active" };
+
+    let fees = &global_config.launch_fees;
+    let toggles = &global_config.toggles;
+
+    let mut report = Report::new();
+    report.field("Global config", "global_config", global_config_address.to_string());
+    report.field("Admin", "admin", global_config.admin.to_string());
+    report.field("Guardian", "guardian", global_config.guardian.to_string());
+    report.field("Treasury", "treasury", global_config.treasury.to_string());
+    report.line(format!("Launch fees: {:?}", fees));
+    report.insert("launch_fees", serde_json::json!({
+        "spl_token_legacy": fees.spl_token_legacy,
+        "spl_token_2022": fees.spl_token_2022,
+        "standard_nft": fees.standard_nft,
+    }));
+    report.line(format!("Feature toggles: {:?}", toggles));
+    report.insert("toggles", serde_json::json!({
+        "collect_fees": toggles.collect_fees,
+        "spl_token_legacy_launches": toggles.spl_token_legacy_launches,
+        "spl_token_2022_launches": toggles.spl_token_2022_launches,
+        "standard_nft_launches": toggles.standard_nft_launches,
+    }));
+    report.line(format!("Launch instructions: {}", paused(global_config.paused.launch)));
+    report.line(format!("Sale instructions: {}", paused(global_config.paused.sale)));
+    report.line(format!("Update instructions: {}", paused(global_config.paused.update)));
+    report.insert("paused", serde_json::json!({
+        "launch": global_config.paused.launch,
+        "sale": global_config.paused.sale,
+        "update": global_config.paused.update,
+    }));
+    report.line(format!("Collected fees: {} lamports", balance.saturating_sub(rent_exempt)));
+    report.insert("collected_fees", balance.saturating_sub(rent_exempt));
+
+    Ok(report)
+}
+
+fn parse_pause_class(class: &str) -> Result<PauseClass, Box<dyn std::error::Error>> {
+    match class {
+        "launch" => Ok(PauseClass::Launch),
+        "sale" => Ok(PauseClass::Sale),
+        "update" => Ok(PauseClass::Update),
+        _ => Err(invalid(format!("Invalid instruction class: {}", class)).into()),
+    }
+}
+
+// Fees that weren't passed keep their `current` value
+fn parse_launch_fees(matches: &clap::ArgMatches, current: &LaunchFees) -> Result<LaunchFees, Box<dyn std::error::Error>> {
+    let fee = |name: &str, current: u64| -> Result<u64, Box<dyn std::error::Error>> {
+        match matches.get_one::<String>(name) {
+            Some(fee) => Ok(fee.parse()?),
+            None => Ok(current),
+        }
+    };
+
+    Ok(LaunchFees {
+        spl_token_legacy: fee("fee-legacy", current.spl_token_legacy)?,
+        spl_token_2022: fee("fee-2022", current.spl_token_2022)?,
+        standard_nft: fee("fee-nft", current.standard_nft)?,
+    })
+}
+
+// Toggles that weren't passed keep their `current` value
+fn parse_toggles(matches: &clap::ArgMatches, current: &FeatureToggles) -> Result<FeatureToggles, Box<dyn std::error::Error>> {
+    let mut toggles = current.clone();
+
+    if let Some(collect_fees) = matches.get_one::<String>("collect-fees") {
+        toggles.collect_fees = collect_fees.parse()?;
+    }
+
+    if let Some(enabled_types) = matches.get_one::<String>("enabled-types") {
+        let enabled: Vec<&str> = enabled_types.split(',').map(str::trim).collect();
+        if let Some(unknown) = enabled.iter().find(|t| !["spl-legacy", "spl-2022", "nft", ""].contains(t)) {
+            return Err(invalid(format!("Invalid asset type: {}", unknown)).into());
         }
+
+        toggles.spl_token_legacy_launches = enabled.contains(&"spl-legacy");
+        toggles.spl_token_2022_launches = enabled.contains(&"spl-2022");
+        toggles.standard_nft_launches = enabled.contains(&"nft");
     }
 
-    Ok(())
-}
\ No newline at end of file
+    Ok(toggles)
+}
@@ -0,0 +1,159 @@
+use solana_client::client_error::ClientError as RpcError;
+use solana_sdk::{
+    hash::ParseHashError, instruction::InstructionError, program_error::ProgramError, pubkey::ParsePubkeyError, signature::ParseSignatureError, transaction::TransactionError
+};
+use std::error::Error;
+use thiserror::Error;
+use token_launch::error::LaunchpadError;
+use token_launch_client::ClientError;
+
+// Errors raised by the CLI itself rather than a library it calls
+#[derive(Error, Debug)]
+pub enum CliError {
+    /// Arguments or input files that can't be used, caught before anything is sent
+    #[error("{0}")]
+    Validation(String),
+}
+
+pub fn invalid(message: impl Into<String>) -> CliError {
+    CliError::Validation(message.into())
+}
+
+/// What went wrong, each kind exits with its own code
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorKind {
+    /// Bad arguments, input files or accounts that don't hold what the command needs
+    Validation,
+    /// The RPC node couldn't be reached or rejected the request
+    Rpc,
+    /// A transaction reached the cluster and failed
+    Program,
+    Other,
+}
+
+impl ErrorKind {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ErrorKind::Other => 1,
+            ErrorKind::Validation => 2,
+            ErrorKind::Rpc => 3,
+            ErrorKind::Program => 4,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::Validation => "validation",
+            ErrorKind::Rpc => "rpc",
+            ErrorKind::Program => "program",
+            ErrorKind::Other => "other",
+        }
+    }
+}
+
+/// A failed command, classified for exit codes and JSON output
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub kind: ErrorKind,
+    pub message: String,
+    /// Custom error code of the failed instruction
+    pub code: Option<u32>,
+    /// Launchpad error with that code, when the launchpad raised it
+    pub program_error: Option<String>,
+}
+
+impl Failure {
+    pub fn new(kind: ErrorKind, message: String) -> Self {
+        Failure { kind, message, code: None, program_error: None }
+    }
+
+    pub fn from_error(error: &(dyn Error + 'static)) -> Self {
+        if let Some(error) = error.downcast_ref::<ClientError>() {
+            if let Some(transaction_error) = error.transaction_error() {
+                return Failure::program(&transaction_error, error.to_string());
+            }
+            let kind = match error {
+                ClientError::Rpc(_) | ClientError::MissingLogs => ErrorKind::Rpc,
+                ClientError::Signer(_) => ErrorKind::Other,
+                _ => ErrorKind::Validation,
+            };
+            return Failure::new(kind, error.to_string());
+        }
+
+        if let Some(error) = error.downcast_ref::<RpcError>() {
+            return match error.get_transaction_error() {
+                Some(transaction_error) => Failure::program(&transaction_error, error.to_string()),
+                None => Failure::new(ErrorKind::Rpc, error.to_string()),
+            };
+        }
+
+        let validation = error.is::<CliError>()
+            || error.is::<ProgramError>()
+            || error.is::<ParsePubkeyError>()
+            || error.is::<ParseSignatureError>()
+            || error.is::<ParseHashError>()
+            || error.is::<std::num::ParseIntError>()
+            || error.is::<std::num::TryFromIntError>()
+            || error.is::<std::str::ParseBoolError>()
+            || error.is::<serde_json::Error>();
+        let kind = if validation { ErrorKind::Validation } else { ErrorKind::Other };
+        Failure::new(kind, error.to_string())
+    }
+
+    // Launchpad errors are described by their own message, the RPC's wording only has the code
+    fn program(transaction_error: &TransactionError, message: String) -> Self {
+        let code = match transaction_error {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(*code),
+            _ => None,
+        };
+        let launchpad_error = code.and_then(|code| LaunchpadError::try_from(code).ok());
+
+        Failure {
+            kind: ErrorKind::Program,
+            message: launchpad_error.map_or(message, |error| error.to_string()),
+            code,
+            program_error: launchpad_error.map(|error| format!("{:?}", error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_client::client_error::ClientErrorKind;
+    use std::str::FromStr;
+
+    fn classify(error: Box<dyn Error>) -> Failure {
+        Failure::from_error(error.as_ref())
+    }
+
+    #[test]
+    fn test_validation_failures() {
+        assert_eq!(classify(invalid("Invalid asset type").into()).kind, ErrorKind::Validation);
+        assert_eq!(classify(solana_sdk::pubkey::Pubkey::from_str("nope").unwrap_err().into()).kind, ErrorKind::Validation);
+        assert_eq!(classify("12x".parse::<u64>().unwrap_err().into()).kind, ErrorKind::Validation);
+        assert_eq!(classify(ProgramError::InvalidArgument.into()).kind, ErrorKind::Validation);
+
+        let mismatch = ClientError::CreatorMismatch { config: Default::default(), signer: Default::default() };
+        assert_eq!(classify(mismatch.into()).kind, ErrorKind::Validation);
+
+        assert_eq!(classify("Failed to read keypair file".into()).kind, ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_rpc_and_program_failures() {
+        let unreachable = RpcError::from(ClientErrorKind::Custom("connection refused".to_string()));
+        assert_eq!(classify(ClientError::from(unreachable).into()).kind, ErrorKind::Rpc);
+
+        let symbol_taken = TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::SymbolTaken as u32));
+        let failure = classify(RpcError::from(symbol_taken).into());
+        assert_eq!(failure.kind, ErrorKind::Program);
+        assert_eq!(failure.code, Some(LaunchpadError::SymbolTaken as u32));
+        assert_eq!(failure.program_error.as_deref(), Some("SymbolTaken"));
+        assert_eq!(failure.message, LaunchpadError::SymbolTaken.to_string());
+
+        let failure = classify(ClientError::from(RpcError::from(TransactionError::AccountNotFound)).into());
+        assert_eq!(failure.kind, ErrorKind::Program);
+        assert_eq!(failure.code, None);
+    }
+}
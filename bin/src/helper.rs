@@ -1,6 +1,9 @@
-use solana_sdk::signature::{read_keypair_file, Keypair};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::transaction::Transaction;
 use std::env;
 use std::path::PathBuf;
+use token_launch_client::Transport;
 
 /// Loads the payer keypair from ~/.config/solana/id.json
 pub fn get_payer_keypair() -> Result<Keypair, Box<dyn std::error::Error>> {
@@ -10,4 +13,23 @@ pub fn get_payer_keypair() -> Result<Keypair, Box<dyn std::error::Error>> {
     let keypair = read_keypair_file(path)
         .map_err(|e| format!("Failed to read keypair file: {}", e))?;
     Ok(keypair)
-}
\ No newline at end of file
+}
+
+/// Signs a single instruction with `signer` as fee payer and sends it
+pub async fn send_instruction(
+    transport: &impl Transport,
+    instruction: Instruction,
+    signer: &Keypair,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let recent_blockhash = transport.get_latest_blockhash().await?;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[signer],
+        recent_blockhash,
+    );
+
+    let confirmation = transport.send_and_confirm(&tx).await?;
+    Ok(confirmation.signature.to_string())
+}
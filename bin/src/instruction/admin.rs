@@ -0,0 +1,98 @@
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
+use token_launch::{
+    instruction,
+    state::{FeatureToggles, LaunchFees, PauseClass},
+    util::{get_global_config_pda, normalize_symbol},
+};
+use token_launch_client::Transport;
+
+use crate::helper::send_instruction;
+use crate::output::Report;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn initialize_global_config(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    upgrade_authority: &Keypair,
+    admin: Pubkey,
+    guardian: Pubkey,
+    treasury: Pubkey,
+    launch_fees: LaunchFees,
+    toggles: FeatureToggles,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let global_config = get_global_config_pda(&program_id).0;
+
+    let instruction = instruction::initialize_global_config(&program_id, &upgrade_authority.pubkey(), admin, guardian, treasury, launch_fees, toggles);
+
+    let signature = send_instruction(transport, instruction, upgrade_authority).await?;
+    let mut report = Report::signed(format!("✅ Global config initialized: {}", global_config), signature);
+    report.insert("global_config", global_config.to_string());
+    Ok(report)
+}
+
+pub async fn update_global_config(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    admin: &Keypair,
+    launch_fees: Option<LaunchFees>,
+    treasury: Option<Pubkey>,
+    toggles: Option<FeatureToggles>,
+    guardian: Option<Pubkey>,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::update_global_config(&program_id, &admin.pubkey(), launch_fees, treasury, toggles, guardian);
+
+    let signature = send_instruction(transport, instruction, admin).await?;
+    Ok(Report::signed("✅ Global config updated", signature))
+}
+
+pub async fn rotate_admin(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    admin: &Keypair,
+    new_admin: Pubkey,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::rotate_admin(&program_id, &admin.pubkey(), new_admin);
+
+    let signature = send_instruction(transport, instruction, admin).await?;
+    Ok(Report::signed(format!("✅ Admin rotated to {}", new_admin), signature))
+}
+
+pub async fn withdraw_fees(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    admin: &Keypair,
+    treasury: Pubkey,
+    amount: u64,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::withdraw_fees(&program_id, &admin.pubkey(), &treasury, amount);
+
+    let signature = send_instruction(transport, instruction, admin).await?;
+    Ok(Report::signed(format!("✅ Withdrew {} lamports to {}", amount, treasury), signature))
+}
+
+pub async fn set_paused(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    guardian: &Keypair,
+    class: PauseClass,
+    paused: bool,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::set_paused(&program_id, &guardian.pubkey(), class, paused);
+
+    let signature = send_instruction(transport, instruction, guardian).await?;
+    Ok(Report::signed(format!("✅ {:?} instructions {}", class, if paused { "paused" } else { "unpaused" }), signature))
+}
+
+pub async fn set_symbol_reserved(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    admin: &Keypair,
+    symbol: String,
+    reserved: bool,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let normalized = normalize_symbol(&symbol);
+    let instruction = instruction::set_symbol_reserved(&program_id, &admin.pubkey(), symbol, reserved);
+
+    let signature = send_instruction(transport, instruction, admin).await?;
+    Ok(Report::signed(format!("✅ Symbol {} {}", normalized, if reserved { "reserved" } else { "released" }), signature))
+}
@@ -0,0 +1,24 @@
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
+use token_launch::{instruction, util::get_creator_profile_pda};
+use token_launch_client::Transport;
+
+use crate::helper::send_instruction;
+use crate::output::Report;
+
+pub async fn update_creator_profile(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    creator: &Keypair,
+    name: String,
+    website: String,
+    socials: String,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let creator_profile = get_creator_profile_pda(&program_id, &creator.pubkey()).0;
+
+    let instruction = instruction::update_creator_profile(&program_id, &creator.pubkey(), name, website, socials);
+
+    let signature = send_instruction(transport, instruction, creator).await?;
+    let mut report = Report::signed(format!("✅ Creator profile updated: {}", creator_profile), signature);
+    report.insert("creator_profile", creator_profile.to_string());
+    Ok(report)
+}
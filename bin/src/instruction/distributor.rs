@@ -0,0 +1,61 @@
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
+use token_launch::{instruction, util::get_distributor_pda};
+use token_launch_client::Transport;
+
+use crate::helper::send_instruction;
+use crate::output::Report;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn create_distributor(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    creator: &Keypair,
+    mint: Pubkey,
+    creator_token_account: Pubkey,
+    token_program: Pubkey,
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    num_leaves: u64,
+    expiry: i64,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let distributor = get_distributor_pda(&program_id, &mint).0;
+
+    let instruction = instruction::create_distributor(&program_id, &creator.pubkey(), &mint, &creator_token_account, &token_program, merkle_root, total_amount, num_leaves, expiry);
+
+    let signature = send_instruction(transport, instruction, creator).await?;
+    let mut report = Report::signed(format!("✅ Distributor created: {}", distributor), signature);
+    report.insert("distributor", distributor.to_string());
+    Ok(report)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn claim(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    claimant: &Keypair,
+    mint: Pubkey,
+    claimant_token_account: Pubkey,
+    token_program: Pubkey,
+    index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::claim(&program_id, &claimant.pubkey(), &mint, &claimant_token_account, &token_program, index, amount, proof);
+
+    let signature = send_instruction(transport, instruction, claimant).await?;
+    Ok(Report::signed(format!("✅ Claimed {} tokens", amount), signature))
+}
+
+pub async fn clawback(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    creator: &Keypair,
+    mint: Pubkey,
+    creator_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::clawback(&program_id, &creator.pubkey(), &mint, &creator_token_account, &token_program);
+
+    let signature = send_instruction(transport, instruction, creator).await?;
+    Ok(Report::signed("✅ Unclaimed tokens returned", signature))
+}
@@ -1,9 +1,6 @@
-pub mod launch_spl_token_2022;
-pub mod launch_spl_token_legacy;
-pub mod launch_standard_nft;
-pub mod launch_result;
-
-pub use launch_spl_token_2022::launch_spl_token_2022;
-pub use launch_spl_token_legacy::launch_spl_token_legacy;
-pub use launch_standard_nft::launch_standard_nft;
-pub use launch_result::LaunchResult;
\ No newline at end of file
+pub mod admin;
+pub mod creator;
+pub mod distributor;
+pub mod presale;
+pub mod supply;
+pub mod voucher;
@@ -0,0 +1,81 @@
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
+use token_launch::{instruction, util::get_presale_pda};
+use token_launch_client::Transport;
+
+use crate::helper::send_instruction;
+use crate::output::Report;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn create_presale(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    creator: &Keypair,
+    mint: Pubkey,
+    creator_token_account: Pubkey,
+    token_program: Pubkey,
+    soft_cap: u64,
+    tokens_for_sale: u64,
+    deadline: i64,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let presale = get_presale_pda(&program_id, &mint).0;
+
+    let instruction = instruction::create_presale(&program_id, &creator.pubkey(), &mint, &creator_token_account, &token_program, soft_cap, tokens_for_sale, deadline);
+
+    let signature = send_instruction(transport, instruction, creator).await?;
+    let mut report = Report::signed(format!("✅ Presale created: {}", presale), signature);
+    report.insert("presale", presale.to_string());
+    Ok(report)
+}
+
+pub async fn contribute(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    contributor: &Keypair,
+    mint: Pubkey,
+    amount: u64,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::contribute(&program_id, &contributor.pubkey(), &mint, amount);
+
+    let signature = send_instruction(transport, instruction, contributor).await?;
+    Ok(Report::signed(format!("✅ Contributed {} lamports", amount), signature))
+}
+
+pub async fn claim(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    contributor: &Keypair,
+    mint: Pubkey,
+    contributor_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::claim_presale_tokens(&program_id, &contributor.pubkey(), &mint, &contributor_token_account, &token_program);
+
+    let signature = send_instruction(transport, instruction, contributor).await?;
+    Ok(Report::signed("✅ Presale tokens claimed", signature))
+}
+
+pub async fn withdraw(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    creator: &Keypair,
+    mint: Pubkey,
+    creator_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::withdraw_presale(&program_id, &creator.pubkey(), &mint, &creator_token_account, &token_program);
+
+    let signature = send_instruction(transport, instruction, creator).await?;
+    Ok(Report::signed("✅ Presale settled for creator", signature))
+}
+
+pub async fn refund(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    contributor: &Keypair,
+    mint: Pubkey,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::refund(&program_id, &contributor.pubkey(), &mint);
+
+    let signature = send_instruction(transport, instruction, contributor).await?;
+    Ok(Report::signed("✅ Contribution refunded", signature))
+}
@@ -0,0 +1,50 @@
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use token_launch::instruction;
+use token_launch_client::Transport;
+
+use crate::helper::send_instruction;
+use crate::output::Report;
+
+pub async fn emit(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    payer: &Keypair,
+    mint: Pubkey,
+    treasury: Pubkey,
+    token_program: Pubkey,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::emit(&program_id, &mint, &treasury, &token_program);
+
+    let signature = send_instruction(transport, instruction, payer).await?;
+    Ok(Report::signed(format!("✅ Emission minted to treasury {}", treasury), signature))
+}
+
+pub async fn mint_additional(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    creator: &Keypair,
+    mint: Pubkey,
+    destination: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::mint_additional(&program_id, &creator.pubkey(), &mint, &destination, &token_program, amount);
+
+    let signature = send_instruction(transport, instruction, creator).await?;
+    Ok(Report::signed(format!("✅ Minted {} additional tokens", amount), signature))
+}
+
+pub async fn burn(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    owner: &Keypair,
+    mint: Pubkey,
+    token_account: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::burn_tracked(&program_id, &owner.pubkey(), &mint, &token_account, &token_program, amount);
+
+    let signature = send_instruction(transport, instruction, owner).await?;
+    Ok(Report::signed(format!("✅ Burned {} tokens", amount), signature))
+}
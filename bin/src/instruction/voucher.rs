@@ -0,0 +1,71 @@
+use borsh::BorshSerialize;
+use solana_sdk::{
+    pubkey::Pubkey, signature::{Keypair, Signature, Signer}, transaction::Transaction
+};
+use token_launch::{ed25519::new_ed25519_instruction, instruction, state::Voucher};
+use token_launch_client::Transport;
+
+use crate::helper::send_instruction;
+use crate::output::Report;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn register_voucher_signer(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    creator: &Keypair,
+    mint: Pubkey,
+    creator_token_account: Pubkey,
+    token_program: Pubkey,
+    voucher_signer: Pubkey,
+    amount: u64,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::register_voucher_signer(&program_id, &creator.pubkey(), &mint, &creator_token_account, &token_program, voucher_signer, amount);
+
+    let signature = send_instruction(transport, instruction, creator).await?;
+    Ok(Report::signed(format!("✅ Voucher signer registered: {}", voucher_signer), signature))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn claim_with_voucher(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    claimant: &Keypair,
+    claimant_token_account: Pubkey,
+    token_program: Pubkey,
+    voucher: Voucher,
+    voucher_signer: Pubkey,
+    voucher_signature: Signature,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let signature_bytes: [u8; 64] = voucher_signature.as_ref().try_into()?;
+    let signature_instruction = new_ed25519_instruction(&voucher_signer, &signature_bytes, &voucher.try_to_vec()?);
+
+    let amount = voucher.amount;
+    let claim = instruction::claim_with_voucher(&program_id, &claimant.pubkey(), &claimant_token_account, &token_program, voucher);
+
+    let recent_blockhash = transport.get_latest_blockhash().await?;
+
+    // The Ed25519 verification must sit right before the claim
+    let tx = Transaction::new_signed_with_payer(
+        &[signature_instruction, claim],
+        Some(&claimant.pubkey()),
+        &[claimant],
+        recent_blockhash,
+    );
+
+    let confirmation = transport.send_and_confirm(&tx).await?;
+    Ok(Report::signed(format!("✅ Claimed {} tokens with voucher", amount), confirmation.signature))
+}
+
+pub async fn reclaim_voucher_tokens(
+    program_id: Pubkey,
+    transport: &impl Transport,
+    creator: &Keypair,
+    mint: Pubkey,
+    creator_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Result<Report, Box<dyn std::error::Error>> {
+    let instruction = instruction::reclaim_voucher_tokens(&program_id, &creator.pubkey(), &mint, &creator_token_account, &token_program);
+
+    let signature = send_instruction(transport, instruction, creator).await?;
+    Ok(Report::signed("✅ Voucher tokens reclaimed", signature))
+}
@@ -0,0 +1,640 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use clap::{parser::ValueSource, ArgMatches};
+use serde::Deserialize;
+use solana_sdk::{pubkey::Pubkey, signature::{read_keypair_file, Keypair}};
+use token_launch::{
+    constants::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_TRANSFER_FEE_BASIS_POINTS, MAX_URI_LENGTH},
+    state::{EmissionSchedule, MintExtension},
+    util::normalize_symbol,
+    AssetType, LaunchConfig,
+};
+
+use crate::error::{invalid, CliError};
+
+/// Launch settings from `launch --config`, keys mirror the flags and all are optional so flags can fill them in
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct LaunchFile {
+    #[serde(rename = "type")]
+    pub asset_type: Option<String>,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+    pub supply: Option<u64>,
+    pub max_supply: Option<u64>,
+    pub uri: Option<String>,
+    pub mutable: Option<bool>,
+    pub unique_symbol: Option<bool>,
+    pub deterministic_mint: Option<bool>,
+    #[serde(default)]
+    pub emission: EmissionKeys,
+    #[serde(default)]
+    pub extensions: ExtensionKeys,
+    #[serde(default)]
+    pub authorities: AuthorityKeys,
+    #[serde(default)]
+    pub cluster: ClusterKeys,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct EmissionKeys {
+    pub per_epoch: Option<u64>,
+    pub treasury: Option<String>,
+}
+
+/// Token-2022 extensions to enable on the mint, the creator gets any authority they have
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ExtensionKeys {
+    pub transfer_fee_bps: Option<u16>,
+    pub max_transfer_fee: Option<u64>,
+    pub close_authority: Option<bool>,
+    pub non_transferable: Option<bool>,
+    pub permanent_delegate: Option<bool>,
+    pub interest_rate: Option<i16>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct AuthorityKeys {
+    /// Keypair file, relative paths are read from the config file's directory
+    pub creator: Option<String>,
+    pub fee_payer: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ClusterKeys {
+    pub rpc_url: Option<String>,
+    pub program_id: Option<String>,
+}
+
+impl LaunchFile {
+    /// Sets `key`, spelled as in launch files, from its text form
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let text = Some(value.to_string());
+        match key {
+            "type" => self.asset_type = text,
+            "name" => self.name = text,
+            "symbol" => self.symbol = text,
+            "decimals" => self.decimals = Some(parse_text(value)?),
+            "supply" => self.supply = Some(parse_text(value)?),
+            "max-supply" => self.max_supply = Some(parse_text(value)?),
+            "uri" => self.uri = text,
+            "mutable" => self.mutable = Some(parse_text(value)?),
+            "unique-symbol" => self.unique_symbol = Some(parse_text(value)?),
+            "deterministic-mint" => self.deterministic_mint = Some(parse_text(value)?),
+            "emission.per-epoch" => self.emission.per_epoch = Some(parse_text(value)?),
+            "emission.treasury" => self.emission.treasury = text,
+            "extensions.transfer-fee-bps" => self.extensions.transfer_fee_bps = Some(parse_text(value)?),
+            "extensions.max-transfer-fee" => self.extensions.max_transfer_fee = Some(parse_text(value)?),
+            "extensions.close-authority" => self.extensions.close_authority = Some(parse_text(value)?),
+            "extensions.non-transferable" => self.extensions.non_transferable = Some(parse_text(value)?),
+            "extensions.permanent-delegate" => self.extensions.permanent_delegate = Some(parse_text(value)?),
+            "extensions.interest-rate" => self.extensions.interest_rate = Some(parse_text(value)?),
+            "authorities.creator" => self.authorities.creator = text,
+            "authorities.fee-payer" => self.authorities.fee_payer = text,
+            "cluster.rpc-url" => self.cluster.rpc_url = text,
+            "cluster.program-id" => self.cluster.program_id = text,
+            _ => return Err(format!("unknown key `{}`", key)),
+        }
+        Ok(())
+    }
+}
+
+fn parse_text<T: FromStr>(value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value.parse().map_err(|e| format!("invalid value {:?}: {}", value, e))
+}
+
+/// Reads a launch file, its format picked by the extension: .toml, .json, .yaml or .yml
+pub fn read_launch_file(path: &Path) -> Result<LaunchFile, CliError> {
+    let contents = std::fs::read_to_string(path).map_err(|e| invalid(format!("Failed to read {}: {}", path.display(), e)))?;
+    let format = path.extension().and_then(|extension| extension.to_str()).unwrap_or_default().to_ascii_lowercase();
+    parse_launch_file(&contents, &format).map_err(|e| invalid(format!("{}: {}", path.display(), e)))
+}
+
+fn parse_launch_file(contents: &str, format: &str) -> Result<LaunchFile, String> {
+    match format {
+        "toml" => serde_path_to_error::deserialize(&mut toml::Deserializer::new(contents)).map_err(located),
+        "json" => serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(contents)).map_err(located),
+        "yaml" | "yml" => serde_path_to_error::deserialize(serde_yaml::Deserializer::from_str(contents)).map_err(located),
+        _ => Err("config files must end in .toml, .json, .yaml or .yml".to_string()),
+    }
+}
+
+// Prefixes the key the parser stopped at, the root is "." and adds nothing
+fn located<E: Display>(error: serde_path_to_error::Error<E>) -> String {
+    let path = error.path().to_string();
+    let message = error.inner().to_string();
+    // serde_yaml already leads with the path, without the backticks
+    let message = message.strip_prefix(&format!("{}: ", path)).unwrap_or(&message);
+    match path.as_str() {
+        "." => message.to_string(),
+        path => format!("`{}`: {}", path, message),
+    }
+}
+
+/// Launch flags whose value came from `source`, read into the same shape as a launch file
+pub fn from_matches(matches: &ArgMatches, source: ValueSource) -> Result<LaunchFile, CliError> {
+    // Not every command taking launch flags has all of them, `launch-batch` can't be relayed
+    let from_source = |id: &str| matches.try_contains_id(id).is_ok() && matches.value_source(id) == Some(source);
+    let value = |id: &str| match from_source(id) {
+        true => matches.get_one::<String>(id).cloned(),
+        false => None,
+    };
+    let flag = |id: &str| from_source(id).then(|| matches.get_flag(id));
+
+    Ok(LaunchFile {
+        asset_type: value("type"),
+        name: value("name"),
+        symbol: value("symbol"),
+        decimals: parse_flag("decimals", value("decimals"))?,
+        supply: parse_flag("supply", value("supply"))?,
+        max_supply: parse_flag("max-supply", value("max-supply"))?,
+        uri: value("uri"),
+        mutable: flag("immutable").map(|immutable| !immutable),
+        unique_symbol: flag("unique-symbol"),
+        deterministic_mint: flag("deterministic-mint"),
+        emission: EmissionKeys {
+            per_epoch: parse_flag("emission-per-epoch", value("emission-per-epoch"))?,
+            treasury: value("emission-treasury"),
+        },
+        extensions: ExtensionKeys {
+            transfer_fee_bps: parse_flag("transfer-fee-bps", value("transfer-fee-bps"))?,
+            max_transfer_fee: parse_flag("max-transfer-fee", value("max-transfer-fee"))?,
+            close_authority: flag("close-authority"),
+            non_transferable: flag("non-transferable"),
+            permanent_delegate: flag("permanent-delegate"),
+            interest_rate: parse_flag("interest-rate", value("interest-rate"))?,
+        },
+        authorities: AuthorityKeys { creator: value("creator"), fee_payer: value("fee-payer") },
+        cluster: ClusterKeys { rpc_url: value("rpc-url"), program_id: value("program-id") },
+    })
+}
+
+fn parse_flag<T: FromStr>(id: &str, value: Option<String>) -> Result<Option<T>, CliError>
+where
+    T::Err: Display,
+{
+    value.map(|value| value.parse().map_err(|e| invalid(format!("--{}: {}", id, e)))).transpose()
+}
+
+/// Where a resolved value came from, so errors name the flag or file key to fix
+#[derive(Debug, Clone, PartialEq)]
+pub enum Origin {
+    Flag,
+    File(PathBuf),
+    /// Line of a `launch-batch` manifest
+    Row(PathBuf, usize),
+    Default,
+}
+
+// A setting's key in launch files and its flag
+#[derive(Clone, Copy)]
+struct Key {
+    file: &'static str,
+    flag: &'static str,
+}
+
+const TYPE: Key = Key { file: "type", flag: "type" };
+const NAME: Key = Key { file: "name", flag: "name" };
+const SYMBOL: Key = Key { file: "symbol", flag: "symbol" };
+const DECIMALS: Key = Key { file: "decimals", flag: "decimals" };
+const SUPPLY: Key = Key { file: "supply", flag: "supply" };
+const MAX_SUPPLY: Key = Key { file: "max-supply", flag: "max-supply" };
+const URI: Key = Key { file: "uri", flag: "uri" };
+const PER_EPOCH: Key = Key { file: "emission.per-epoch", flag: "emission-per-epoch" };
+const TREASURY: Key = Key { file: "emission.treasury", flag: "emission-treasury" };
+const TRANSFER_FEE_BPS: Key = Key { file: "extensions.transfer-fee-bps", flag: "transfer-fee-bps" };
+const MAX_TRANSFER_FEE: Key = Key { file: "extensions.max-transfer-fee", flag: "max-transfer-fee" };
+const CLOSE_AUTHORITY: Key = Key { file: "extensions.close-authority", flag: "close-authority" };
+const NON_TRANSFERABLE: Key = Key { file: "extensions.non-transferable", flag: "non-transferable" };
+const PERMANENT_DELEGATE: Key = Key { file: "extensions.permanent-delegate", flag: "permanent-delegate" };
+const INTEREST_RATE: Key = Key { file: "extensions.interest-rate", flag: "interest-rate" };
+const CREATOR: Key = Key { file: "authorities.creator", flag: "creator" };
+const FEE_PAYER: Key = Key { file: "authorities.fee-payer", flag: "fee-payer" };
+const RPC_URL: Key = Key { file: "cluster.rpc-url", flag: "rpc-url" };
+const PROGRAM_ID: Key = Key { file: "cluster.program-id", flag: "program-id" };
+
+impl Origin {
+    fn describe(&self, key: Key) -> String {
+        match self {
+            Origin::File(path) => format!("`{}` in {}", key.file, path.display()),
+            Origin::Row(path, line) => format!("`{}` on line {} of {}", key.file, line, path.display()),
+            Origin::Flag | Origin::Default => format!("--{}", key.flag),
+        }
+    }
+
+    fn error(&self, key: Key, problem: impl Display) -> CliError {
+        invalid(format!("{}: {}", self.describe(key), problem))
+    }
+}
+
+// First layer setting the value, layers are ordered by precedence
+fn pick<'a, T>(layers: &'a [(LaunchFile, Origin)], get: impl Fn(&'a LaunchFile) -> Option<T>) -> Option<(T, &'a Origin)> {
+    layers.iter().find_map(|(file, origin)| get(file).map(|value| (value, origin)))
+}
+
+fn required<'a, T>(layers: &'a [(LaunchFile, Origin)], key: Key, get: impl Fn(&'a LaunchFile) -> Option<T>) -> Result<(T, &'a Origin), CliError> {
+    pick(layers, get).ok_or_else(|| invalid(format!("No value for `{}`, set it in the config file or pass --{}", key.file, key.flag)))
+}
+
+fn parse_key<T: FromStr>((value, origin): (&String, &Origin), key: Key) -> Result<T, CliError>
+where
+    T::Err: Display,
+{
+    value.parse().map_err(|e| origin.error(key, e))
+}
+
+/// The launch flags, the `--config` file and the flag defaults, in order of precedence
+pub fn launch_layers(matches: &ArgMatches) -> Result<Vec<(LaunchFile, Origin)>, CliError> {
+    let mut layers = vec![(from_matches(matches, ValueSource::CommandLine)?, Origin::Flag)];
+    if let Some(path) = matches.get_one::<String>("config") {
+        let path = PathBuf::from(path);
+        layers.push((read_launch_file(&path)?, Origin::File(path)));
+    }
+    layers.push((from_matches(matches, ValueSource::DefaultValue)?, Origin::Default));
+    Ok(layers)
+}
+
+/// A launch with flags, config file and defaults merged and checked, ready to send
+#[derive(Debug)]
+pub struct LaunchSpec {
+    pub asset_type: AssetType,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: u64,
+    pub max_supply: u64,
+    pub uri: String,
+    pub is_mutable: bool,
+    pub unique_symbol: bool,
+    pub deterministic_mint: bool,
+    pub emission: Option<EmissionSchedule>,
+    pub extensions: Vec<MintExtension>,
+    /// Creator keypair, the payer signs as creator when unset
+    pub creator: Option<Keypair>,
+    pub fee_payer: Option<Pubkey>,
+    pub rpc_url: String,
+    pub program_id: Pubkey,
+}
+
+impl LaunchSpec {
+    /// Merges the launch flags over the `--config` file over the flag defaults
+    pub fn from_matches(matches: &ArgMatches) -> Result<Self, CliError> {
+        LaunchSpec::resolve(&launch_layers(matches)?)
+    }
+
+    /// Takes each setting from the first layer that has it and checks it against the asset type's rules
+    pub fn resolve(layers: &[(LaunchFile, Origin)]) -> Result<Self, CliError> {
+        let (asset_type, origin) = required(layers, TYPE, |layer| layer.asset_type.as_ref())?;
+        let asset_type = match asset_type.as_str() {
+            "spl-legacy" => AssetType::SplTokenLegacy,
+            "spl-2022" => AssetType::SplToken2022,
+            "nft" => AssetType::StandardNft,
+            other => return Err(origin.error(TYPE, format!("unknown asset type {:?}, expected spl-legacy, spl-2022 or nft", other))),
+        };
+
+        let (name, origin) = required(layers, NAME, |layer| layer.name.clone())?;
+        if name.len() > MAX_NAME_LENGTH {
+            return Err(origin.error(NAME, format!("{} bytes long, at most {}", name.len(), MAX_NAME_LENGTH)));
+        }
+        let (symbol, symbol_origin) = required(layers, SYMBOL, |layer| layer.symbol.clone())?;
+        if symbol.len() > MAX_SYMBOL_LENGTH {
+            return Err(symbol_origin.error(SYMBOL, format!("{} bytes long, at most {}", symbol.len(), MAX_SYMBOL_LENGTH)));
+        }
+        let (uri, origin) = required(layers, URI, |layer| layer.uri.clone())?;
+        if uri.len() > MAX_URI_LENGTH {
+            return Err(origin.error(URI, format!("{} bytes long, at most {}", uri.len(), MAX_URI_LENGTH)));
+        }
+
+        let (mut decimals, decimals_origin) = required(layers, DECIMALS, |layer| layer.decimals)?;
+        if decimals > 9 {
+            return Err(decimals_origin.error(DECIMALS, format!("{} decimals, at most 9", decimals)));
+        }
+        let (mut total_supply, supply_origin) = required(layers, SUPPLY, |layer| layer.supply)?;
+        let max_supply = pick(layers, |layer| layer.max_supply);
+        let per_epoch = pick(layers, |layer| layer.emission.per_epoch);
+        let treasury = pick(layers, |layer| layer.emission.treasury.as_ref());
+
+        // NFTs are always one token with no decimals, only values someone actually set can contradict that
+        let max_supply = if asset_type == AssetType::StandardNft {
+            if decimals != 0 && *decimals_origin != Origin::Default {
+                return Err(decimals_origin.error(DECIMALS, "NFTs have 0 decimals"));
+            }
+            if total_supply != 1 && *supply_origin != Origin::Default {
+                return Err(supply_origin.error(SUPPLY, "NFTs have a supply of 1"));
+            }
+            if let Some((max_supply, origin)) = max_supply.filter(|(max_supply, _)| *max_supply != 1) {
+                return Err(origin.error(MAX_SUPPLY, format!("NFTs are capped at 1, not {}", max_supply)));
+            }
+            if let Some((_, origin)) = per_epoch {
+                return Err(origin.error(PER_EPOCH, "NFTs can't have an emission schedule"));
+            }
+            decimals = 0;
+            total_supply = 1;
+            1
+        } else {
+            match max_supply {
+                Some((max_supply, origin)) if max_supply < total_supply => {
+                    return Err(origin.error(MAX_SUPPLY, format!("{} is below the initial supply {}", max_supply, total_supply)));
+                }
+                Some((max_supply, _)) => max_supply,
+                None => total_supply,
+            }
+        };
+
+        let emission = match (per_epoch, treasury) {
+            (Some((0, origin)), Some(_)) => return Err(origin.error(PER_EPOCH, "must be greater than 0")),
+            (Some((amount_per_epoch, _)), Some(treasury)) => Some(EmissionSchedule { amount_per_epoch, treasury: parse_key(treasury, TREASURY)? }),
+            (Some((_, origin)), None) => return Err(origin.error(PER_EPOCH, format!("needs `{}` as well", TREASURY.file))),
+            (None, Some((_, origin))) => return Err(origin.error(TREASURY, format!("needs `{}` as well", PER_EPOCH.file))),
+            (None, None) => None,
+        };
+
+        let extensions = resolve_extensions(layers, &asset_type)?;
+
+        let unique_symbol = pick(layers, |layer| layer.unique_symbol).is_some_and(|(unique_symbol, _)| unique_symbol);
+        if unique_symbol && normalize_symbol(&symbol).is_empty() {
+            return Err(symbol_origin.error(SYMBOL, "a blank symbol can't be claimed with unique-symbol"));
+        }
+
+        let creator = match pick(layers, |layer| layer.authorities.creator.as_ref()) {
+            Some((path, origin)) => {
+                let path = match origin {
+                    Origin::File(file) | Origin::Row(file, _) => file.parent().unwrap_or(Path::new("")).join(path),
+                    _ => PathBuf::from(path),
+                };
+                Some(read_keypair_file(&path).map_err(|e| origin.error(CREATOR, format!("failed to read keypair {}: {}", path.display(), e)))?)
+            }
+            None => None,
+        };
+        let fee_payer = pick(layers, |layer| layer.authorities.fee_payer.as_ref()).map(|fee_payer| parse_key(fee_payer, FEE_PAYER)).transpose()?;
+        let (rpc_url, _) = required(layers, RPC_URL, |layer| layer.cluster.rpc_url.clone())?;
+        let program_id = parse_key(required(layers, PROGRAM_ID, |layer| layer.cluster.program_id.as_ref())?, PROGRAM_ID)?;
+
+        Ok(LaunchSpec {
+            asset_type,
+            name,
+            symbol,
+            decimals,
+            total_supply,
+            max_supply,
+            uri,
+            is_mutable: pick(layers, |layer| layer.mutable).map_or(true, |(mutable, _)| mutable),
+            unique_symbol,
+            deterministic_mint: pick(layers, |layer| layer.deterministic_mint).is_some_and(|(deterministic_mint, _)| deterministic_mint),
+            emission,
+            extensions,
+            creator,
+            fee_payer,
+            rpc_url,
+            program_id,
+        })
+    }
+
+    pub fn launch_config(&self, creator: Pubkey) -> LaunchConfig {
+        LaunchConfig {
+            asset_type: self.asset_type.clone(),
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            decimals: self.decimals,
+            total_supply: self.total_supply,
+            metadata_uri: self.uri.clone(),
+            creator,
+            is_mutable: self.is_mutable,
+            max_supply: self.max_supply,
+            emission: self.emission.clone(),
+            unique_symbol: self.unique_symbol,
+            deterministic_mint: self.deterministic_mint,
+            extensions: self.extensions.clone(),
+        }
+    }
+}
+
+// Extensions in the order the launch initializes them, only an spl-2022 launch may enable any
+fn resolve_extensions(layers: &[(LaunchFile, Origin)], asset_type: &AssetType) -> Result<Vec<MintExtension>, CliError> {
+    let transfer_fee_bps = pick(layers, |layer| layer.extensions.transfer_fee_bps);
+    let max_transfer_fee = pick(layers, |layer| layer.extensions.max_transfer_fee);
+    let interest_rate = pick(layers, |layer| layer.extensions.interest_rate);
+
+    let mut extensions = Vec::new();
+    let mut enabled_by = Vec::new();
+    match (transfer_fee_bps, max_transfer_fee) {
+        (Some((basis_points, origin)), _) if basis_points > MAX_TRANSFER_FEE_BASIS_POINTS => {
+            return Err(origin.error(TRANSFER_FEE_BPS, format!("{} basis points, at most {}", basis_points, MAX_TRANSFER_FEE_BASIS_POINTS)));
+        }
+        (Some((basis_points, origin)), maximum_fee) => {
+            // Without a cap the fee is always the full percentage
+            let maximum_fee = maximum_fee.map_or(u64::MAX, |(maximum_fee, _)| maximum_fee);
+            extensions.push(MintExtension::TransferFee { basis_points, maximum_fee });
+            enabled_by.push((TRANSFER_FEE_BPS, origin));
+        }
+        (None, Some((_, origin))) => return Err(origin.error(MAX_TRANSFER_FEE, format!("needs `{}` as well", TRANSFER_FEE_BPS.file))),
+        (None, None) => {}
+    }
+    // The flag defaults are false, so only a layer that turned a switch on enables its extension
+    if let Some((true, origin)) = pick(layers, |layer| layer.extensions.close_authority) {
+        extensions.push(MintExtension::MintCloseAuthority);
+        enabled_by.push((CLOSE_AUTHORITY, origin));
+    }
+    if let Some((true, origin)) = pick(layers, |layer| layer.extensions.non_transferable) {
+        extensions.push(MintExtension::NonTransferable);
+        enabled_by.push((NON_TRANSFERABLE, origin));
+    }
+    if let Some((true, origin)) = pick(layers, |layer| layer.extensions.permanent_delegate) {
+        extensions.push(MintExtension::PermanentDelegate);
+        enabled_by.push((PERMANENT_DELEGATE, origin));
+    }
+    if let Some((rate, origin)) = interest_rate {
+        extensions.push(MintExtension::InterestBearing { rate });
+        enabled_by.push((INTEREST_RATE, origin));
+    }
+
+    match enabled_by.first() {
+        Some((key, origin)) if *asset_type != AssetType::SplToken2022 => Err(origin.error(*key, "only spl-2022 launches can enable extensions")),
+        _ => Ok(extensions),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = r#"
+type = "spl-2022"
+name = "Config Token"
+symbol = "CFG"
+decimals = 9
+supply = 1000
+max-supply = 5000
+mutable = false
+
+[emission]
+per-epoch = 10
+treasury = "11111111111111111111111111111111"
+
+[cluster]
+rpc-url = "http://localhost:8899"
+"#;
+
+    // What the launch flags default to
+    fn defaults() -> LaunchFile {
+        LaunchFile {
+            name: Some("MyCliToken".to_string()),
+            symbol: Some("CLI".to_string()),
+            decimals: Some(6),
+            supply: Some(1_000_000),
+            uri: Some("https://example.com/metadata.json".to_string()),
+            mutable: Some(true),
+            unique_symbol: Some(false),
+            deterministic_mint: Some(false),
+            cluster: ClusterKeys {
+                rpc_url: Some("https://api.devnet.solana.com".to_string()),
+                program_id: Some(token_launch::id().to_string()),
+            },
+            ..LaunchFile::default()
+        }
+    }
+
+    fn resolve(flags: LaunchFile, file: LaunchFile) -> Result<LaunchSpec, CliError> {
+        LaunchSpec::resolve(&[(flags, Origin::Flag), (file, Origin::File(PathBuf::from("launch.toml"))), (defaults(), Origin::Default)])
+    }
+
+    fn file(asset_type: &str) -> LaunchFile {
+        LaunchFile { asset_type: Some(asset_type.to_string()), ..LaunchFile::default() }
+    }
+
+    #[test]
+    fn test_formats_parse_alike() {
+        let json = r#"{
+            "type": "spl-2022", "name": "Config Token", "symbol": "CFG", "decimals": 9, "supply": 1000, "max-supply": 5000, "mutable": false,
+            "emission": { "per-epoch": 10, "treasury": "11111111111111111111111111111111" },
+            "cluster": { "rpc-url": "http://localhost:8899" }
+        }"#;
+        let yaml = "
+type: spl-2022
+name: Config Token
+symbol: CFG
+decimals: 9
+supply: 1000
+max-supply: 5000
+mutable: false
+emission:
+  per-epoch: 10
+  treasury: '11111111111111111111111111111111'
+cluster:
+  rpc-url: http://localhost:8899
+";
+        let launch = parse_launch_file(TOML, "toml").unwrap();
+        assert_eq!(launch.max_supply, Some(5_000));
+        assert_eq!(launch.emission.per_epoch, Some(10));
+        assert_eq!(launch.cluster.program_id, None);
+        assert_eq!(parse_launch_file(json, "json").unwrap(), launch);
+        assert_eq!(parse_launch_file(yaml, "yml").unwrap(), launch);
+        assert!(parse_launch_file(TOML, "ini").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_key() {
+        let error = parse_launch_file("type = \"nft\"\n[emission]\namount = 5\n", "toml").unwrap_err();
+        assert!(error.starts_with("`emission.amount`: unknown field `amount`"), "{}", error);
+
+        let error = parse_launch_file("{ \"decimals\": 300 }", "json").unwrap_err();
+        assert!(error.starts_with("`decimals`: invalid value"), "{}", error);
+
+        let error = parse_launch_file("authorities:\n  creator: [5]\n", "yaml").unwrap_err();
+        assert!(error.starts_with("`authorities.creator`: invalid type"), "{}", error);
+    }
+
+    #[test]
+    fn test_flags_override_file() {
+        let flags = LaunchFile { symbol: Some("FLAG".to_string()), ..LaunchFile::default() };
+        let spec = resolve(flags, parse_launch_file(TOML, "toml").unwrap()).unwrap();
+
+        assert_eq!(spec.asset_type, AssetType::SplToken2022);
+        assert_eq!(spec.symbol, "FLAG");
+        assert_eq!(spec.name, "Config Token");
+        assert_eq!((spec.decimals, spec.total_supply, spec.max_supply), (9, 1_000, 5_000));
+        assert!(!spec.is_mutable);
+        assert_eq!(spec.emission, Some(EmissionSchedule { amount_per_epoch: 10, treasury: Pubkey::default() }));
+        assert_eq!(spec.rpc_url, "http://localhost:8899");
+        assert_eq!(spec.program_id, token_launch::id());
+    }
+
+    #[test]
+    fn test_errors_point_at_their_source() {
+        let error = resolve(LaunchFile::default(), file("spl-3000")).unwrap_err();
+        assert!(error.to_string().starts_with("`type` in launch.toml: unknown asset type"));
+
+        let flags = LaunchFile { max_supply: Some(10), ..LaunchFile::default() };
+        let error = resolve(flags, file("spl-legacy")).unwrap_err();
+        assert_eq!(error.to_string(), "--max-supply: 10 is below the initial supply 1000000");
+
+        let launch = LaunchFile { emission: EmissionKeys { per_epoch: Some(5), treasury: None }, ..file("spl-legacy") };
+        let error = resolve(LaunchFile::default(), launch).unwrap_err();
+        assert_eq!(error.to_string(), "`emission.per-epoch` in launch.toml: needs `emission.treasury` as well");
+
+        let launch = LaunchFile { unique_symbol: Some(true), symbol: Some("  ".to_string()), ..file("spl-legacy") };
+        let error = resolve(LaunchFile::default(), launch).unwrap_err();
+        assert!(error.to_string().starts_with("`symbol` in launch.toml: a blank symbol"));
+
+        let error = resolve(LaunchFile::default(), LaunchFile::default()).unwrap_err();
+        assert!(error.to_string().starts_with("No value for `type`"));
+    }
+
+    #[test]
+    fn test_nft_rules() {
+        // Fungible defaults give way, explicit values must already fit an NFT
+        let spec = resolve(LaunchFile::default(), file("nft")).unwrap();
+        assert_eq!((spec.decimals, spec.total_supply, spec.max_supply), (0, 1, 1));
+
+        let error = resolve(LaunchFile::default(), LaunchFile { decimals: Some(6), ..file("nft") }).unwrap_err();
+        assert_eq!(error.to_string(), "`decimals` in launch.toml: NFTs have 0 decimals");
+
+        let error = resolve(LaunchFile { supply: Some(5), ..LaunchFile::default() }, file("nft")).unwrap_err();
+        assert_eq!(error.to_string(), "--supply: NFTs have a supply of 1");
+
+        let emission = EmissionKeys { per_epoch: Some(5), treasury: Some(Pubkey::default().to_string()) };
+        let error = resolve(LaunchFile::default(), LaunchFile { emission, ..file("nft") }).unwrap_err();
+        assert_eq!(error.to_string(), "`emission.per-epoch` in launch.toml: NFTs can't have an emission schedule");
+
+        let spec = resolve(LaunchFile::default(), LaunchFile { decimals: Some(0), supply: Some(1), max_supply: Some(1), ..file("nft") }).unwrap();
+        assert_eq!(spec.max_supply, 1);
+    }
+
+    #[test]
+    fn test_extensions() {
+        let mut launch = file("spl-2022");
+        launch.set("extensions.transfer-fee-bps", "250").unwrap();
+        launch.set("extensions.interest-rate", "-50").unwrap();
+        launch.set("extensions.permanent-delegate", "false").unwrap();
+        let flags = LaunchFile { extensions: ExtensionKeys { non_transferable: Some(true), ..ExtensionKeys::default() }, ..LaunchFile::default() };
+        let spec = resolve(flags, launch).unwrap();
+        assert_eq!(spec.extensions, [
+            MintExtension::TransferFee { basis_points: 250, maximum_fee: u64::MAX },
+            MintExtension::NonTransferable,
+            MintExtension::InterestBearing { rate: -50 },
+        ]);
+        assert_eq!(spec.launch_config(Pubkey::default()).extensions, spec.extensions);
+
+        let extensions = ExtensionKeys { max_transfer_fee: Some(10), ..ExtensionKeys::default() };
+        let error = resolve(LaunchFile::default(), LaunchFile { extensions, ..file("spl-2022") }).unwrap_err();
+        assert_eq!(error.to_string(), "`extensions.max-transfer-fee` in launch.toml: needs `extensions.transfer-fee-bps` as well");
+
+        let flags = LaunchFile { extensions: ExtensionKeys { transfer_fee_bps: Some(10_001), ..ExtensionKeys::default() }, ..LaunchFile::default() };
+        let error = resolve(flags, file("spl-2022")).unwrap_err();
+        assert_eq!(error.to_string(), "--transfer-fee-bps: 10001 basis points, at most 10000");
+
+        let extensions = ExtensionKeys { close_authority: Some(true), ..ExtensionKeys::default() };
+        let error = resolve(LaunchFile::default(), LaunchFile { extensions, ..file("spl-legacy") }).unwrap_err();
+        assert_eq!(error.to_string(), "`extensions.close-authority` in launch.toml: only spl-2022 launches can enable extensions");
+    }
+}
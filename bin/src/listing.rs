@@ -0,0 +1,182 @@
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+use token_launch::{Asset, AssetType};
+
+/// Order of listed assets by launch time, ties broken by mint so pages are stable
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortOrder {
+    Oldest,
+    Newest,
+}
+
+/// One page of launched assets, `total` counts every match before paging
+pub struct AssetPage {
+    pub total: usize,
+    pub offset: usize,
+    pub assets: Vec<(Pubkey, Asset)>,
+}
+
+/// Sorts `assets` and keeps the `limit` records starting at `offset`
+pub fn paginate(mut assets: Vec<(Pubkey, Asset)>, order: SortOrder, offset: usize, limit: usize) -> AssetPage {
+    assets.sort_by_key(|(_, asset)| (asset.launch_timestamp.get(), asset.mint));
+    if order == SortOrder::Newest {
+        assets.reverse();
+    }
+
+    let total = assets.len();
+    let assets = assets.into_iter().skip(offset).take(limit).collect();
+    AssetPage { total, offset, assets }
+}
+
+// Asset type as the CLI's `--type` spells it
+fn asset_type_name(asset: &Asset) -> &'static str {
+    match asset.asset_type() {
+        Ok(AssetType::SplTokenLegacy) => "spl-legacy",
+        Ok(AssetType::SplToken2022) => "spl-2022",
+        Ok(AssetType::StandardNft) => "nft",
+        Err(_) => "unknown",
+    }
+}
+
+pub fn assets_to_json(page: &AssetPage) -> Vec<Value> {
+    page.assets
+        .iter()
+        .map(|(address, asset)| {
+            json!({
+                "mint": asset.mint.to_string(),
+                "launched_asset": address.to_string(),
+                "asset_type": asset_type_name(asset),
+                "name": asset.name(),
+                "symbol": asset.symbol(),
+                "uri": asset.uri(),
+                "creator": asset.creator.to_string(),
+                "launch_timestamp": asset.launch_timestamp.get(),
+                "total_minted": asset.total_supply.get(),
+                "burned": asset.burned.get(),
+                "circulating_supply": asset.circulating_supply.get(),
+                "max_supply": asset.max_supply.get(),
+            })
+        })
+        .collect()
+}
+
+pub fn page_to_csv(page: &AssetPage) -> String {
+    let mut csv = String::from("mint,launched_asset,asset_type,name,symbol,uri,creator,launch_timestamp,total_minted,burned,circulating_supply,max_supply\n");
+    for (address, asset) in &page.assets {
+        let fields = [
+            asset.mint.to_string(),
+            address.to_string(),
+            asset_type_name(asset).to_string(),
+            csv_field(asset.name()),
+            csv_field(asset.symbol()),
+            csv_field(asset.uri()),
+            asset.creator.to_string(),
+            asset.launch_timestamp.get().to_string(),
+            asset.total_supply.get().to_string(),
+            asset.burned.get().to_string(),
+            asset.circulating_supply.get().to_string(),
+            asset.max_supply.get().to_string(),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+// Names and URIs are creator supplied, so quote any that would break the row
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn page_to_table(page: &AssetPage) -> String {
+    let header = ["MINT", "TYPE", "SYMBOL", "NAME", "CREATOR", "LAUNCHED", "CIRCULATING", "MAX SUPPLY"].map(String::from);
+    let rows: Vec<[String; 8]> = page
+        .assets
+        .iter()
+        .map(|(_, asset)| {
+            [
+                asset.mint.to_string(),
+                asset_type_name(asset).to_string(),
+                asset.symbol().to_string(),
+                asset.name().to_string(),
+                asset.creator.to_string(),
+                asset.launch_timestamp.get().to_string(),
+                asset.circulating_supply.get().to_string(),
+                asset.max_supply.get().to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|column| column.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<String> = row.iter().zip(widths).map(|(cell, width)| format!("{:<width$}", cell, width = width)).collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+
+    let shown = page.assets.len();
+    if shown == 0 {
+        table.push_str(&format!("No assets shown, {} matched\n", page.total));
+    } else {
+        table.push_str(&format!("Showing {}-{} of {} assets\n", page.offset + 1, page.offset + shown, page.total));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(launch_timestamp: i64, name: &str) -> (Pubkey, Asset) {
+        let mut data = vec![0u8; Asset::LEN];
+        let asset = Asset::init(&mut data).unwrap();
+        asset.mint = Pubkey::new_unique();
+        asset.launch_timestamp.set(launch_timestamp);
+        asset.name[..name.len()].copy_from_slice(name.as_bytes());
+        (Pubkey::new_unique(), *asset)
+    }
+
+    #[test]
+    fn test_paginate() {
+        let assets = vec![asset(30, "c"), asset(10, "a"), asset(20, "b")];
+
+        let page = paginate(assets.clone(), SortOrder::Oldest, 1, 5);
+        assert_eq!(page.total, 3);
+        let names: Vec<&str> = page.assets.iter().map(|(_, asset)| asset.name()).collect();
+        assert_eq!(names, ["b", "c"]);
+
+        let page = paginate(assets, SortOrder::Newest, 0, 2);
+        let names: Vec<&str> = page.assets.iter().map(|(_, asset)| asset.name()).collect();
+        assert_eq!(names, ["c", "b"]);
+    }
+
+    #[test]
+    fn test_csv_quotes_creator_supplied_fields() {
+        let page = AssetPage { total: 1, offset: 0, assets: vec![asset(10, "Say \"hi\", all")] };
+        let csv = page_to_csv(&page);
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.contains(",\"Say \"\"hi\"\", all\","));
+        assert_eq!(csv.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_table_aligns_columns() {
+        let page = AssetPage { total: 4, offset: 2, assets: vec![asset(10, "a"), asset(20, "longer name")] };
+        let table = page_to_table(&page);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0].find("CREATOR"), lines[1].find(&page.assets[0].1.creator.to_string()));
+        assert_eq!(lines[3], "Showing 3-4 of 4 assets");
+    }
+}
@@ -1,6 +1,13 @@
-use clap::{Arg, Command};
-use cli::{handle_get_pda, handle_launch, handle_validate};
+use clap::{Arg, ArgAction, Command};
+use output::OutputFormat;
+use cli::{handle_admin, handle_airdrop, handle_burn, handle_cosign, handle_creator, handle_emit, handle_get_mint_address, handle_get_pda, handle_launch, handle_launch_batch, handle_list, handle_mint_additional, handle_presale, handle_show, handle_supply, handle_validate, handle_voucher};
 
+mod airdrop;
+mod batch;
+mod error;
+mod launch_file;
+mod listing;
+mod output;
 mod instruction;
 mod helper;
 mod cli;
@@ -10,6 +17,15 @@ async fn main() {
     let matches = Command::new("Token Launch CLI")
         .version("1.0")
         .about("CLI tool for launching tokens and NFTs on Solana")
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .value_name("FORMAT")
+                .help("Output format: text, json or json-compact. JSON carries a stable schema, errors included")
+                .value_parser(["text", "json", "json-compact"])
+                .default_value("text")
+                .global(true),
+        )
         .subcommand(
             Command::new("get-pda")
                 .about("Get the PDA for a launched asset")
@@ -30,6 +46,23 @@ async fn main() {
                         .required(true),
                 ),
         )
+        .subcommand(
+            admin_command("get-mint-address", "Get the mint address of a creator's launch made with --deterministic-mint")
+                .arg(
+                    Arg::new("creator")
+                        .long("creator")
+                        .value_name("CREATOR_PUBKEY")
+                        .help("Creator public key")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("index")
+                        .long("index")
+                        .value_name("LAUNCH_INDEX")
+                        .help("Launch index, defaults to the creator's next launch read over RPC")
+                        .required(false),
+                ),
+        )
         .subcommand(
             Command::new("validate")
                 .about("Validate a launch configuration")
@@ -69,105 +102,713 @@ async fn main() {
         .subcommand(
             Command::new("launch")
                 .about("Mint a new SPL token (legacy), token 22 or NFT")
+                .args(launch_args())
+                .mut_arg("type", |arg| arg.required_unless_present("config"))
+                .arg(
+                    Arg::new("fee-payer")
+                        .long("fee-payer")
+                        .value_name("RELAYER_PUBKEY")
+                        .help("Relayer paying the fees, prints a partially signed transaction for `cosign` instead of sending it"),
+                ),
+        )
+        .subcommand(
+            Command::new("launch-batch")
+                .about("Launch every row of a CSV manifest, journaling each launch so a re-run resumes where it stopped")
+                .args(launch_args())
+                .arg(
+                    Arg::new("manifest")
+                        .long("manifest")
+                        .value_name("CSV_FILE")
+                        .help("One launch per row, headed by launch config keys like type, name, symbol, supply")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("journal")
+                        .long("journal")
+                        .value_name("FILE")
+                        .help("Journal of sent launches, defaults to the manifest path with .journal.jsonl appended"),
+                )
+                .arg(
+                    Arg::new("concurrency")
+                        .long("concurrency")
+                        .value_name("N")
+                        .help("Launches in flight at once, a creator's own launches always go one at a time")
+                        .default_value("4"),
+                )
+                .arg(
+                    Arg::new("rate")
+                        .long("rate")
+                        .value_name("PER_SECOND")
+                        .help("Most launches sent per second")
+                        .default_value("2"),
+                ),
+        )
+        .subcommand(
+            mint_command("emit", "Mint the emission accrued since the last emitted epoch to the treasury")
+                .arg(token_account_arg("Treasury token account from the emission schedule")),
+        )
+        .subcommand(
+            mint_command("mint-additional", "Mint extra tokens as the creator, up to the supply cap")
+                .arg(token_account_arg("Token account receiving the tokens"))
+                .arg(
+                    Arg::new("amount")
+                        .long("amount")
+                        .value_name("AMOUNT")
+                        .help("Tokens to mint, in base units")
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            mint_command("burn", "Burn tokens and record it in the launched asset's supply")
+                .arg(token_account_arg("Token account to burn from"))
+                .arg(
+                    Arg::new("amount")
+                        .long("amount")
+                        .value_name("AMOUNT")
+                        .help("Tokens to burn, in base units")
+                        .required(true),
+                ),
+        )
+        .subcommand(mint_command("supply", "Compare the launched asset's supply record against the mint"))
+        .subcommand(
+            mint_command("show", "Show the launched asset record and its mint account, flagging where they disagree"),
+        )
+        .subcommand(
+            admin_command("list", "List launched assets, filtered with getProgramAccounts and sorted by launch time")
+                .arg(
+                    Arg::new("creator")
+                        .long("creator")
+                        .value_name("CREATOR_PUBKEY")
+                        .help("Only assets launched by this creator")
+                        .required(false),
+                )
                 .arg(
                     Arg::new("type")
                         .short('t')
                         .long("type")
                         .value_name("ASSET_TYPE")
-                        .help("Asset type: spl-legacy, spl-2022, or nft")
-                        .required(true),
+                        .help("Only assets of this type: spl-legacy, spl-2022 or nft")
+                        .required(false),
                 )
                 .arg(
-                    Arg::new("name")
-                        .short('n')
-                        .long("name")
-                        .value_name("NAME")
-                        .help("Token or NFT name")
-                        .default_value("MyCliToken")
+                    Arg::new("launched-after")
+                        .long("launched-after")
+                        .value_name("UNIX_TIMESTAMP")
+                        .help("Only assets launched at or after this time")
                         .required(false),
                 )
                 .arg(
-                    Arg::new("symbol")
-                        .short('s')
-                        .long("symbol")
-                        .value_name("SYMBOL")
-                        .help("Token or NFT symbol")
-                        .default_value("CLI")
+                    Arg::new("launched-before")
+                        .long("launched-before")
+                        .value_name("UNIX_TIMESTAMP")
+                        .help("Only assets launched at or before this time")
                         .required(false),
                 )
                 .arg(
-                    Arg::new("decimals")
-                        .short('d')
-                        .long("decimals")
-                        .value_name("DECIMALS")
-                        .help("Number of decimals (0 for NFT)")
-                        .default_value("6")
-                        .required(false),
+                    Arg::new("sort")
+                        .long("sort")
+                        .value_name("ORDER")
+                        .help("Launch time order: oldest or newest")
+                        .default_value("newest"),
                 )
                 .arg(
-                    Arg::new("supply")
-                        .short('S')
-                        .long("supply")
-                        .value_name("SUPPLY")
-                        .help("Total supply (1 for NFT)")
-                        .default_value("1000000")
-                        .required(false),
+                    Arg::new("limit")
+                        .long("limit")
+                        .value_name("COUNT")
+                        .help("Assets per page")
+                        .default_value("50"),
                 )
                 .arg(
-                    Arg::new("uri")
-                        .short('u')
-                        .long("uri")
-                        .value_name("URI")
-                        .help("Metadata URI")
-                        .required(false)
-                        .default_value("https://example.com/metadata.json"),
+                    Arg::new("offset")
+                        .long("offset")
+                        .value_name("COUNT")
+                        .help("Assets to skip, for later pages")
+                        .default_value("0"),
                 )
                 .arg(
-                    Arg::new("program-id")
-                        .short('p')
-                        .long("program-id")
-                        .value_name("PROGRAM_ID")
-                        .help("Launchpad program ID")
-                        .default_value("4n6ByGTtLj4fTgLApV2aigC3XzWZhCmYkNbcfVheGzd8")
-                        .required(false),
+                    Arg::new("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Text output format: table or csv, --output json prints the page as JSON")
+                        .default_value("table"),
+                ),
+        )
+        .subcommand(
+            Command::new("presale")
+                .about("Run a soft-cap refundable presale")
+                .subcommand_required(true)
+                .subcommand(
+                    mint_command("create", "Open a presale and escrow the tokens for sale")
+                        .arg(token_account_arg("Creator token account holding the tokens for sale"))
+                        .arg(
+                            Arg::new("soft-cap")
+                                .long("soft-cap")
+                                .value_name("LAMPORTS")
+                                .help("Minimum SOL to raise, in lamports")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("tokens")
+                                .long("tokens")
+                                .value_name("AMOUNT")
+                                .help("Tokens for sale, in base units")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("deadline")
+                                .long("deadline")
+                                .value_name("UNIX_TIMESTAMP")
+                                .help("Time at which the presale ends")
+                                .required(true),
+                        ),
                 )
+                .subcommand(
+                    mint_command("contribute", "Contribute SOL to a running presale")
+                        .arg(
+                            Arg::new("amount")
+                                .long("amount")
+                                .value_name("LAMPORTS")
+                                .help("SOL to contribute, in lamports")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    mint_command("claim", "Claim tokens after a successful presale")
+                        .arg(token_account_arg("Token account receiving the claimed tokens")),
+                )
+                .subcommand(
+                    mint_command("withdraw", "Withdraw raised SOL and later the rounding remainder, or reclaim tokens if the soft cap was missed")
+                        .arg(token_account_arg("Creator token account receiving returned tokens")),
+                )
+                .subcommand(mint_command("refund", "Refund a contribution after a failed presale")),
+        )
+        .subcommand(
+            Command::new("airdrop")
+                .about("Distribute a launched token with merkle proof claims")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("build-tree")
+                        .about("Build a merkle tree from a wallet,amount CSV and write the proofs as JSON")
+                        .arg(
+                            Arg::new("csv")
+                                .long("csv")
+                                .value_name("CSV_FILE")
+                                .help("CSV file with wallet,amount rows")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("proofs")
+                                .long("proofs")
+                                .value_name("JSON_FILE")
+                                .help("Where to write the proofs")
+                                .default_value("proofs.json"),
+                        ),
+                )
+                .subcommand(
+                    mint_command("create", "Escrow the airdrop allocation under the merkle root")
+                        .arg(token_account_arg("Creator token account holding the allocation"))
+                        .arg(proofs_arg())
+                        .arg(
+                            Arg::new("expiry")
+                                .long("expiry")
+                                .value_name("UNIX_TIMESTAMP")
+                                .help("Time after which unclaimed tokens can be clawed back")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    mint_command("claim", "Claim this wallet's airdrop entry")
+                        .arg(token_account_arg("Token account receiving the airdrop"))
+                        .arg(proofs_arg()),
+                )
+                .subcommand(
+                    mint_command("clawback", "Return unclaimed tokens to the creator after expiry")
+                        .arg(token_account_arg("Creator token account receiving unclaimed tokens")),
+                ),
+        )
+        .subcommand(
+            Command::new("voucher")
+                .about("Claim tokens with vouchers signed off-chain")
+                .subcommand_required(true)
+                .subcommand(
+                    mint_command("register", "Register the voucher signer and escrow the voucher allocation")
+                        .arg(token_account_arg("Creator token account holding the allocation"))
+                        .arg(
+                            Arg::new("signer")
+                                .long("signer")
+                                .value_name("SIGNER_PUBKEY")
+                                .help("Public key that signs vouchers")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .long("amount")
+                                .value_name("AMOUNT")
+                                .help("Tokens to escrow for vouchers, in base units")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    Command::new("sign")
+                        .about("Sign a voucher offline and print it as JSON")
+                        .arg(
+                            Arg::new("keypair")
+                                .short('k')
+                                .long("keypair")
+                                .value_name("KEYPAIR_FILE")
+                                .help("Voucher signer keypair file")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("mint")
+                                .short('m')
+                                .long("mint")
+                                .value_name("MINT_PUBKEY")
+                                .help("Mint public key")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("wallet")
+                                .short('w')
+                                .long("wallet")
+                                .value_name("WALLET_PUBKEY")
+                                .help("Wallet allowed to claim")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .long("amount")
+                                .value_name("AMOUNT")
+                                .help("Tokens the wallet may claim, in base units")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("nonce")
+                                .long("nonce")
+                                .value_name("NONCE")
+                                .help("Unique voucher number, each nonce can be claimed once")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("expiry")
+                                .long("expiry")
+                                .value_name("UNIX_TIMESTAMP")
+                                .help("Time after which the voucher can no longer be claimed")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    Command::new("claim")
+                        .about("Claim tokens with a signed voucher")
+                        .arg(token_account_arg("Token account receiving the tokens"))
+                        .arg(
+                            Arg::new("voucher")
+                                .long("voucher")
+                                .value_name("JSON_FILE")
+                                .help("Voucher written by `voucher sign`")
+                                .required(true),
+                        )
+                        .arg(program_id_arg())
+                        .arg(rpc_url_arg()),
+                )
+                .subcommand(
+                    mint_command("reclaim", "Return the tokens left in escrow to the creator and stop further claims")
+                        .arg(token_account_arg("Creator token account receiving the tokens")),
+                ),
+        )
+        .subcommand(
+            Command::new("admin")
+                .about("Manage the launchpad's global config")
+                .subcommand_required(true)
+                .subcommand(
+                    admin_command("init", "Create the global config, signed by the program's upgrade authority")
+                        .arg(
+                            Arg::new("admin")
+                                .long("admin")
+                                .value_name("ADMIN_PUBKEY")
+                                .help("Admin key, defaults to the signer")
+                                .required(false),
+                        )
+                        .arg(guardian_arg().help("Guardian key allowed to pause instructions, defaults to the admin"))
+                        .arg(treasury_arg().required(true))
+                        .args(launch_fee_args())
+                        .args(toggle_args()),
+                )
+                .subcommand(
+                    admin_command("update", "Change launch fees, the treasury, feature toggles or the guardian")
+                        .arg(guardian_arg())
+                        .arg(treasury_arg())
+                        .args(launch_fee_args())
+                        .args(toggle_args()),
+                )
+                .subcommand(
+                    admin_command("rotate", "Hand the admin role to another key")
+                        .arg(
+                            Arg::new("new-admin")
+                                .long("new-admin")
+                                .value_name("ADMIN_PUBKEY")
+                                .help("New admin key")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    admin_command("withdraw", "Send collected launch fees to the treasury")
+                        .arg(
+                            Arg::new("amount")
+                                .long("amount")
+                                .value_name("LAMPORTS")
+                                .help("Lamports to withdraw")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    admin_command("reserve", "Add a symbol to the reserved denylist")
+                        .arg(symbol_arg()),
+                )
+                .subcommand(
+                    admin_command("release", "Remove a symbol from the reserved denylist")
+                        .arg(symbol_arg()),
+                )
+                .subcommand(
+                    admin_command("pause", "Stop an instruction class, signed by the guardian")
+                        .arg(pause_class_arg()),
+                )
+                .subcommand(
+                    admin_command("unpause", "Resume a paused instruction class, signed by the guardian")
+                        .arg(pause_class_arg()),
+                )
+                .subcommand(admin_command("status", "Show the global config and which instruction classes are paused")),
+        )
+        .subcommand(
+            Command::new("creator")
+                .about("Creator profiles and the launches indexed under them")
+                .subcommand_required(true)
+                .subcommand(
+                    admin_command("show", "List a creator's launches in order")
+                        .arg(
+                            Arg::new("creator")
+                                .value_name("CREATOR_PUBKEY")
+                                .help("Creator public key")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    admin_command("update", "Set the signer's profile display info")
+                        .arg(
+                            Arg::new("name")
+                                .long("name")
+                                .value_name("NAME")
+                                .help("Display name")
+                                .default_value(""),
+                        )
+                        .arg(
+                            Arg::new("website")
+                                .long("website")
+                                .value_name("URL")
+                                .help("Website")
+                                .default_value(""),
+                        )
+                        .arg(
+                            Arg::new("socials")
+                                .long("socials")
+                                .value_name("SOCIALS")
+                                .help("Social handles, e.g. x:@handle,discord:handle")
+                                .default_value(""),
+                        ),
+                ),
+        )
+        .subcommand(
+            admin_command("cosign", "Co-sign and send a launch prepared with `launch --fee-payer`, paying its fees")
                 .arg(
-                    Arg::new("rpc-url")
-                        .long("rpc-url")
-                        .value_name("RPC_URL")
-                        .help("Solana RPC endpoint")
-                        .default_value("https://api.devnet.solana.com")
-                        .required(false),
+                    Arg::new("transaction")
+                        .value_name("BASE64_TRANSACTION")
+                        .help("Partially signed transaction printed by `launch --fee-payer`")
+                        .required(true),
                 ),
         )
-        .get_matches();
+        .try_get_matches();
 
-    match matches.subcommand() {
-        Some(("launch", sub_matches)) => {
-            if let Err(e) = handle_launch(sub_matches).await {
-                eprintln!("Error launching asset: {}", e);
-                std::process::exit(1);    
-            }
-        }
-        Some(("get-pda", sub_matches)) => {
-            if let Err(e) = handle_get_pda(sub_matches) {
-                eprintln!("Error getting PDA: {}", e);
-                std::process::exit(1);
+    let matches = match matches {
+        Ok(matches) => matches,
+        Err(error) => {
+            // Help and version aren't failures, and clap's own messages read better as text
+            let output = requested_output(std::env::args());
+            if output == OutputFormat::Text || !error.use_stderr() {
+                error.exit();
             }
+            let message = error.render().to_string();
+            let result = Err(error::invalid(message.lines().next().unwrap_or_default().trim_start_matches("error: ")).into());
+            std::process::exit(output::finish(output, "", "", result));
         }
-        Some(("validate", sub_matches)) => {
-            if let Err(e) = handle_validate(sub_matches) {
-                eprintln!("Error validating config: {}", e);
-                std::process::exit(1);
-            }
-        }
-        _ => {
-            eprintln!("No valid subcommand provided. Use --help for usage information.");
-            std::process::exit(1);
-        }
-    }
+    };
+
+    let output = OutputFormat::parse(matches.get_one::<String>("output").unwrap()).unwrap();
+
+    // Nested actions are part of the command, like "presale create"
+    let command = std::iter::successors(Some(&matches), |matches| matches.subcommand().map(|(_, sub_matches)| sub_matches))
+        .filter_map(|matches| matches.subcommand_name())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let (context, result) = match matches.subcommand() {
+        Some(("launch", sub_matches)) => ("Error launching asset", handle_launch(sub_matches).await),
+        Some(("launch-batch", sub_matches)) => ("Error running launch batch", handle_launch_batch(sub_matches).await),
+        Some(("get-pda", sub_matches)) => ("Error getting PDA", handle_get_pda(sub_matches)),
+        Some(("get-mint-address", sub_matches)) => ("Error getting mint address", handle_get_mint_address(sub_matches).await),
+        Some(("emit", sub_matches)) => ("Error emitting tokens", handle_emit(sub_matches).await),
+        Some(("mint-additional", sub_matches)) => ("Error minting additional tokens", handle_mint_additional(sub_matches).await),
+        Some(("burn", sub_matches)) => ("Error burning tokens", handle_burn(sub_matches).await),
+        Some(("supply", sub_matches)) => ("Error reading supply", handle_supply(sub_matches).await),
+        Some(("show", sub_matches)) => ("Error showing asset", handle_show(sub_matches).await),
+        Some(("list", sub_matches)) => ("Error listing assets", handle_list(sub_matches).await),
+        Some(("presale", sub_matches)) => ("Error running presale command", handle_presale(sub_matches).await),
+        Some(("airdrop", sub_matches)) => ("Error running airdrop command", handle_airdrop(sub_matches).await),
+        Some(("voucher", sub_matches)) => ("Error running voucher command", handle_voucher(sub_matches).await),
+        Some(("creator", sub_matches)) => ("Error running creator command", handle_creator(sub_matches).await),
+        Some(("admin", sub_matches)) => ("Error running admin command", handle_admin(sub_matches).await),
+        Some(("cosign", sub_matches)) => ("Error co-signing transaction", handle_cosign(sub_matches).await),
+        Some(("validate", sub_matches)) => ("Error validating config", handle_validate(sub_matches)),
+        _ => ("Error", Err(error::invalid("No valid subcommand provided. Use --help for usage information.").into())),
+    };
+
+    std::process::exit(output::finish(output, &command, context, result));
+}
+
+// `--output` as passed on the command line, for reporting errors clap found before it parsed the flag
+fn requested_output(args: impl Iterator<Item = String>) -> OutputFormat {
+    let mut args = args.skip_while(|arg| arg != "--output" && !arg.starts_with("--output="));
+    let format = match args.next() {
+        Some(arg) if arg == "--output" => args.next(),
+        Some(arg) => arg.strip_prefix("--output=").map(str::to_string),
+        None => None,
+    };
+    format.and_then(|format| OutputFormat::parse(&format)).unwrap_or(OutputFormat::Text)
+}
+
+// Subcommand acting on a mint, with the arguments shared by every such action
+fn mint_command(name: &'static str, about: &'static str) -> Command {
+    Command::new(name)
+        .about(about)
+        .arg(
+            Arg::new("mint")
+                .short('m')
+                .long("mint")
+                .value_name("MINT_PUBKEY")
+                .help("Mint public key")
+                .required(true),
+        )
+        .arg(program_id_arg())
+        .arg(rpc_url_arg())
+}
+
+// Subcommand that only needs the program and an RPC endpoint, like those acting on the global config
+fn admin_command(name: &'static str, about: &'static str) -> Command {
+    Command::new(name)
+        .about(about)
+        .arg(program_id_arg())
+        .arg(rpc_url_arg())
+}
+
+fn treasury_arg() -> Arg {
+    Arg::new("treasury")
+        .long("treasury")
+        .value_name("TREASURY_PUBKEY")
+        .help("Account receiving withdrawn launch fees")
+        .required(false)
+}
+
+fn guardian_arg() -> Arg {
+    Arg::new("guardian")
+        .long("guardian")
+        .value_name("GUARDIAN_PUBKEY")
+        .help("Guardian key allowed to pause instructions")
+        .required(false)
 }
 
+fn symbol_arg() -> Arg {
+    Arg::new("symbol")
+        .long("symbol")
+        .value_name("SYMBOL")
+        .help("Symbol, matched case-insensitively after trimming")
+        .required(true)
+}
+
+fn pause_class_arg() -> Arg {
+    Arg::new("class")
+        .long("class")
+        .value_name("CLASS")
+        .help("Instruction class: launch, sale or update")
+        .required(true)
+}
+
+// Flags describing a launch, shared by `launch` and `launch-batch`
+fn launch_args() -> Vec<Arg> {
+    vec![
+        Arg::new("config")
+            .long("config")
+            .value_name("FILE")
+            .help("TOML, JSON or YAML file describing the launch, flags given alongside override its keys"),
+        Arg::new("type")
+            .short('t')
+            .long("type")
+            .value_name("ASSET_TYPE")
+            .help("Asset type: spl-legacy, spl-2022, or nft"),
+        Arg::new("name")
+            .short('n')
+            .long("name")
+            .value_name("NAME")
+            .help("Token or NFT name")
+            .default_value("MyCliToken"),
+        Arg::new("symbol")
+            .short('s')
+            .long("symbol")
+            .value_name("SYMBOL")
+            .help("Token or NFT symbol")
+            .default_value("CLI"),
+        Arg::new("decimals")
+            .short('d')
+            .long("decimals")
+            .value_name("DECIMALS")
+            .help("Number of decimals (0 for NFT)")
+            .default_value("6"),
+        Arg::new("supply")
+            .short('S')
+            .long("supply")
+            .value_name("SUPPLY")
+            .help("Total supply (1 for NFT)")
+            .default_value("1000000"),
+        Arg::new("max-supply")
+            .long("max-supply")
+            .value_name("MAX_SUPPLY")
+            .help("Supply cap for later minting, defaults to the initial supply"),
+        Arg::new("emission-per-epoch")
+            .long("emission-per-epoch")
+            .value_name("AMOUNT")
+            .help("Tokens anyone can mint to the treasury each epoch, up to the cap")
+            .requires("emission-treasury"),
+        Arg::new("emission-treasury")
+            .long("emission-treasury")
+            .value_name("TOKEN_ACCOUNT")
+            .help("Token account receiving the emission")
+            .requires("emission-per-epoch"),
+        Arg::new("creator")
+            .long("creator")
+            .value_name("KEYPAIR")
+            .help("Keypair file of the creator the launch is attributed to, defaults to the payer"),
+        Arg::new("deterministic-mint")
+            .long("deterministic-mint")
+            .help("Derive the mint from the creator and launch index instead of generating a mint keypair")
+            .action(ArgAction::SetTrue),
+        Arg::new("immutable")
+            .long("immutable")
+            .help("Launch with metadata that can't be updated later")
+            .action(ArgAction::SetTrue),
+        Arg::new("unique-symbol")
+            .long("unique-symbol")
+            .help("Claim the symbol so no later launch can use it, fails if it's already taken")
+            .action(ArgAction::SetTrue),
+        Arg::new("transfer-fee-bps")
+            .long("transfer-fee-bps")
+            .value_name("BASIS_POINTS")
+            .help("Token-2022 transfer fee withheld from every transfer, the creator can change and collect it"),
+        Arg::new("max-transfer-fee")
+            .long("max-transfer-fee")
+            .value_name("AMOUNT")
+            .help("Cap on the transfer fee of a single transfer, uncapped by default")
+            .requires("transfer-fee-bps"),
+        Arg::new("close-authority")
+            .long("close-authority")
+            .help("Let the creator close the Token-2022 mint once its supply is zero")
+            .action(ArgAction::SetTrue),
+        Arg::new("non-transferable")
+            .long("non-transferable")
+            .help("Make the Token-2022 tokens non-transferable")
+            .action(ArgAction::SetTrue),
+        Arg::new("permanent-delegate")
+            .long("permanent-delegate")
+            .help("Make the creator a permanent delegate of every Token-2022 account of the mint")
+            .action(ArgAction::SetTrue),
+        Arg::new("interest-rate")
+            .long("interest-rate")
+            .value_name("BASIS_POINTS")
+            .help("Token-2022 interest rate shown on balances, the creator can change it")
+            .allow_hyphen_values(true),
+        Arg::new("uri")
+            .short('u')
+            .long("uri")
+            .value_name("URI")
+            .help("Metadata URI")
+            .default_value("https://example.com/metadata.json"),
+        program_id_arg(),
+        rpc_url_arg(),
+    ]
+}
+
+fn launch_fee_args() -> [Arg; 3] {
+    [
+        Arg::new("fee-legacy")
+            .long("fee-legacy")
+            .value_name("LAMPORTS")
+            .help("Launch fee for spl-legacy tokens"),
+        Arg::new("fee-2022")
+            .long("fee-2022")
+            .value_name("LAMPORTS")
+            .help("Launch fee for spl-2022 tokens"),
+        Arg::new("fee-nft")
+            .long("fee-nft")
+            .value_name("LAMPORTS")
+            .help("Launch fee for NFTs"),
+    ]
+}
+
+fn toggle_args() -> [Arg; 2] {
+    [
+        Arg::new("collect-fees")
+            .long("collect-fees")
+            .value_name("true|false")
+            .help("Whether launches are charged the launch fee"),
+        Arg::new("enabled-types")
+            .long("enabled-types")
+            .value_name("ASSET_TYPES")
+            .help("Comma separated asset types that may be launched: spl-legacy, spl-2022, nft"),
+    ]
+}
+
+fn program_id_arg() -> Arg {
+    Arg::new("program-id")
+        .short('p')
+        .long("program-id")
+        .value_name("PROGRAM_ID")
+        .help("Launchpad program ID")
+        .default_value("4n6ByGTtLj4fTgLApV2aigC3XzWZhCmYkNbcfVheGzd8")
+        .required(false)
+}
+
+fn rpc_url_arg() -> Arg {
+    Arg::new("rpc-url")
+        .long("rpc-url")
+        .value_name("RPC_URL")
+        .help("Solana RPC endpoint")
+        .default_value("https://api.devnet.solana.com")
+        .required(false)
+}
+
+fn token_account_arg(help: &'static str) -> Arg {
+    Arg::new("token-account")
+        .short('a')
+        .long("token-account")
+        .value_name("TOKEN_ACCOUNT")
+        .help(help)
+        .required(true)
+}
+
+fn proofs_arg() -> Arg {
+    Arg::new("proofs")
+        .long("proofs")
+        .value_name("JSON_FILE")
+        .help("Proofs file written by `airdrop build-tree`")
+        .required(true)
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,202 @@
+use serde_json::{json, Map, Value};
+
+use crate::error::{ErrorKind, Failure};
+
+/// Output picked with the global `--output` flag
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    JsonCompact,
+}
+
+impl OutputFormat {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "json-compact" => Some(OutputFormat::JsonCompact),
+            _ => None,
+        }
+    }
+}
+
+/// What a command produced, rendered as lines of text or as the `result` object of the JSON output
+#[derive(Debug, Default)]
+pub struct Report {
+    lines: Vec<String>,
+    result: Map<String, Value>,
+    /// Set when the command ran to the end but some of its work failed
+    partial: Option<ErrorKind>,
+    /// Set when the command failed, the report then only carries the error
+    failure: Option<Failure>,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Report::default()
+    }
+
+    /// Line only shown in text output
+    pub fn line(&mut self, line: impl Into<String>) {
+        self.lines.push(line.into());
+    }
+
+    /// Value only in JSON output, under `key`
+    pub fn insert(&mut self, key: &str, value: impl Into<Value>) {
+        self.result.insert(key.to_string(), value.into());
+    }
+
+    /// Value shown as `label: value` in text output and under `key` in JSON output
+    pub fn field(&mut self, label: &str, key: &str, value: impl Into<Value>) {
+        let value = value.into();
+        let text = match &value {
+            Value::String(text) => text.clone(),
+            Value::Null => "none".to_string(),
+            value => value.to_string(),
+        };
+        self.lines.push(format!("{}: {}", label, text));
+        self.result.insert(key.to_string(), value);
+    }
+
+    /// Marks the command as partly failed, it exits with the code of `kind` and a "partial" JSON status
+    pub fn partial(&mut self, kind: ErrorKind) {
+        self.partial = Some(kind);
+    }
+
+    /// Report of a sent transaction, `message` is its text summary
+    pub fn signed(message: impl Into<String>, signature: impl ToString) -> Self {
+        let mut report = Report::new();
+        report.line(message);
+        report.field("Transaction Signature", "signature", signature.to_string());
+        report
+    }
+
+    /// Report of a failed command, its text line is the message prefixed by `context`
+    pub fn failed(context: &str, failure: Failure) -> Self {
+        let mut report = Report::new();
+        report.line(format!("{}: {}", context, failure.message));
+        report.failure = Some(failure);
+        report
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match (&self.failure, self.partial) {
+            (Some(failure), _) => failure.kind.exit_code(),
+            (None, Some(kind)) => kind.exit_code(),
+            (None, None) => 0,
+        }
+    }
+}
+
+/// Prints the outcome of `command` and returns the process exit code
+/// JSON goes to stdout either way, text errors go to stderr prefixed by `context`
+pub fn finish(format: OutputFormat, command: &str, context: &str, result: Result<Report, Box<dyn std::error::Error>>) -> i32 {
+    let report = result.unwrap_or_else(|error| Report::failed(context, Failure::from_error(error.as_ref())));
+    let exit_code = report.exit_code();
+    let to_stderr = format == OutputFormat::Text && report.failure.is_some();
+
+    let output = render(format, command, report);
+    if to_stderr {
+        eprint!("{}", output);
+    } else {
+        print!("{}", output);
+    }
+    exit_code
+}
+
+// Everything `finish` prints for `report`, one line per text line or a single JSON document
+fn render(format: OutputFormat, command: &str, mut report: Report) -> String {
+    if format == OutputFormat::Text {
+        return report.lines.iter().map(|line| format!("{}\n", line)).collect();
+    }
+    let document = match report.failure.take() {
+        Some(failure) => failure_json(command, failure),
+        None => success_json(command, report),
+    };
+    match format {
+        OutputFormat::JsonCompact => format!("{}\n", document),
+        _ => format!("{:#}\n", document),
+    }
+}
+
+fn success_json(command: &str, report: Report) -> Value {
+    json!({
+        "command": command,
+        "status": if report.partial.is_some() { "partial" } else { "ok" },
+        "result": report.result,
+    })
+}
+
+fn failure_json(command: &str, failure: Failure) -> Value {
+    json!({
+        "command": command,
+        "status": "error",
+        "error": {
+            "kind": failure.kind.as_str(),
+            "exit_code": failure.kind.exit_code(),
+            "message": failure.message,
+            "code": failure.code,
+            "program_error": failure.program_error,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_fields() {
+        let mut report = Report::new();
+        report.line("✅ Done");
+        report.field("Mint", "mint", "abc");
+        report.field("Supply", "supply", 5u64);
+        report.field("Authority", "authority", None::<String>);
+        report.insert("events", vec!["Launched"]);
+
+        assert_eq!(report.lines, ["✅ Done", "Mint: abc", "Supply: 5", "Authority: none"]);
+        assert_eq!(
+            success_json("show", report),
+            json!({
+                "command": "show",
+                "status": "ok",
+                "result": { "mint": "abc", "supply": 5, "authority": null, "events": ["Launched"] },
+            })
+        );
+    }
+
+    #[test]
+    fn test_partial_report() {
+        let mut report = Report::new();
+        report.field("Failed", "failed", 2);
+        report.partial(ErrorKind::Program);
+        assert_eq!(success_json("launch-batch", report)["status"], "partial");
+    }
+
+    #[test]
+    fn test_failure_schema() {
+        let failure = Failure { kind: ErrorKind::Program, message: "Symbol is already taken".to_string(), code: Some(27), program_error: Some("SymbolTaken".to_string()) };
+        assert_eq!(
+            failure_json("launch", failure),
+            json!({
+                "command": "launch",
+                "status": "error",
+                "error": { "kind": "program", "exit_code": 4, "message": "Symbol is already taken", "code": 27, "program_error": "SymbolTaken" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_failed_report_renders_once() {
+        let failure = Failure::new(ErrorKind::Rpc, "Connection refused".to_string());
+        let report = Report::failed("Error showing asset", failure.clone());
+        assert_eq!(report.exit_code(), 3);
+        assert_eq!(render(OutputFormat::Text, "show", report), "Error showing asset: Connection refused\n");
+
+        // JSON output is the document alone, without the text line
+        let output = render(OutputFormat::JsonCompact, "show", Report::failed("Error showing asset", failure.clone()));
+        assert_eq!(output.lines().count(), 1);
+        assert_eq!(serde_json::from_str::<Value>(&output).unwrap(), failure_json("show", failure));
+    }
+}
@@ -0,0 +1,332 @@
+#[cfg(test)]
+mod test_admin {
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        account::Account, bpf_loader_upgradeable, instruction::{Instruction, InstructionError}, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::{Transaction, TransactionError}
+    };
+    use token_launch::{
+        constants::TOKEN_PROGRAM_ID,
+        entrypoint::process_instruction,
+        error::LaunchpadError,
+        instruction,
+        state::{FeatureToggles, GlobalConfig, LaunchFees, PauseClass, PauseFlags},
+        util::{get_creator_profile_pda, get_global_config_pda, get_program_data_address},
+        AssetType, LaunchConfig,
+    };
+
+    const LEGACY_FEE: u64 = 100_000_000;
+
+    // Helper function to create program test environment, with a program data account naming `upgrade_authority`
+    fn create_program_test(upgrade_authority: &Pubkey) -> ProgramTest {
+        let mut program_test = ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        );
+
+        // UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address: Some(..) } in bincode
+        let mut data = 3u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(upgrade_authority.as_ref());
+
+        program_test.add_account(
+            get_program_data_address(&token_launch::id()),
+            Account {
+                lamports: 1_000_000_000,
+                data,
+                owner: bpf_loader_upgradeable::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        program_test
+    }
+
+    async fn process(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn fund(context: &mut ProgramTestContext, wallet: &Pubkey) {
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), wallet, 1_000_000_000);
+        process(context, &[transfer], &[]).await.unwrap();
+    }
+
+    async fn global_config(context: &mut ProgramTestContext) -> GlobalConfig {
+        let account = context.banks_client.get_account(get_global_config_pda(&token_launch::id()).0).await.unwrap().unwrap();
+        instruction::decode_global_config(&account.data).unwrap()
+    }
+
+    fn launch_fees() -> LaunchFees {
+        LaunchFees {
+            spl_token_legacy: LEGACY_FEE,
+            spl_token_2022: 0,
+            standard_nft: 0,
+        }
+    }
+
+    fn initialize_instruction(upgrade_authority: &Pubkey, admin: &Pubkey, treasury: &Pubkey) -> Instruction {
+        initialize_with_guardian_instruction(upgrade_authority, admin, admin, treasury)
+    }
+
+    fn initialize_with_guardian_instruction(upgrade_authority: &Pubkey, admin: &Pubkey, guardian: &Pubkey, treasury: &Pubkey) -> Instruction {
+        instruction::initialize_global_config(&token_launch::id(), upgrade_authority, *admin, *guardian, *treasury, launch_fees(), FeatureToggles::default())
+    }
+
+    fn withdraw_instruction(admin: &Pubkey, treasury: &Pubkey, amount: u64) -> Instruction {
+        instruction::withdraw_fees(&token_launch::id(), admin, treasury, amount)
+    }
+
+    // Launches recorded under `creator` so far, which is also the index of their next launch
+    async fn launch_count(context: &mut ProgramTestContext, creator: &Pubkey) -> u64 {
+        let creator_profile = get_creator_profile_pda(&token_launch::id(), creator).0;
+        match context.banks_client.get_account(creator_profile).await.unwrap() {
+            Some(account) => instruction::decode_creator_profile(&account.data).unwrap().launch_count,
+            None => 0,
+        }
+    }
+
+    // Launches a token capped at twice its initial supply, returning the mint and the creator's token account
+    async fn launch_token(context: &mut ProgramTestContext) -> Result<(Pubkey, Pubkey), solana_program_test::BanksClientError> {
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+
+        let config = LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Fee Token".to_string(),
+            symbol: "FEE".to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/fee-metadata.json".to_string(),
+            creator: context.payer.pubkey(),
+            is_mutable: false,
+            max_supply: 2_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        };
+
+        let creator = context.payer.pubkey();
+        let launch_index = launch_count(context, &creator).await;
+
+        let instruction = instruction::launch_asset(&token_launch::id(), &creator, &mint.pubkey(), &token_account.pubkey(), &TOKEN_PROGRAM_ID, launch_index, config);
+
+        process(context, &[instruction], &[&mint, &token_account]).await?;
+        Ok((mint.pubkey(), token_account.pubkey()))
+    }
+
+    // Mint-additional instruction, `with_config` decides whether the global config account is passed
+    fn mint_additional_instruction(creator: &Pubkey, mint: &Pubkey, destination: &Pubkey, with_config: bool) -> Instruction {
+        let mut instruction = instruction::mint_additional(&token_launch::id(), creator, mint, destination, &TOKEN_PROGRAM_ID, 1);
+        if !with_config {
+            instruction.accounts.pop();
+        }
+        instruction
+    }
+
+    // Starts a test validator with the global config initialized, returning the admin
+    async fn setup() -> (ProgramTestContext, Keypair, Pubkey) {
+        let upgrade_authority = Keypair::new();
+        let mut context = create_program_test(&upgrade_authority.pubkey()).start_with_context().await;
+        fund(&mut context, &upgrade_authority.pubkey()).await;
+
+        let admin = Keypair::new();
+        fund(&mut context, &admin.pubkey()).await;
+        let treasury = Pubkey::new_unique();
+
+        let initialize = initialize_instruction(&upgrade_authority.pubkey(), &admin.pubkey(), &treasury);
+        process(&mut context, &[initialize], &[&upgrade_authority]).await.unwrap();
+
+        (context, admin, treasury)
+    }
+
+    #[tokio::test]
+    async fn test_initialize_requires_upgrade_authority() {
+        let upgrade_authority = Keypair::new();
+        let mut context = create_program_test(&upgrade_authority.pubkey()).start_with_context().await;
+
+        let impostor = Keypair::new();
+        fund(&mut context, &impostor.pubkey()).await;
+        let initialize = initialize_instruction(&impostor.pubkey(), &impostor.pubkey(), &Pubkey::new_unique());
+        let result = process(&mut context, &[initialize], &[&impostor]).await;
+        assert!(result.is_err(), "Only the upgrade authority may initialize the config");
+
+        fund(&mut context, &upgrade_authority.pubkey()).await;
+        let admin = Pubkey::new_unique();
+        let initialize = initialize_instruction(&upgrade_authority.pubkey(), &admin, &Pubkey::new_unique());
+        process(&mut context, &[initialize], &[&upgrade_authority]).await.unwrap();
+
+        let config = global_config(&mut context).await;
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.launch_fees, launch_fees());
+    }
+
+    #[tokio::test]
+    async fn test_initialize_with_prefunded_config() {
+        let upgrade_authority = Keypair::new();
+        let mut context = create_program_test(&upgrade_authority.pubkey()).start_with_context().await;
+        fund(&mut context, &upgrade_authority.pubkey()).await;
+
+        // Anyone can send lamports to the config address before it is initialized
+        let global_config_pda = get_global_config_pda(&token_launch::id()).0;
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &global_config_pda, 1_000_000);
+        process(&mut context, &[transfer], &[]).await.unwrap();
+
+        let admin = Pubkey::new_unique();
+        let initialize = initialize_instruction(&upgrade_authority.pubkey(), &admin, &Pubkey::new_unique());
+        process(&mut context, &[initialize], &[&upgrade_authority]).await.unwrap();
+
+        let config = global_config(&mut context).await;
+        assert_eq!(config.admin, admin);
+        let account = context.banks_client.get_account(global_config_pda).await.unwrap().unwrap();
+        assert_eq!(account.owner, token_launch::id());
+    }
+
+    #[tokio::test]
+    async fn test_launch_is_free_without_config() {
+        let mut context = create_program_test(&Pubkey::new_unique()).start_with_context().await;
+        launch_token(&mut context).await.unwrap();
+
+        let global_config = get_global_config_pda(&token_launch::id()).0;
+        assert!(context.banks_client.get_account(global_config).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_launch_collects_fee_and_admin_withdraws() {
+        let (mut context, admin, treasury) = setup().await;
+        let global_config = get_global_config_pda(&token_launch::id()).0;
+        let balance_before = context.banks_client.get_balance(global_config).await.unwrap();
+
+        launch_token(&mut context).await.unwrap();
+        assert_eq!(context.banks_client.get_balance(global_config).await.unwrap(), balance_before + LEGACY_FEE);
+
+        let result = process(&mut context, &[withdraw_instruction(&admin.pubkey(), &treasury, LEGACY_FEE + 1)], &[&admin]).await;
+        assert!(result.is_err(), "Withdrawing into the rent exempt balance should fail");
+
+        let result = process(&mut context, &[withdraw_instruction(&admin.pubkey(), &Pubkey::new_unique(), LEGACY_FEE)], &[&admin]).await;
+        assert!(result.is_err(), "Fees may only go to the treasury");
+
+        process(&mut context, &[withdraw_instruction(&admin.pubkey(), &treasury, LEGACY_FEE)], &[&admin]).await.unwrap();
+        assert_eq!(context.banks_client.get_balance(treasury).await.unwrap(), LEGACY_FEE);
+        assert_eq!(context.banks_client.get_balance(global_config).await.unwrap(), balance_before);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_asset_type_cannot_launch() {
+        let (mut context, admin, _) = setup().await;
+
+        let toggles = FeatureToggles {
+            spl_token_legacy_launches: false,
+            ..FeatureToggles::default()
+        };
+        let update = instruction::update_global_config(&token_launch::id(), &admin.pubkey(), None, None, Some(toggles), None);
+        process(&mut context, &[update], &[&admin]).await.unwrap();
+
+        let result = launch_token(&mut context).await;
+        assert!(result.is_err(), "Launch of a disabled asset type should fail");
+    }
+
+    #[tokio::test]
+    async fn test_update_fees_and_rotate_admin() {
+        let (mut context, admin, _) = setup().await;
+
+        let stranger = Keypair::new();
+        fund(&mut context, &stranger.pubkey()).await;
+        let free = LaunchFees { spl_token_legacy: 0, spl_token_2022: 0, standard_nft: 0 };
+        let update = |signer: &Pubkey| instruction::update_global_config(&token_launch::id(), signer, Some(free.clone()), None, None, None);
+
+        let error = process(&mut context, &[update(&stranger.pubkey())], &[&stranger]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAdmin as u32)), "Only the admin may update fees");
+
+        process(&mut context, &[update(&admin.pubkey())], &[&admin]).await.unwrap();
+        assert_eq!(global_config(&mut context).await.launch_fees, free);
+
+        let rotate = instruction::rotate_admin(&token_launch::id(), &admin.pubkey(), stranger.pubkey());
+        process(&mut context, &[rotate], &[&admin]).await.unwrap();
+        assert_eq!(global_config(&mut context).await.admin, stranger.pubkey());
+
+        // Fresh blockhash so the retry isn't deduplicated as the same transaction
+        context.get_new_latest_blockhash().await.unwrap();
+        let error = process(&mut context, &[update(&admin.pubkey())], &[&admin]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAdmin as u32)), "The previous admin has lost access");
+        process(&mut context, &[update(&stranger.pubkey())], &[&stranger]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_guardian_pauses_and_unpauses_launches() {
+        let upgrade_authority = Keypair::new();
+        let mut context = create_program_test(&upgrade_authority.pubkey()).start_with_context().await;
+        fund(&mut context, &upgrade_authority.pubkey()).await;
+
+        let admin = Keypair::new();
+        let guardian = Keypair::new();
+        fund(&mut context, &admin.pubkey()).await;
+        fund(&mut context, &guardian.pubkey()).await;
+
+        let initialize = initialize_with_guardian_instruction(&upgrade_authority.pubkey(), &admin.pubkey(), &guardian.pubkey(), &Pubkey::new_unique());
+        process(&mut context, &[initialize], &[&upgrade_authority]).await.unwrap();
+
+        let pause = |signer: &Pubkey| instruction::set_paused(&token_launch::id(), signer, PauseClass::Launch, true);
+        let error = process(&mut context, &[pause(&admin.pubkey())], &[&admin]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotGuardian as u32)), "Only the guardian may pause");
+
+        process(&mut context, &[pause(&guardian.pubkey())], &[&guardian]).await.unwrap();
+        assert_eq!(global_config(&mut context).await.paused, PauseFlags { launch: true, sale: false, update: false });
+
+        let result = launch_token(&mut context).await;
+        assert!(result.is_err(), "Launches should fail while paused");
+
+        let unpause = instruction::set_paused(&token_launch::id(), &guardian.pubkey(), PauseClass::Launch, false);
+        process(&mut context, &[unpause], &[&guardian]).await.unwrap();
+        assert_eq!(global_config(&mut context).await.paused, PauseFlags::default());
+
+        launch_token(&mut context).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pause_is_per_class() {
+        let (mut context, admin, _) = setup().await;
+        let creator = context.payer.pubkey();
+        let (mint, token_account) = launch_token(&mut context).await.unwrap();
+
+        // The admin doubles as guardian when none is given
+        let pause = instruction::set_paused(&token_launch::id(), &admin.pubkey(), PauseClass::Update, true);
+        process(&mut context, &[pause], &[&admin]).await.unwrap();
+
+        let result = process(&mut context, &[mint_additional_instruction(&creator, &mint, &token_account, true)], &[]).await;
+        assert!(result.is_err(), "Update instructions should fail while paused");
+
+        launch_token(&mut context).await.unwrap();
+
+        let unpause = instruction::set_paused(&token_launch::id(), &admin.pubkey(), PauseClass::Update, false);
+        process(&mut context, &[unpause], &[&admin]).await.unwrap();
+        context.get_new_latest_blockhash().await.unwrap();
+        process(&mut context, &[mint_additional_instruction(&creator, &mint, &token_account, true)], &[]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_paused_instruction_requires_config_account() {
+        let mut context = create_program_test(&Pubkey::new_unique()).start_with_context().await;
+        let creator = context.payer.pubkey();
+        let (mint, token_account) = launch_token(&mut context).await.unwrap();
+
+        let result = process(&mut context, &[mint_additional_instruction(&creator, &mint, &token_account, false)], &[]).await;
+        assert!(result.is_err(), "Instructions without the global config account should be rejected");
+
+        process(&mut context, &[mint_additional_instruction(&creator, &mint, &token_account, true)], &[]).await.unwrap();
+    }
+}
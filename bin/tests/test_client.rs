@@ -0,0 +1,272 @@
+#[cfg(test)]
+mod test_client {
+    use solana_program_test::{processor, BanksClient, ProgramTest, ProgramTestContext};
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::Transaction};
+    use solana_sdk::instruction::Instruction;
+    use token_launch::{entrypoint::process_instruction, instruction, AssetType, LaunchConfig};
+    use token_launch_client::{decode_transaction, ClientError, LaunchOptions, LaunchpadClient, Submission};
+
+    async fn start() -> ProgramTestContext {
+        ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        )
+        .start_with_context()
+        .await
+    }
+
+    // Client over the context's bank, paid by the context payer
+    fn client(context: &ProgramTestContext) -> LaunchpadClient<BanksClient, Keypair> {
+        LaunchpadClient::new(context.banks_client.clone(), context.payer.insecure_clone(), token_launch::id())
+    }
+
+    fn launch_config(creator: &Pubkey, symbol: &str) -> LaunchConfig {
+        LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Client Token".to_string(),
+            symbol: symbol.to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/client-metadata.json".to_string(),
+            creator: *creator,
+            is_mutable: false,
+            max_supply: 5_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_launch_and_fetch_asset() {
+        let context = start().await;
+        let client = client(&context);
+        let creator = context.payer.pubkey();
+
+        let result = client.launch(launch_config(&creator, "CLT"), LaunchOptions::default()).await.unwrap();
+        assert!(result.signature().is_some());
+        assert_eq!(result.launch_index, 0);
+
+        let asset = client.fetch_asset(&result.mint).await.unwrap();
+        assert_eq!(asset.creator, creator);
+        assert_eq!(asset.mint, result.mint);
+        assert_eq!(asset.symbol(), "CLT");
+        assert_eq!(asset.total_supply.get(), 1_000_000);
+
+        assert_eq!(client.next_launch_index(&creator).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_launched_mints_match_their_records() {
+        let context = start().await;
+        let client = client(&context);
+        let creator = context.payer.pubkey();
+
+        for (asset_type, symbol) in [(AssetType::SplTokenLegacy, "MLG"), (AssetType::SplToken2022, "M22"), (AssetType::StandardNft, "MNFT")] {
+            let mut config = launch_config(&creator, symbol);
+            if asset_type == AssetType::StandardNft {
+                config.decimals = 0;
+                config.total_supply = 1;
+                config.max_supply = 1;
+            }
+            config.asset_type = asset_type;
+            let result = client.launch(config, LaunchOptions::default()).await.unwrap();
+
+            let asset = client.fetch_asset(&result.mint).await.unwrap();
+            let mint = client.fetch_mint(&result.mint).await.unwrap();
+            assert_eq!(mint.supply, asset.circulating_supply.get());
+            assert_eq!(mint.freeze_authority, Some(creator));
+            assert_eq!(mint.discrepancies(&token_launch::id(), &result.mint, &asset), vec![]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_launch_without_initial_supply() {
+        let context = start().await;
+        let client = client(&context);
+        let creator = context.payer.pubkey();
+
+        // No token account is created, so the launch is sent without its keypair's signature
+        let config = LaunchConfig { total_supply: 0, ..launch_config(&creator, "ZRO") };
+        let result = client.launch(config, LaunchOptions::default()).await.unwrap();
+
+        let asset = client.fetch_asset(&result.mint).await.unwrap();
+        assert_eq!(asset.total_supply.get(), 0);
+        assert!(context.banks_client.clone().get_account(result.token_account).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_launch_rejects_creator_mismatch() {
+        let context = start().await;
+        let client = client(&context);
+        let other = Pubkey::new_unique();
+
+        let error = client.launch(launch_config(&other, "CLT"), LaunchOptions::default()).await.unwrap_err();
+        assert!(matches!(error, ClientError::CreatorMismatch { config, .. } if config == other));
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_mint_keypair() {
+        let context = start().await;
+        let client = client(&context);
+        let creator = context.payer.pubkey();
+        let mint = Keypair::new();
+
+        let options = LaunchOptions { mint: Some(&mint), ..LaunchOptions::default() };
+        let result = client.launch(launch_config(&creator, "KEY"), options).await.unwrap();
+        assert_eq!(result.mint, mint.pubkey());
+
+        // Retrying with the same keypair can't launch the mint twice
+        let options = LaunchOptions { mint: Some(&mint), ..LaunchOptions::default() };
+        let error = client.launch(launch_config(&creator, "KEY"), options).await.unwrap_err();
+        assert!(error.transaction_error().is_some());
+        assert_eq!(client.next_launch_index(&creator).await.unwrap(), 1);
+
+        let mut config = launch_config(&creator, "DET");
+        config.deterministic_mint = true;
+        let options = LaunchOptions { mint: Some(&mint), ..LaunchOptions::default() };
+        let error = client.launch(config, options).await.unwrap_err();
+        assert!(matches!(error, ClientError::DeterministicMintKeypair));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_missing_asset() {
+        let context = start().await;
+        let client = client(&context);
+
+        let error = client.fetch_asset(&Pubkey::new_unique()).await.unwrap_err();
+        assert!(matches!(error, ClientError::AccountNotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_simulate_prepared_launch() {
+        let context = start().await;
+        let client = client(&context);
+
+        // Nothing is committed, so the asset doesn't exist afterwards
+        let launch = client.prepare_launch(launch_config(&context.payer.pubkey(), "SIM")).await.unwrap();
+        let simulation = client.simulate(std::slice::from_ref(&launch.instruction)).await.unwrap();
+        assert_eq!(simulation.err, None);
+        assert!(simulation.units_consumed.unwrap() > 0);
+        assert!(matches!(client.fetch_asset(&launch.mint).await, Err(ClientError::AccountNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_relayer_cosigns_launch() {
+        let context = start().await;
+        let relayer = client(&context);
+        let creator = Keypair::new();
+        let creator_client = LaunchpadClient::new(context.banks_client.clone(), creator.insecure_clone(), token_launch::id());
+
+        let options = LaunchOptions { creator: None, relayer: Some(context.payer.pubkey()), mint: None };
+        let result = creator_client.launch(launch_config(&creator.pubkey(), "RLY"), options).await.unwrap();
+        let Submission::PartiallySigned(encoded) = &result.submission else {
+            panic!("Relayed launch was sent");
+        };
+
+        relayer.cosign(decode_transaction(encoded).unwrap()).await.unwrap();
+        let asset = relayer.fetch_asset(&result.mint).await.unwrap();
+        assert_eq!(asset.creator, creator.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_cosign_refuses_foreign_program() {
+        let context = start().await;
+        let relayer = client(&context);
+
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &Pubkey::new_unique(), 1_000_000);
+        let recent_blockhash = context.banks_client.clone().get_latest_blockhash().await.unwrap();
+        let mut tx = Transaction::new_with_payer(&[transfer], Some(&context.payer.pubkey()));
+        tx.message.recent_blockhash = recent_blockhash;
+
+        let error = relayer.cosign(tx).await.unwrap_err();
+        assert!(matches!(error, ClientError::ForeignProgram));
+    }
+
+    // Unsigned transaction of `instructions` paid by the context payer, as a relayer would receive it
+    async fn relayed(context: &ProgramTestContext, instructions: &[Instruction]) -> Transaction {
+        let recent_blockhash = context.banks_client.clone().get_latest_blockhash().await.unwrap();
+        let mut tx = Transaction::new_with_payer(instructions, Some(&context.payer.pubkey()));
+        tx.message.recent_blockhash = recent_blockhash;
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_cosign_refuses_contribution() {
+        let context = start().await;
+        let relayer = client(&context);
+
+        let contribute = instruction::contribute(&token_launch::id(), &context.payer.pubkey(), &Pubkey::new_unique(), 1_000_000);
+        let error = relayer.cosign(relayed(&context, &[contribute]).await).await.unwrap_err();
+        assert!(matches!(error, ClientError::NotALaunch));
+    }
+
+    #[tokio::test]
+    async fn test_cosign_refuses_presale() {
+        let context = start().await;
+        let relayer = client(&context);
+
+        let create_presale = instruction::create_presale(
+            &token_launch::id(),
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &spl_token::id(),
+            1_000,
+            1_000,
+            0,
+        );
+        let error = relayer.cosign(relayed(&context, &[create_presale]).await).await.unwrap_err();
+        assert!(matches!(error, ClientError::NotALaunch));
+    }
+
+    #[tokio::test]
+    async fn test_cosign_refuses_voucher_signer() {
+        let context = start().await;
+        let relayer = client(&context);
+
+        let register = instruction::register_voucher_signer(
+            &token_launch::id(),
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &spl_token::id(),
+            Pubkey::new_unique(),
+            1_000,
+        );
+        let error = relayer.cosign(relayed(&context, &[register]).await).await.unwrap_err();
+        assert!(matches!(error, ClientError::NotALaunch));
+    }
+
+    #[tokio::test]
+    async fn test_cosign_refuses_several_launches() {
+        let context = start().await;
+        let relayer = client(&context);
+        let creator = Keypair::new();
+        let creator_client = LaunchpadClient::new(context.banks_client.clone(), creator.insecure_clone(), token_launch::id());
+
+        let options = LaunchOptions { creator: None, relayer: Some(context.payer.pubkey()), mint: None };
+        let result = creator_client.launch(launch_config(&creator.pubkey(), "TWO"), options).await.unwrap();
+        let Submission::PartiallySigned(encoded) = &result.submission else {
+            panic!("Relayed launch was sent");
+        };
+
+        let mut tx = decode_transaction(encoded).unwrap();
+        tx.message.instructions.push(tx.message.instructions[0].clone());
+        let error = relayer.cosign(tx).await.unwrap_err();
+        assert!(matches!(error, ClientError::NotALaunch));
+    }
+
+    #[tokio::test]
+    async fn test_cosign_refuses_relayer_as_creator() {
+        let context = start().await;
+        let relayer = client(&context);
+
+        // As creator too, the relayer would sign for the supply and accounts the creator should fund
+        let launch = relayer.prepare_launch(launch_config(&context.payer.pubkey(), "OWN")).await.unwrap();
+        let error = relayer.cosign(relayed(&context, &[launch.instruction]).await).await.unwrap_err();
+        assert!(matches!(error, ClientError::RelayerNotPayer));
+    }
+}
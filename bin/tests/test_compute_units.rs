@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod test_compute_units {
+    use std::{collections::BTreeMap, fs, path::PathBuf};
+
+    use solana_program_test::{processor, ProgramTest};
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+    use token_launch::{
+        constants::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+        entrypoint::process_instruction,
+        instruction,
+        AssetType, LaunchConfig,
+    };
+
+    // Budgets per program build and asset type, a launch consuming more fails the benchmark
+    const BASELINE_FILE: &str = "tests/compute_units.json";
+
+    type Baseline = BTreeMap<String, BTreeMap<String, u64>>;
+
+    // `cargo test-sbf` runs the built program, a plain `cargo test` the native processor, which meters CPIs only
+    fn program_build() -> &'static str {
+        if std::env::var("BPF_OUT_DIR").is_ok() || std::env::var("SBF_OUT_DIR").is_ok() {
+            "sbf"
+        } else {
+            "builtin"
+        }
+    }
+
+    fn baseline_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(BASELINE_FILE)
+    }
+
+    // Units `program_id` consumed itself at the top level, excluding what the programs it invoked consumed
+    fn own_compute_units(logs: &[String], program_id: &Pubkey) -> Option<u64> {
+        let consumed = format!("Program {} consumed ", program_id);
+
+        // Each frame holds the units its children consumed and, once logged, its own total
+        let mut stack: Vec<(u64, Option<u64>)> = Vec::new();
+        let mut own = None;
+        for line in logs {
+            let Some(rest) = line.strip_prefix("Program ") else { continue };
+            if ["log: ", "data: ", "return: "].iter().any(|prefix| rest.starts_with(prefix)) {
+                continue;
+            }
+            if rest.contains(" invoke [") {
+                stack.push((0, None));
+            } else if let Some(units) = rest.split(" consumed ").nth(1).and_then(|rest| rest.split(' ').next()) {
+                let units: u64 = units.parse().ok()?;
+                if stack.len() == 1 && line.starts_with(&consumed) {
+                    own = Some(units - stack[0].0);
+                }
+                stack.last_mut()?.1 = Some(units);
+            } else if rest.ends_with(" success") || rest.contains(" failed: ") {
+                let (_, units) = stack.pop()?;
+                if let (Some(parent), Some(units)) = (stack.last_mut(), units) {
+                    parent.0 += units;
+                }
+            }
+        }
+        own
+    }
+
+    #[test]
+    fn test_own_compute_units_excludes_invoked_programs() {
+        let program_id = token_launch::id();
+        let token_program = TOKEN_PROGRAM_ID;
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Launching".to_string(),
+            "Program 11111111111111111111111111111111 invoke [2]".to_string(),
+            "Program 11111111111111111111111111111111 success".to_string(),
+            format!("Program {} invoke [2]", token_program),
+            format!("Program {} consumed 2900 of 190000 compute units", token_program),
+            format!("Program {} success", token_program),
+            format!("Program {} invoke [2]", token_program),
+            format!("Program {} consumed 4500 of 180000 compute units", token_program),
+            format!("Program {} success", token_program),
+            format!("Program {} consumed 20400 of 200000 compute units", program_id),
+            format!("Program {} success", program_id),
+        ];
+
+        assert_eq!(own_compute_units(&logs, &program_id), Some(13_000));
+        assert_eq!(own_compute_units(&logs, &token_program), None);
+    }
+
+    // Compute units a launch of `asset_type` with an initial supply is measured by, the payer is the creator
+    // The native processor isn't metered so builtin runs count the whole transaction, which is only its CPIs,
+    // while sbf runs count the launchpad's own units so token program changes don't move its budget
+    async fn launch_compute_units(asset_type: AssetType) -> u64 {
+        let program_test = ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        );
+        let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+        let (token_program, decimals, total_supply) = match asset_type {
+            AssetType::SplTokenLegacy => (TOKEN_PROGRAM_ID, 6, 1_000_000),
+            AssetType::SplToken2022 => (TOKEN_2022_PROGRAM_ID, 6, 1_000_000),
+            AssetType::StandardNft => (TOKEN_PROGRAM_ID, 0, 1),
+        };
+        let config = LaunchConfig {
+            asset_type,
+            name: "Benchmark Token".to_string(),
+            symbol: "BNCH".to_string(),
+            decimals,
+            total_supply,
+            metadata_uri: "https://example.com/benchmark-metadata.json".to_string(),
+            creator: payer.pubkey(),
+            is_mutable: false,
+            max_supply: total_supply,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        };
+
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
+            &payer.pubkey(),
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &token_program,
+            0,
+            config,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[&payer, &mint, &token_account],
+            recent_blockhash,
+        );
+
+        let result = banks_client.process_transaction_with_metadata(tx).await.unwrap();
+        result.result.unwrap();
+        let metadata = result.metadata.unwrap();
+        match program_build() {
+            "sbf" => own_compute_units(&metadata.log_messages, &token_launch::id()).expect("launch logs are missing the consumed units"),
+            _ => metadata.compute_units_consumed,
+        }
+    }
+
+    // Set CU_BASELINE=record to write the measured units as the new budgets
+    #[tokio::test]
+    async fn test_launch_compute_units() {
+        let mut measured = BTreeMap::new();
+        for asset_type in [AssetType::SplTokenLegacy, AssetType::SplToken2022, AssetType::StandardNft] {
+            let name = format!("{:?}", asset_type);
+            let units = launch_compute_units(asset_type).await;
+            println!("{} launch ({}): {} CU", name, program_build(), units);
+            measured.insert(name, units);
+        }
+
+        let path = baseline_path();
+        let mut baseline: Baseline = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap(),
+            Err(_) => Baseline::new(),
+        };
+
+        // A build without budgets yet starts from its first measurement, commit the file to enforce them
+        if std::env::var("CU_BASELINE").as_deref() == Ok("record") || !baseline.contains_key(program_build()) {
+            println!("Recording {} budgets in {}", program_build(), BASELINE_FILE);
+            baseline.insert(program_build().to_string(), measured);
+            fs::write(&path, serde_json::to_string_pretty(&baseline).unwrap() + "\n").unwrap();
+            return;
+        }
+
+        let budgets = &baseline[program_build()];
+
+        let regressions: Vec<String> = measured
+            .iter()
+            .filter_map(|(name, units)| {
+                let budget = budgets.get(name).copied().unwrap_or(0);
+                (*units > budget).then(|| format!("{} consumed {} CU, budget {}", name, units, budget))
+            })
+            .collect();
+        assert!(regressions.is_empty(), "Compute unit regressions:\n{}", regressions.join("\n"));
+    }
+}
@@ -1,17 +1,14 @@
 #[cfg(test)]
 mod test_configs {
-    use borsh::BorshSerialize;
     use token_launch::{
         constants::TOKEN_PROGRAM_ID, 
         entrypoint::process_instruction, 
+        instruction,
         LaunchConfig, 
-        LaunchpadInstruction,
         state::AssetType,
     };
     use solana_program_test::{processor, ProgramTest};
     use solana_sdk::{
-        instruction::{AccountMeta, Instruction}, 
-        pubkey::Pubkey, 
         signature::Keypair, 
         signer::Signer, 
         transaction::Transaction
@@ -26,36 +23,6 @@ mod test_configs {
         )
     }
 
-    // Helper function to create launch instruction
-    fn create_launch_instruction(
-        payer: &Pubkey,
-        mint: &Pubkey,
-        token_account: &Pubkey,
-        metadata_account: &Pubkey,
-        token_program: &Pubkey,
-        config: LaunchConfig,
-    ) -> Instruction {
-        let accounts = vec![
-            AccountMeta::new(*payer, true),
-            AccountMeta::new(*mint, true),
-            AccountMeta::new(*token_account, true),
-            AccountMeta::new(*metadata_account, false),
-            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
-            AccountMeta::new_readonly(*token_program, false),
-            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
-        ];
-
-        let data = LaunchpadInstruction::LaunchAsset { config }
-            .try_to_vec()
-            .unwrap();
-
-        Instruction {
-            program_id: token_launch::id(),
-            accounts,
-            data,
-        }
-    }
-
     #[tokio::test]
     async fn test_launch_with_maximum_supply() {
         let program_test = create_program_test();
@@ -63,11 +30,6 @@ mod test_configs {
 
         let mint = Keypair::new();
         let token_account = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(
-            &[b"launched_asset", mint.pubkey().as_ref()], 
-            &token_launch::id()
-        ).0;
-
         let config = LaunchConfig {
             asset_type: AssetType::SplTokenLegacy,
             name: "Max Supply Token".to_string(),
@@ -77,14 +39,20 @@ mod test_configs {
             metadata_uri: "https://example.com/max-metadata.json".to_string(),
             creator: payer.pubkey(),
             is_mutable: true,
+            max_supply: u64::MAX,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
-        let instruction = create_launch_instruction(
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
             &payer.pubkey(),
             &mint.pubkey(),
             &token_account.pubkey(),
-            &metadata_account,
             &TOKEN_PROGRAM_ID,
+            0,
             config,
         );
 
@@ -106,11 +74,6 @@ mod test_configs {
 
         let mint = Keypair::new();
         let token_account = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(
-            &[b"launched_asset", mint.pubkey().as_ref()], 
-            &token_launch::id()
-        ).0;
-
         let config = LaunchConfig {
             asset_type: AssetType::SplTokenLegacy,
             name: "Invalid Decimals Token".to_string(),
@@ -120,14 +83,20 @@ mod test_configs {
             metadata_uri: "https://example.com/invalid-metadata.json".to_string(),
             creator: payer.pubkey(),
             is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
-        let instruction = create_launch_instruction(
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
             &payer.pubkey(),
             &mint.pubkey(),
             &token_account.pubkey(),
-            &metadata_account,
             &TOKEN_PROGRAM_ID,
+            0,
             config,
         );
 
@@ -149,11 +118,6 @@ mod test_configs {
 
         let mint = Keypair::new();
         let token_account = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(
-            &[b"launched_asset", mint.pubkey().as_ref()], 
-            &token_launch::id()
-        ).0;
-
         let long_name = "A".repeat(100); // Very long name, Max allowed is 32
         let config = LaunchConfig {
             asset_type: AssetType::SplTokenLegacy,
@@ -164,14 +128,20 @@ mod test_configs {
             metadata_uri: "https://example.com/long-metadata.json".to_string(),
             creator: payer.pubkey(),
             is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
-        let instruction = create_launch_instruction(
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
             &payer.pubkey(),
             &mint.pubkey(),
             &token_account.pubkey(),
-            &metadata_account,
             &TOKEN_PROGRAM_ID,
+            0,
             config,
         );
 
@@ -194,11 +164,6 @@ mod test_configs {
 
         let mint = Keypair::new();
         let token_account = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(
-            &[b"launched_asset", mint.pubkey().as_ref()], 
-            &token_launch::id()
-        ).0;
-
         let config = LaunchConfig {
             asset_type: AssetType::SplTokenLegacy,
             name: "Invalid URI Token".to_string(),
@@ -208,14 +173,20 @@ mod test_configs {
             metadata_uri: "not-a-valid-uri".to_string(), // Invalid URI
             creator: payer.pubkey(),
             is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
-        let instruction = create_launch_instruction(
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
             &payer.pubkey(),
             &mint.pubkey(),
             &token_account.pubkey(),
-            &metadata_account,
             &TOKEN_PROGRAM_ID,
+            0,
             config,
         );
 
@@ -239,11 +210,6 @@ mod test_configs {
         let mint = Keypair::new();
         let token_account1 = Keypair::new();
         let token_account2 = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(
-            &[b"launched_asset", mint.pubkey().as_ref()], 
-            &token_launch::id()
-        ).0;
-
         let config = LaunchConfig {
             asset_type: AssetType::SplTokenLegacy,
             name: "Duplicate Mint Token".to_string(),
@@ -253,15 +219,21 @@ mod test_configs {
             metadata_uri: "https://example.com/dup-metadata.json".to_string(),
             creator: payer.pubkey(),
             is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
         // First launch
-        let instruction1 = create_launch_instruction(
+        let instruction1 = instruction::launch_asset(
+            &token_launch::id(),
             &payer.pubkey(),
             &mint.pubkey(),
             &token_account1.pubkey(),
-            &metadata_account,
             &TOKEN_PROGRAM_ID,
+            0,
             config.clone(),
         );
 
@@ -275,12 +247,13 @@ mod test_configs {
         let result1 = banks_client.process_transaction(tx1).await;
         
         // Second launch with same mint should fail
-        let instruction2 = create_launch_instruction(
+        let instruction2 = instruction::launch_asset(
+            &token_launch::id(),
             &payer.pubkey(),
             &mint.pubkey(),
             &token_account2.pubkey(),
-            &metadata_account,
             &TOKEN_PROGRAM_ID,
+            1,
             config,
         );
 
@@ -0,0 +1,331 @@
+#[cfg(test)]
+mod test_creator {
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError}, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::{Transaction, TransactionError}
+    };
+    use token_launch::{
+        constants::TOKEN_PROGRAM_ID,
+        error::LaunchpadError,
+        entrypoint::process_instruction,
+        instruction::{self, decode_creator_profile, decode_launch_index},
+        state::{CreatorProfile, LaunchIndex},
+        util::{get_creator_profile_pda, get_deterministic_mint_pda, get_launch_index_pda},
+        AssetType, LaunchConfig,
+    };
+
+    // Helper function to create program test environment
+    fn create_program_test() -> ProgramTest {
+        ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        )
+    }
+
+    async fn process(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn creator_profile(context: &mut ProgramTestContext, creator: &Pubkey) -> CreatorProfile {
+        let creator_profile = get_creator_profile_pda(&token_launch::id(), creator).0;
+        let account = context.banks_client.get_account(creator_profile).await.unwrap().unwrap();
+        decode_creator_profile(&account.data).unwrap()
+    }
+
+    async fn launch_index(context: &mut ProgramTestContext, creator: &Pubkey, index: u64) -> LaunchIndex {
+        let launch_index = get_launch_index_pda(&token_launch::id(), creator, index).0;
+        let account = context.banks_client.get_account(launch_index).await.unwrap().unwrap();
+        decode_launch_index(&account.data).unwrap()
+    }
+
+    // Legacy token launch for `creator` recorded at `index`, with `payer` funding it
+    fn launch_instruction(payer: &Pubkey, creator: &Pubkey, index: u64, mint: &Pubkey, token_account: &Pubkey, deterministic_mint: bool) -> Instruction {
+        let config = LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: format!("Creator Token {}", index),
+            symbol: format!("CT{}", index),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/creator-metadata.json".to_string(),
+            creator: *creator,
+            is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint,
+            extensions: Vec::new(),
+        };
+
+        instruction::launch_asset(&token_launch::id(), payer, mint, token_account, &TOKEN_PROGRAM_ID, index, config)
+    }
+
+    // Launches a legacy token for `creator`, recording it at `index`, and returns the mint
+    async fn launch_token(context: &mut ProgramTestContext, creator: &Keypair, index: u64) -> Result<Pubkey, solana_program_test::BanksClientError> {
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+
+        let instruction = launch_instruction(&context.payer.pubkey(), &creator.pubkey(), index, &mint.pubkey(), &token_account.pubkey(), false);
+        process(context, &[instruction], &[&mint, &token_account, creator]).await?;
+        Ok(mint.pubkey())
+    }
+
+    fn update_profile_instruction(creator: &Pubkey, name: &str) -> Instruction {
+        instruction::update_creator_profile(
+            &token_launch::id(),
+            creator,
+            name.to_string(),
+            "https://example.com".to_string(),
+            "x:@creator".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_launches_are_indexed_in_order() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator_keypair = Keypair::new();
+        let creator = creator_keypair.pubkey();
+
+        let mut mints = Vec::new();
+        for index in 0..3 {
+            mints.push(launch_token(&mut context, &creator_keypair, index).await.unwrap());
+        }
+
+        let profile = creator_profile(&mut context, &creator).await;
+        assert_eq!(profile.creator, creator);
+        assert_eq!(profile.launch_count, 3);
+
+        for (index, mint) in mints.iter().enumerate() {
+            let launch_index = launch_index(&mut context, &creator, index as u64).await;
+            assert_eq!(launch_index.creator, creator);
+            assert_eq!(launch_index.index, index as u64);
+            assert_eq!(launch_index.mint, *mint);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_stale_index_fails() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator = Keypair::new();
+
+        launch_token(&mut context, &creator, 0).await.unwrap();
+
+        let result = launch_token(&mut context, &creator, 0).await;
+        assert!(result.is_err(), "Launch index 0 is already taken");
+
+        let result = launch_token(&mut context, &creator, 2).await;
+        assert!(result.is_err(), "Launch indexes can't skip ahead");
+
+        launch_token(&mut context, &creator, 1).await.unwrap();
+        assert_eq!(creator_profile(&mut context, &creator.pubkey()).await.launch_count, 2);
+    }
+
+    // Sends `lamports` to `address` before it exists, which anyone can do to a PDA they can derive
+    async fn prefund(context: &mut ProgramTestContext, address: &Pubkey) {
+        let lamports = context.banks_client.get_rent().await.unwrap().minimum_balance(0);
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), address, lamports);
+        process(context, &[transfer], &[]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_prefunded_pdas() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator = Keypair::new();
+
+        prefund(&mut context, &get_creator_profile_pda(&token_launch::id(), &creator.pubkey()).0).await;
+        prefund(&mut context, &get_launch_index_pda(&token_launch::id(), &creator.pubkey(), 0).0).await;
+
+        let mint = launch_token(&mut context, &creator, 0).await.unwrap();
+        assert_eq!(creator_profile(&mut context, &creator.pubkey()).await.launch_count, 1);
+        assert_eq!(launch_index(&mut context, &creator.pubkey(), 0).await.mint, mint);
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_keeps_launch_count() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator = context.payer.pubkey();
+
+        process(&mut context, &[update_profile_instruction(&creator, "Creator")], &[]).await.unwrap();
+        let profile = creator_profile(&mut context, &creator).await;
+        assert_eq!(profile.name, "Creator");
+        assert_eq!(profile.launch_count, 0);
+
+        let payer = context.payer.insecure_clone();
+        launch_token(&mut context, &payer, 0).await.unwrap();
+
+        process(&mut context, &[update_profile_instruction(&creator, "Renamed")], &[]).await.unwrap();
+        let profile = creator_profile(&mut context, &creator).await;
+        assert_eq!(profile.name, "Renamed");
+        assert_eq!(profile.website, "https://example.com");
+        assert_eq!(profile.socials, "x:@creator");
+        assert_eq!(profile.launch_count, 1);
+
+        let result = process(&mut context, &[update_profile_instruction(&creator, &"A".repeat(33))], &[]).await;
+        assert!(result.is_err(), "Display name over the length limit should fail");
+    }
+
+    #[tokio::test]
+    async fn test_only_the_creator_updates_their_profile() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator = Keypair::new();
+
+        // Signed by the payer but aimed at someone else's profile
+        let mut instruction = update_profile_instruction(&context.payer.pubkey(), "Impostor");
+        instruction.accounts[1] = AccountMeta::new(get_creator_profile_pda(&token_launch::id(), &creator.pubkey()).0, false);
+        let result = process(&mut context, &[instruction], &[]).await;
+        assert!(result.is_err(), "A profile is seeded by its creator's key");
+    }
+
+    #[tokio::test]
+    async fn test_launch_requires_creator_signature() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator = Keypair::new();
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+
+        let config = LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Impersonated Token".to_string(),
+            symbol: "IMP".to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/creator-metadata.json".to_string(),
+            creator: creator.pubkey(),
+            is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        };
+
+        // Attributed to `creator` but signed only by the payer
+        let payer = context.payer.pubkey();
+        let launch = |creator_account: &Pubkey, is_signer: bool| {
+            let mut instruction = instruction::launch_asset(
+                &token_launch::id(),
+                &payer,
+                &mint.pubkey(),
+                &token_account.pubkey(),
+                &TOKEN_PROGRAM_ID,
+                0,
+                config.clone(),
+            );
+            instruction.accounts[11] = AccountMeta::new_readonly(*creator_account, is_signer);
+            instruction
+        };
+
+        let result = process(&mut context, &[launch(&creator.pubkey(), false)], &[&mint, &token_account]).await;
+        assert!(result.is_err(), "The creator must sign");
+
+        let result = process(&mut context, &[launch(&payer, true)], &[&mint, &token_account]).await;
+        assert!(result.is_err(), "The signing creator must match the config");
+
+        process(&mut context, &[launch(&creator.pubkey(), true)], &[&mint, &token_account, &creator]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_relayer_sponsored_launch() {
+        let mut context = create_program_test().start_with_context().await;
+        let relayer = context.payer.insecure_clone();
+        let creator = Keypair::new();
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+
+        let instruction = launch_instruction(&relayer.pubkey(), &creator.pubkey(), 0, &mint.pubkey(), &token_account.pubkey(), false);
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+
+        // The creator signs first without holding any SOL, the relayer co-signs and pays
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&relayer.pubkey()));
+        tx.partial_sign(&[&creator, &mint, &token_account], recent_blockhash);
+        tx.partial_sign(&[&relayer], recent_blockhash);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        assert!(context.banks_client.get_account(creator.pubkey()).await.unwrap().is_none(), "The creator spends nothing");
+
+        let account = context.banks_client.get_account(token_account.pubkey()).await.unwrap().unwrap();
+        let token_account = spl_token::state::Account::unpack(&account.data).unwrap();
+        assert_eq!(token_account.owner, creator.pubkey());
+        assert_eq!(token_account.amount, 1_000_000);
+
+        let account = context.banks_client.get_account(mint.pubkey()).await.unwrap().unwrap();
+        let mint_state = spl_token::state::Mint::unpack(&account.data).unwrap();
+        assert_eq!(mint_state.freeze_authority, Some(creator.pubkey()).into());
+
+        assert_eq!(creator_profile(&mut context, &creator.pubkey()).await.launch_count, 1);
+        assert_eq!(launch_index(&mut context, &creator.pubkey(), 0).await.mint, mint.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_deterministic_mint_launch() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator = context.payer.insecure_clone();
+
+        let mut mints = Vec::new();
+        for index in 0..2 {
+            // Known before launch, and no mint keypair signs
+            let mint = get_deterministic_mint_pda(&token_launch::id(), &creator.pubkey(), index).0;
+            let token_account = Keypair::new();
+            let instruction = launch_instruction(&creator.pubkey(), &creator.pubkey(), index, &mint, &token_account.pubkey(), true);
+            process(&mut context, &[instruction], &[&token_account]).await.unwrap();
+
+            let account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+            assert_eq!(account.owner, TOKEN_PROGRAM_ID);
+            assert_eq!(spl_token::state::Mint::unpack(&account.data).unwrap().supply, 1_000_000);
+            assert_eq!(launch_index(&mut context, &creator.pubkey(), index).await.mint, mint);
+            mints.push(mint);
+        }
+
+        assert_ne!(mints[0], mints[1]);
+    }
+
+    #[tokio::test]
+    async fn test_deterministic_mint_launch_with_prefunded_mint() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator = context.payer.insecure_clone();
+
+        // The mint address is public before the launch, so anyone can fund it first
+        let mint = get_deterministic_mint_pda(&token_launch::id(), &creator.pubkey(), 0).0;
+        prefund(&mut context, &mint).await;
+
+        let token_account = Keypair::new();
+        let instruction = launch_instruction(&creator.pubkey(), &creator.pubkey(), 0, &mint, &token_account.pubkey(), true);
+        process(&mut context, &[instruction], &[&token_account]).await.unwrap();
+
+        let account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+        assert_eq!(account.owner, TOKEN_PROGRAM_ID);
+        assert_eq!(spl_token::state::Mint::unpack(&account.data).unwrap().supply, 1_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_deterministic_mint_must_match_launch_index() {
+        let mut context = create_program_test().start_with_context().await;
+        let creator = context.payer.pubkey();
+        let token_account = Keypair::new();
+
+        // The next launch is index 0, so index 1's mint is rejected
+        let mint = get_deterministic_mint_pda(&token_launch::id(), &creator, 1).0;
+        let instruction = launch_instruction(&creator, &creator, 0, &mint, &token_account.pubkey(), true);
+        let error = process(&mut context, &[instruction], &[&token_account]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::InvalidPda as u32)));
+
+        // A keypair mint can't stand in for the derived one, even when it signs
+        let mint = Keypair::new();
+        let mut instruction = launch_instruction(&creator, &creator, 0, &mint.pubkey(), &token_account.pubkey(), true);
+        instruction.accounts[1].is_signer = true;
+        let error = process(&mut context, &[instruction], &[&mint, &token_account]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::InvalidPda as u32)));
+    }
+}
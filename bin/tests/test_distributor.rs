@@ -0,0 +1,241 @@
+#[cfg(test)]
+mod test_distributor {
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        clock::Clock, instruction::{AccountMeta, Instruction, InstructionError}, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::{Transaction, TransactionError}
+    };
+    use token_launch::{
+        constants::TOKEN_PROGRAM_ID,
+        entrypoint::process_instruction,
+        error::LaunchpadError,
+        instruction,
+        merkle::{hash_leaf, hash_pair},
+        util::{get_creator_profile_pda, get_distributor_pda, get_distributor_vault_pda},
+        AssetType, LaunchConfig,
+    };
+
+    const EXPIRY: i64 = 1_000;
+
+    // Helper function to create program test environment
+    fn create_program_test() -> ProgramTest {
+        ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        )
+    }
+
+    async fn process(context: &mut ProgramTestContext, instruction: Instruction, signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn set_time(context: &mut ProgramTestContext, unix_timestamp: i64) {
+        let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        context.set_sysvar(&clock);
+
+        // A transaction repeated after the clock moves would otherwise hit the status cache
+        context.get_new_latest_blockhash().await.unwrap();
+    }
+
+    async fn token_balance(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+        let account = context.banks_client.get_account(*token_account).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    // Launches recorded under `creator` so far, which is also the index of their next launch
+    async fn launch_count(context: &mut ProgramTestContext, creator: &Pubkey) -> u64 {
+        let creator_profile = get_creator_profile_pda(&token_launch::id(), creator).0;
+        match context.banks_client.get_account(creator_profile).await.unwrap() {
+            Some(account) => instruction::decode_creator_profile(&account.data).unwrap().launch_count,
+            None => 0,
+        }
+    }
+
+    // Launches a legacy token and returns its mint and the payer's token account
+    async fn launch_token(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+        let config = LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Airdrop Token".to_string(),
+            symbol: "AIR".to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/airdrop-metadata.json".to_string(),
+            creator: context.payer.pubkey(),
+            is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        };
+
+        let creator = context.payer.pubkey();
+        let launch_index = launch_count(context, &creator).await;
+
+        let instruction = instruction::launch_asset(&token_launch::id(), &creator, &mint.pubkey(), &token_account.pubkey(), &TOKEN_PROGRAM_ID, launch_index, config);
+
+        process(context, instruction, &[&mint, &token_account]).await.unwrap();
+
+        (mint.pubkey(), token_account.pubkey())
+    }
+
+    // Creates a funded wallet with an empty token account for the mint
+    async fn create_wallet(context: &mut ProgramTestContext, mint: &Pubkey) -> (Keypair, Pubkey) {
+        let wallet = Keypair::new();
+        let token_account = Keypair::new();
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let instructions = [
+            system_instruction::transfer(&context.payer.pubkey(), &wallet.pubkey(), 1_000_000_000),
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &token_account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_account3(&spl_token::id(), &token_account.pubkey(), mint, &wallet.pubkey()).unwrap(),
+        ];
+
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &token_account],
+            recent_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        (wallet, token_account.pubkey())
+    }
+
+    fn create_distributor_instruction(creator: &Pubkey, mint: &Pubkey, creator_token_account: &Pubkey, merkle_root: [u8; 32], total_amount: u64) -> Instruction {
+        instruction::create_distributor(&token_launch::id(), creator, mint, creator_token_account, &TOKEN_PROGRAM_ID, merkle_root, total_amount, 2, EXPIRY)
+    }
+
+    fn claim_instruction(claimant: &Pubkey, mint: &Pubkey, claimant_token_account: &Pubkey, index: u64, amount: u64, proof: Vec<[u8; 32]>) -> Instruction {
+        instruction::claim(&token_launch::id(), claimant, mint, claimant_token_account, &TOKEN_PROGRAM_ID, index, amount, proof)
+    }
+
+    fn clawback_instruction(creator: &Pubkey, mint: &Pubkey, creator_token_account: &Pubkey) -> Instruction {
+        instruction::clawback(&token_launch::id(), creator, mint, creator_token_account, &TOKEN_PROGRAM_ID)
+    }
+
+    #[tokio::test]
+    async fn test_merkle_airdrop_claim_and_clawback() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        let (alice, alice_tokens) = create_wallet(&mut context, &mint).await;
+        let (bob, bob_tokens) = create_wallet(&mut context, &mint).await;
+
+        let alice_leaf = hash_leaf(0, &alice.pubkey(), 1_000);
+        let bob_leaf = hash_leaf(1, &bob.pubkey(), 2_000);
+        let merkle_root = hash_pair(&alice_leaf, &bob_leaf);
+
+        process(&mut context, create_distributor_instruction(&creator, &mint, &creator_token_account, merkle_root, 3_000), &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &creator_token_account).await, 1_000_000 - 3_000);
+
+        let result = process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens, 0, 2_000, vec![bob_leaf]), &[&alice]).await;
+        assert!(result.is_err(), "Claim with the wrong amount should fail");
+
+        let result = process(&mut context, claim_instruction(&bob.pubkey(), &mint, &bob_tokens, 0, 1_000, vec![bob_leaf]), &[&bob]).await;
+        assert!(result.is_err(), "Claim of another wallet's entry should fail");
+
+        process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens, 0, 1_000, vec![bob_leaf]), &[&alice]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &alice_tokens).await, 1_000);
+
+        // A fresh blockhash keeps the retry from being deduplicated
+        context.get_new_latest_blockhash().await.unwrap();
+        let result = process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens, 0, 1_000, vec![bob_leaf]), &[&alice]).await;
+        assert!(result.is_err(), "Double claim should fail");
+
+        let result = process(&mut context, clawback_instruction(&creator, &mint, &creator_token_account), &[]).await;
+        assert!(result.is_err(), "Clawback before expiry should fail");
+
+        set_time(&mut context, EXPIRY).await;
+
+        let result = process(&mut context, claim_instruction(&bob.pubkey(), &mint, &bob_tokens, 1, 2_000, vec![alice_leaf]), &[&bob]).await;
+        assert!(result.is_err(), "Claim after expiry should fail");
+
+        let error = process(&mut context, clawback_instruction(&bob.pubkey(), &mint, &bob_tokens), &[&bob]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAssetCreator as u32)), "Only the creator may claw back");
+
+        process(&mut context, clawback_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &creator_token_account).await, 1_000_000 - 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_distributor_requires_launched_asset() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+
+        let mut instruction = create_distributor_instruction(&creator, &mint, &creator_token_account, [0; 32], 3_000);
+        instruction.accounts[2] = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+
+        let result = process(&mut context, instruction, &[]).await;
+        assert!(result.is_err(), "Distributor for an unknown asset should fail");
+    }
+
+    #[tokio::test]
+    async fn test_only_the_creator_creates_a_distributor() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let (mint, _) = launch_token(&mut context).await;
+        let (stranger, stranger_tokens) = create_wallet(&mut context, &mint).await;
+
+        // Otherwise anyone could take the mint's only distributor address first
+        let instruction = create_distributor_instruction(&stranger.pubkey(), &mint, &stranger_tokens, [0; 32], 3_000);
+        let error = process(&mut context, instruction, &[&stranger]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAssetCreator as u32)));
+    }
+
+    #[tokio::test]
+    async fn test_distributor_with_prefunded_pdas() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        let (alice, alice_tokens) = create_wallet(&mut context, &mint).await;
+
+        // These addresses are predictable, so lamports sent there first must not block the airdrop
+        let distributor = get_distributor_pda(&token_launch::id(), &mint).0;
+        let vault = get_distributor_vault_pda(&token_launch::id(), &distributor).0;
+        for address in [distributor, vault] {
+            process(&mut context, system_instruction::transfer(&creator, &address, 1_000_000), &[]).await.unwrap();
+        }
+
+        let alice_leaf = hash_leaf(0, &alice.pubkey(), 1_000);
+        let other_leaf = hash_leaf(1, &Pubkey::new_unique(), 2_000);
+        let merkle_root = hash_pair(&alice_leaf, &other_leaf);
+
+        process(&mut context, create_distributor_instruction(&creator, &mint, &creator_token_account, merkle_root, 3_000), &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &vault).await, 3_000);
+
+        process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens, 0, 1_000, vec![other_leaf]), &[&alice]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &alice_tokens).await, 1_000);
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod test_events {
+    use std::sync::{Mutex, Once};
+
+    use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, program_stubs::{set_syscall_stubs, SyscallStubs}};
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction
+    };
+    use token_launch::{
+        constants::TOKEN_PROGRAM_ID,
+        entrypoint::process_instruction,
+        event::LaunchpadEvent,
+        instruction,
+        util::get_mint_authority_pda,
+        AssetType, LaunchConfig,
+    };
+
+    // Events logged by every test in this file, they run in parallel so tests look for events about their own keys
+    static EVENTS: Mutex<Vec<LaunchpadEvent>> = Mutex::new(Vec::new());
+    static RECORD_EVENTS: Once = Once::new();
+
+    // Natively built programs log data through the syscall stubs, which program test leaves printing to stdout
+    struct EventRecorder {
+        inner: Box<dyn SyscallStubs>,
+    }
+
+    impl SyscallStubs for EventRecorder {
+        fn sol_log(&self, message: &str) {
+            self.inner.sol_log(message)
+        }
+        fn sol_log_compute_units(&self) {
+            self.inner.sol_log_compute_units()
+        }
+        fn sol_remaining_compute_units(&self) -> u64 {
+            self.inner.sol_remaining_compute_units()
+        }
+        fn sol_invoke_signed(&self, instruction: &Instruction, account_infos: &[AccountInfo], signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+            self.inner.sol_invoke_signed(instruction, account_infos, signers_seeds)
+        }
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            self.inner.sol_get_clock_sysvar(var_addr)
+        }
+        fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+            self.inner.sol_get_epoch_schedule_sysvar(var_addr)
+        }
+        fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+            self.inner.sol_get_fees_sysvar(var_addr)
+        }
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            self.inner.sol_get_rent_sysvar(var_addr)
+        }
+        fn sol_get_epoch_rewards_sysvar(&self, var_addr: *mut u8) -> u64 {
+            self.inner.sol_get_epoch_rewards_sysvar(var_addr)
+        }
+        fn sol_get_last_restart_slot(&self, var_addr: *mut u8) -> u64 {
+            self.inner.sol_get_last_restart_slot(var_addr)
+        }
+        fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+            self.inner.sol_get_return_data()
+        }
+        fn sol_set_return_data(&self, data: &[u8]) {
+            self.inner.sol_set_return_data(data)
+        }
+        fn sol_log_data(&self, fields: &[&[u8]]) {
+            EVENTS.lock().unwrap().extend(LaunchpadEvent::decode(fields));
+        }
+        fn sol_get_processed_sibling_instruction(&self, index: usize) -> Option<Instruction> {
+            self.inner.sol_get_processed_sibling_instruction(index)
+        }
+        fn sol_get_stack_height(&self) -> u64 {
+            self.inner.sol_get_stack_height()
+        }
+    }
+
+    // Starts the program test, then wraps the stubs it installed so logged events get recorded
+    async fn start() -> ProgramTestContext {
+        let context = ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        )
+        .start_with_context()
+        .await;
+
+        RECORD_EVENTS.call_once(|| {
+            // Every test waits here before sending transactions, so the placeholder is never called
+            let inner = set_syscall_stubs(Box::new(Placeholder));
+            set_syscall_stubs(Box::new(EventRecorder { inner }));
+        });
+
+        context
+    }
+
+    struct Placeholder;
+    impl SyscallStubs for Placeholder {}
+
+    fn assert_emitted(expected: &[LaunchpadEvent]) {
+        let events = EVENTS.lock().unwrap();
+        for event in expected {
+            assert!(events.contains(event), "{:?} wasn't emitted", event);
+        }
+    }
+
+    async fn process(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    // Launch of `config` by the context payer, who is also its creator
+    fn launch_instruction(payer: &Pubkey, mint: &Pubkey, token_account: &Pubkey, config: LaunchConfig) -> Instruction {
+        instruction::launch_asset(&token_launch::id(), payer, mint, token_account, &TOKEN_PROGRAM_ID, 0, config)
+    }
+
+    fn launch_config(creator: &Pubkey, asset_type: AssetType, unique_symbol: bool) -> LaunchConfig {
+        let (decimals, total_supply, max_supply) = match asset_type {
+            AssetType::StandardNft => (0, 1, 1),
+            _ => (6, 1_000_000, 5_000_000),
+        };
+
+        LaunchConfig {
+            asset_type,
+            name: "Event Token".to_string(),
+            symbol: "EVT".to_string(),
+            decimals,
+            total_supply,
+            metadata_uri: "https://example.com/event-metadata.json".to_string(),
+            creator: *creator,
+            is_mutable: false,
+            max_supply,
+            emission: None,
+            unique_symbol,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_token_launch_events() {
+        let mut context = start().await;
+        let creator = context.payer.pubkey();
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+
+        let config = launch_config(&creator, AssetType::SplTokenLegacy, true);
+        let instruction = launch_instruction(&creator, &mint.pubkey(), &token_account.pubkey(), config);
+        process(&mut context, &[instruction], &[&mint, &token_account]).await;
+
+        assert_emitted(&[
+            LaunchpadEvent::MintAuthorityTransferred {
+                mint: mint.pubkey(),
+                authority: get_mint_authority_pda(&token_launch::id(), &mint.pubkey()).0,
+            },
+            LaunchpadEvent::SymbolClaimed { symbol: "EVT".to_string(), mint: mint.pubkey() },
+            LaunchpadEvent::AssetLaunched {
+                mint: mint.pubkey(),
+                creator,
+                payer: creator,
+                asset_type: AssetType::SplTokenLegacy,
+                launch_index: 0,
+                total_supply: 1_000_000,
+                max_supply: 5_000_000,
+            },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_nft_launch_events() {
+        let mut context = start().await;
+        let creator = context.payer.pubkey();
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+
+        let config = launch_config(&creator, AssetType::StandardNft, false);
+        let instruction = launch_instruction(&creator, &mint.pubkey(), &token_account.pubkey(), config);
+        process(&mut context, &[instruction], &[&mint, &token_account]).await;
+
+        assert_emitted(&[
+            LaunchpadEvent::MintAuthorityRevoked { mint: mint.pubkey() },
+            LaunchpadEvent::AssetLaunched {
+                mint: mint.pubkey(),
+                creator,
+                payer: creator,
+                asset_type: AssetType::StandardNft,
+                launch_index: 0,
+                total_supply: 1,
+                max_supply: 1,
+            },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_creator_profile_update_event() {
+        let mut context = start().await;
+        let creator = context.payer.pubkey();
+
+        let instruction = instruction::update_creator_profile(
+            &token_launch::id(),
+            &creator,
+            "Event Creator".to_string(),
+            "https://example.com".to_string(),
+            "x:@creator".to_string(),
+        );
+        process(&mut context, &[instruction], &[]).await;
+
+        assert_emitted(&[LaunchpadEvent::CreatorProfileUpdated { creator }]);
+    }
+}
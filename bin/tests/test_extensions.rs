@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod test_extensions {
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        instruction::{Instruction, InstructionError}, pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::{Transaction, TransactionError}
+    };
+    use token_launch::{
+        constants::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+        entrypoint::process_instruction,
+        extension::{account_len, mint_extensions, mint_len, ExtensionType},
+        instruction,
+        state::MintExtension,
+        AssetType, LaunchConfig,
+    };
+
+    const SUPPLY: u64 = 1_000_000;
+
+    // Helper function to create program test environment
+    fn create_program_test() -> ProgramTest {
+        ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        )
+    }
+
+    async fn process(context: &mut ProgramTestContext, instruction: Instruction, signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    // Launches the creator's first token with `extensions`, returning the mint and the creator's token account
+    async fn launch(context: &mut ProgramTestContext, asset_type: AssetType, token_program: &Pubkey, extensions: Vec<MintExtension>) -> Result<(Pubkey, Pubkey), solana_program_test::BanksClientError> {
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+
+        let config = LaunchConfig {
+            asset_type,
+            name: "Extended Token".to_string(),
+            symbol: "EXT".to_string(),
+            decimals: 6,
+            total_supply: SUPPLY,
+            metadata_uri: "https://example.com/extended-metadata.json".to_string(),
+            creator: context.payer.pubkey(),
+            is_mutable: false,
+            max_supply: SUPPLY,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions,
+        };
+
+        let instruction = instruction::launch_asset(&token_launch::id(), &context.payer.pubkey(), &mint.pubkey(), &token_account.pubkey(), token_program, 0, config);
+        process(context, instruction, &[&mint, &token_account]).await?;
+        Ok((mint.pubkey(), token_account.pubkey()))
+    }
+
+    async fn account_data(context: &mut ProgramTestContext, address: Pubkey) -> Vec<u8> {
+        context.banks_client.get_account(address).await.unwrap().unwrap().data
+    }
+
+    // Token amount of a token account, right after the mint and owner
+    fn token_amount(data: &[u8]) -> u64 {
+        u64::from_le_bytes(data[64..72].try_into().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_extensions() {
+        let mut context = create_program_test().start_with_context().await;
+        let extensions = vec![
+            MintExtension::TransferFee { basis_points: 250, maximum_fee: 5_000 },
+            MintExtension::MintCloseAuthority,
+            MintExtension::PermanentDelegate,
+            MintExtension::InterestBearing { rate: 500 },
+        ];
+        let (mint, token_account) = launch(&mut context, AssetType::SplToken2022, &TOKEN_2022_PROGRAM_ID, extensions).await.unwrap();
+
+        let expected = [
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::PermanentDelegate,
+            ExtensionType::InterestBearingConfig,
+        ];
+        let mint_data = account_data(&mut context, mint).await;
+        assert_eq!(mint_data.len(), mint_len(&expected).unwrap());
+        assert_eq!(mint_extensions(&mint_data).unwrap(), expected);
+
+        // The creator's account carries the withheld fee of the transfer fee mint
+        let token_data = account_data(&mut context, token_account).await;
+        assert_eq!(token_data.len(), account_len(&[ExtensionType::TransferFeeAmount]).unwrap());
+        assert_eq!(token_amount(&token_data), SUPPLY);
+    }
+
+    #[tokio::test]
+    async fn test_launch_non_transferable() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, token_account) = launch(&mut context, AssetType::SplToken2022, &TOKEN_2022_PROGRAM_ID, vec![MintExtension::NonTransferable]).await.unwrap();
+
+        assert_eq!(mint_extensions(&account_data(&mut context, mint).await).unwrap(), [ExtensionType::NonTransferable]);
+
+        // Token-2022 only mints non-transferable tokens into accounts whose owner can't change
+        let token_data = account_data(&mut context, token_account).await;
+        assert_eq!(token_data.len(), account_len(&[ExtensionType::NonTransferableAccount, ExtensionType::ImmutableOwner]).unwrap());
+        assert_eq!(token_amount(&token_data), SUPPLY);
+    }
+
+    #[tokio::test]
+    async fn test_extensions_need_token_2022() {
+        let mut context = create_program_test().start_with_context().await;
+        let error = launch(&mut context, AssetType::SplTokenLegacy, &TOKEN_PROGRAM_ID, vec![MintExtension::MintCloseAuthority]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::InvalidArgument));
+
+        let extensions = vec![MintExtension::MintCloseAuthority, MintExtension::MintCloseAuthority];
+        let error = launch(&mut context, AssetType::SplToken2022, &TOKEN_2022_PROGRAM_ID, extensions).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::InvalidArgument));
+    }
+}
@@ -1,9 +1,8 @@
 #[cfg(test)]
 mod test_happy {
-    use borsh::BorshSerialize;
-    use token_launch::{constants::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID}, entrypoint::process_instruction, AssetType, LaunchConfig, LaunchpadInstruction};
+    use token_launch::{constants::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID}, entrypoint::process_instruction, instruction, AssetType, LaunchConfig};
     use solana_program_test::{processor, ProgramTest};
-    use solana_sdk::{instruction::{AccountMeta, Instruction}, pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+    use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
 
     // Helper function to create program test environment
     fn create_program_test() -> ProgramTest {
@@ -14,36 +13,6 @@ mod test_happy {
         )
     }
 
-    // Helper function to create launch instruction
-    fn create_launch_instruction(
-        payer: &Pubkey,
-        mint: &Pubkey,
-        token_account: &Pubkey,
-        metadata_account: &Pubkey,
-        token_program: &Pubkey,
-        config: LaunchConfig,
-    ) -> Instruction {
-        let accounts = vec![
-            AccountMeta::new(*payer, true),
-            AccountMeta::new(*mint, true),
-            AccountMeta::new(*token_account, true),
-            AccountMeta::new(*metadata_account, false),
-            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
-            AccountMeta::new_readonly(*token_program, false),
-            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
-        ];
-
-        let data = LaunchpadInstruction::LaunchAsset { config }
-            .try_to_vec()
-            .unwrap();
-
-        Instruction {
-            program_id: token_launch::id(),
-            accounts,
-            data,
-        }
-    }
-
     #[tokio::test]
     async fn test_spl_token_legacy_launch() {
 
@@ -52,38 +21,33 @@ mod test_happy {
 
         let mint = Keypair::new();
         let token_account = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(&[b"launched_asset", mint.pubkey().as_ref()], &token_launch::id()).0;
-
-        let accounts = vec![
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new(mint.pubkey(), true),
-            AccountMeta::new(token_account.pubkey(), true),
-            AccountMeta::new(metadata_account, false),
-            AccountMeta::new_readonly(solana_sdk::system_program::id(), false), // System program
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false), // Program ID
-            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false), // Rent sysvar
-        ];   
-
-        let data = LaunchpadInstruction::LaunchAsset {
-            config: LaunchConfig {
-                asset_type: token_launch::state::AssetType::SplTokenLegacy,
-                name: "Test Token".to_string(),
-                symbol: "TTK".to_string(),
-                decimals: 6,
-                total_supply: 1_000_000_000, // 1 million tokens
-                metadata_uri: "https://example.com/metadata.json".to_string(),
-                creator: payer.pubkey(),
-                is_mutable: false,
-            },
-        }.try_to_vec().unwrap();     
-
-        // Create a dummy instruction (no accounts, no data)
-        let instruction = Instruction {
-            program_id: token_launch::id(),
-            accounts, 
-            data, 
+
+        let config = LaunchConfig {
+            asset_type: token_launch::state::AssetType::SplTokenLegacy,
+            name: "Test Token".to_string(),
+            symbol: "TTK".to_string(),
+            decimals: 6,
+            total_supply: 1_000_000_000, // 1 million tokens
+            metadata_uri: "https://example.com/metadata.json".to_string(),
+            creator: payer.pubkey(),
+            is_mutable: false,
+            max_supply: 1_000_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
+            &payer.pubkey(),
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &TOKEN_PROGRAM_ID,
+            0,
+            config,
+        );
+
         // Create and send a transaction with the instruction
         let tx = Transaction::new_signed_with_payer(
             &[instruction],
@@ -96,7 +60,7 @@ mod test_happy {
         let result = banks_client.process_transaction(tx).await;
 
         match result {
-            Ok(_) => assert!(true, "Transaction processed successfully"),
+            Ok(_) => {}
             Err(e) => panic!("Transaction failed: {:?}", e),
         }
     }
@@ -109,38 +73,33 @@ mod test_happy {
 
         let mint = Keypair::new();
         let token_account = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(&[b"launched_asset", mint.pubkey().as_ref()], &token_launch::id()).0;
-
-        let accounts = vec![
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new(mint.pubkey(), true),
-            AccountMeta::new(token_account.pubkey(), true),
-            AccountMeta::new(metadata_account, false),
-            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
-            AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
-            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
-        ];   
-
-        let data = LaunchpadInstruction::LaunchAsset {
-            config: LaunchConfig {
-                asset_type: token_launch::state::AssetType::SplToken2022,
-                name: "Test Token 2022".to_string(),
-                symbol: "TTK2".to_string(),
-                decimals: 6,
-                total_supply: 500_000_000, // 500k tokens
-                metadata_uri: "https://example.com/metadata.json".to_string(),
-                creator: payer.pubkey(),
-                is_mutable: false,
-            },
-        }.try_to_vec().unwrap();     
-
-        // Create a dummy instruction (no accounts, no data)
-        let instruction = Instruction {
-            program_id: token_launch::id(),
-            accounts, // No accounts needed for this simple test
-            data,     // No instruction data either
+
+        let config = LaunchConfig {
+            asset_type: token_launch::state::AssetType::SplToken2022,
+            name: "Test Token 2022".to_string(),
+            symbol: "TTK2".to_string(),
+            decimals: 6,
+            total_supply: 500_000_000, // 500k tokens
+            metadata_uri: "https://example.com/metadata.json".to_string(),
+            creator: payer.pubkey(),
+            is_mutable: false,
+            max_supply: 500_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
+            &payer.pubkey(),
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &TOKEN_2022_PROGRAM_ID,
+            0,
+            config,
+        );
+
         // Create and send a transaction with the instruction
         let tx = Transaction::new_signed_with_payer(
             &[instruction],
@@ -153,7 +112,7 @@ mod test_happy {
         let result = banks_client.process_transaction(tx).await;
 
         match result {
-            Ok(_) => assert!(true, "Transaction processed successfully"),
+            Ok(_) => {}
             Err(e) => panic!("Transaction failed: {:?}", e),
         }
 
@@ -175,38 +134,33 @@ mod test_happy {
 
         let mint = Keypair::new();
         let token_account = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(&[b"launched_asset", mint.pubkey().as_ref()], &token_launch::id()).0;
-
-        let accounts = vec![
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new(mint.pubkey(), true),
-            AccountMeta::new(token_account.pubkey(), true),
-            AccountMeta::new(metadata_account, false),
-            AccountMeta::new_readonly(solana_sdk::system_program::id(), false), // System program
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false), // Program ID
-            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false), // Rent sysvar
-        ];   
-
-        let data = LaunchpadInstruction::LaunchAsset {
-            config: LaunchConfig {
-                asset_type: token_launch::state::AssetType::StandardNft,
-                name: "Test NFT".to_string(),
-                symbol: "TN".to_string(),
-                decimals: 0,
-                total_supply: 1,
-                metadata_uri: "https://example.com/metadata.json".to_string(),
-                creator: payer.pubkey(),
-                is_mutable: false,
-            },
-        }.try_to_vec().unwrap();     
-
-        // Create a dummy instruction (no accounts, no data)
-        let instruction = Instruction {
-            program_id: token_launch::id(),
-            accounts, // No accounts needed for this simple test
-            data,     // No instruction data either
+
+        let config = LaunchConfig {
+            asset_type: token_launch::state::AssetType::StandardNft,
+            name: "Test NFT".to_string(),
+            symbol: "TN".to_string(),
+            decimals: 0,
+            total_supply: 1,
+            metadata_uri: "https://example.com/metadata.json".to_string(),
+            creator: payer.pubkey(),
+            is_mutable: false,
+            max_supply: 1,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
+            &payer.pubkey(),
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &TOKEN_PROGRAM_ID,
+            0,
+            config,
+        );
+
         // Create and send a transaction with the instruction
         let tx = Transaction::new_signed_with_payer(
             &[instruction],
@@ -219,7 +173,7 @@ mod test_happy {
         let result = banks_client.process_transaction(tx).await;
 
         match result {
-            Ok(_) => assert!(true, "Transaction processed successfully"),
+            Ok(_) => {}
             Err(e) => panic!("Transaction failed: {:?}", e),
         }
 
@@ -235,10 +189,6 @@ mod test_happy {
         for i in 0..3 {
             let mint = Keypair::new();
             let token_account = Keypair::new();
-            let metadata_account = Pubkey::find_program_address(
-                &[b"launched_asset", mint.pubkey().as_ref()], 
-                &token_launch::id()
-            ).0;
 
             let config = LaunchConfig {
                 asset_type: AssetType::SplTokenLegacy,
@@ -249,14 +199,20 @@ mod test_happy {
                 metadata_uri: format!("https://example.com/multi-{}-metadata.json", i),
                 creator: payer.pubkey(),
                 is_mutable: i % 2 == 0, // Alternate mutability
+                max_supply: 1_000_000 * (i + 1) as u64,
+                emission: None,
+                unique_symbol: false,
+                deterministic_mint: false,
+                extensions: Vec::new(),
             };
 
-            let instruction = create_launch_instruction(
+            let instruction = instruction::launch_asset(
+                &token_launch::id(),
                 &payer.pubkey(),
                 &mint.pubkey(),
                 &token_account.pubkey(),
-                &metadata_account,
                 &TOKEN_PROGRAM_ID,
+                i as u64,
                 config,
             );
 
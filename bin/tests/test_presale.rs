@@ -0,0 +1,357 @@
+#[cfg(test)]
+mod test_presale {
+    use borsh::BorshSerialize;
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        account::Account, clock::Clock, instruction::{Instruction, InstructionError}, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::{Transaction, TransactionError}
+    };
+    use token_launch::{
+        constants::TOKEN_PROGRAM_ID,
+        entrypoint::process_instruction,
+        error::LaunchpadError,
+        instruction,
+        state::Contribution,
+        util::{get_contribution_pda, get_creator_profile_pda, get_presale_pda, get_presale_vault_pda},
+        AssetType, LaunchConfig,
+    };
+
+    const SOFT_CAP: u64 = 3_000_000_000;
+    const TOKENS_FOR_SALE: u64 = 600_000;
+    const DEADLINE: i64 = 1_000;
+
+    // Helper function to create program test environment
+    fn create_program_test() -> ProgramTest {
+        ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        )
+    }
+
+    async fn process(context: &mut ProgramTestContext, instruction: Instruction, signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn set_time(context: &mut ProgramTestContext, unix_timestamp: i64) {
+        let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        context.set_sysvar(&clock);
+
+        // A transaction repeated after the clock moves would otherwise hit the status cache
+        context.get_new_latest_blockhash().await.unwrap();
+    }
+
+    async fn token_balance(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+        let account = context.banks_client.get_account(*token_account).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    // Launches recorded under `creator` so far, which is also the index of their next launch
+    async fn launch_count(context: &mut ProgramTestContext, creator: &Pubkey) -> u64 {
+        let creator_profile = get_creator_profile_pda(&token_launch::id(), creator).0;
+        match context.banks_client.get_account(creator_profile).await.unwrap() {
+            Some(account) => instruction::decode_creator_profile(&account.data).unwrap().launch_count,
+            None => 0,
+        }
+    }
+
+    // Launches a legacy token and returns its mint and the payer's token account
+    async fn launch_token(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+        let config = LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Presale Token".to_string(),
+            symbol: "PRE".to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/presale-metadata.json".to_string(),
+            creator: context.payer.pubkey(),
+            is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        };
+
+        let creator = context.payer.pubkey();
+        let launch_index = launch_count(context, &creator).await;
+
+        let instruction = instruction::launch_asset(&token_launch::id(), &creator, &mint.pubkey(), &token_account.pubkey(), &TOKEN_PROGRAM_ID, launch_index, config);
+
+        process(context, instruction, &[&mint, &token_account]).await.unwrap();
+
+        (mint.pubkey(), token_account.pubkey())
+    }
+
+    // Creates a funded contributor with an empty token account for the mint
+    async fn create_contributor(context: &mut ProgramTestContext, mint: &Pubkey) -> (Keypair, Pubkey) {
+        let contributor = Keypair::new();
+        let token_account = Keypair::new();
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let instructions = [
+            system_instruction::transfer(&context.payer.pubkey(), &contributor.pubkey(), 10_000_000_000),
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &token_account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_account3(&spl_token::id(), &token_account.pubkey(), mint, &contributor.pubkey()).unwrap(),
+        ];
+
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &token_account],
+            recent_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        (contributor, token_account.pubkey())
+    }
+
+    fn create_presale_instruction(creator: &Pubkey, mint: &Pubkey, creator_token_account: &Pubkey) -> Instruction {
+        instruction::create_presale(&token_launch::id(), creator, mint, creator_token_account, &TOKEN_PROGRAM_ID, SOFT_CAP, TOKENS_FOR_SALE, DEADLINE)
+    }
+
+    fn contribute_instruction(contributor: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+        instruction::contribute(&token_launch::id(), contributor, mint, amount)
+    }
+
+    fn claim_instruction(contributor: &Pubkey, mint: &Pubkey, contributor_token_account: &Pubkey) -> Instruction {
+        instruction::claim_presale_tokens(&token_launch::id(), contributor, mint, contributor_token_account, &TOKEN_PROGRAM_ID)
+    }
+
+    fn withdraw_instruction(creator: &Pubkey, mint: &Pubkey, creator_token_account: &Pubkey) -> Instruction {
+        instruction::withdraw_presale(&token_launch::id(), creator, mint, creator_token_account, &TOKEN_PROGRAM_ID)
+    }
+
+    fn refund_instruction(contributor: &Pubkey, mint: &Pubkey) -> Instruction {
+        instruction::refund(&token_launch::id(), contributor, mint)
+    }
+
+    #[tokio::test]
+    async fn test_presale_reaches_soft_cap() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        process(&mut context, create_presale_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &creator_token_account).await, 1_000_000 - TOKENS_FOR_SALE);
+
+        let (alice, alice_tokens) = create_contributor(&mut context, &mint).await;
+        let (bob, bob_tokens) = create_contributor(&mut context, &mint).await;
+        process(&mut context, contribute_instruction(&alice.pubkey(), &mint, 1_000_000_000), &[&alice]).await.unwrap();
+        process(&mut context, contribute_instruction(&bob.pubkey(), &mint, 2_000_000_000), &[&bob]).await.unwrap();
+
+        // Claims and withdrawals wait for the deadline
+        let result = process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens), &[&alice]).await;
+        assert!(result.is_err(), "Claim before the deadline should fail");
+
+        set_time(&mut context, DEADLINE).await;
+
+        let result = process(&mut context, contribute_instruction(&alice.pubkey(), &mint, 1_000_000_000), &[&alice]).await;
+        assert!(result.is_err(), "Contribution after the deadline should fail");
+
+        let result = process(&mut context, refund_instruction(&alice.pubkey(), &mint), &[&alice]).await;
+        assert!(result.is_err(), "Refund after a successful presale should fail");
+
+        process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens), &[&alice]).await.unwrap();
+        process(&mut context, claim_instruction(&bob.pubkey(), &mint, &bob_tokens), &[&bob]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &alice_tokens).await, TOKENS_FOR_SALE / 3);
+        assert_eq!(token_balance(&mut context, &bob_tokens).await, TOKENS_FOR_SALE * 2 / 3);
+
+        // A fresh blockhash keeps the retry from being deduplicated
+        context.get_new_latest_blockhash().await.unwrap();
+        let result = process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens), &[&alice]).await;
+        assert!(result.is_err(), "Double claim should fail");
+
+        let creator_lamports = context.banks_client.get_balance(creator).await.unwrap();
+        process(&mut context, withdraw_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+        let withdrawn = context.banks_client.get_balance(creator).await.unwrap() + 5_000 - creator_lamports;
+        assert_eq!(withdrawn, SOFT_CAP);
+
+        let presale_account = context.banks_client
+            .get_account(get_presale_pda(&token_launch::id(), &mint).0)
+            .await.unwrap().unwrap();
+        let presale = instruction::decode_presale(&presale_account.data).unwrap();
+        assert!(presale.withdrawn);
+        assert_eq!(presale.total_raised, SOFT_CAP);
+    }
+
+    #[tokio::test]
+    async fn test_presale_misses_soft_cap() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        process(&mut context, create_presale_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+
+        let (alice, alice_tokens) = create_contributor(&mut context, &mint).await;
+        process(&mut context, contribute_instruction(&alice.pubkey(), &mint, 1_000_000_000), &[&alice]).await.unwrap();
+        let alice_lamports = context.banks_client.get_balance(alice.pubkey()).await.unwrap();
+
+        let result = process(&mut context, refund_instruction(&alice.pubkey(), &mint), &[&alice]).await;
+        assert!(result.is_err(), "Refund before the deadline should fail");
+
+        set_time(&mut context, DEADLINE).await;
+
+        let result = process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens), &[&alice]).await;
+        assert!(result.is_err(), "Claim after a failed presale should fail");
+
+        process(&mut context, refund_instruction(&alice.pubkey(), &mint), &[&alice]).await.unwrap();
+        assert_eq!(context.banks_client.get_balance(alice.pubkey()).await.unwrap(), alice_lamports + 1_000_000_000);
+
+        // A fresh blockhash keeps the retry from being deduplicated
+        context.get_new_latest_blockhash().await.unwrap();
+        let result = process(&mut context, refund_instruction(&alice.pubkey(), &mint), &[&alice]).await;
+        assert!(result.is_err(), "Double refund should fail");
+
+        let error = process(&mut context, withdraw_instruction(&alice.pubkey(), &mint, &alice_tokens), &[&alice]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAssetCreator as u32)), "Only the creator may withdraw");
+
+        process(&mut context, withdraw_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &creator_token_account).await, 1_000_000);
+
+        // A fresh blockhash keeps the retry from being deduplicated
+        context.get_new_latest_blockhash().await.unwrap();
+        let result = process(&mut context, withdraw_instruction(&creator, &mint, &creator_token_account), &[]).await;
+        assert!(result.is_err(), "Double withdraw should fail");
+    }
+
+    #[tokio::test]
+    async fn test_refund_rejects_contribution_at_another_address() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        process(&mut context, create_presale_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+
+        let (alice, _) = create_contributor(&mut context, &mint).await;
+        process(&mut context, contribute_instruction(&alice.pubkey(), &mint, 1_000_000_000), &[&alice]).await.unwrap();
+        set_time(&mut context, DEADLINE).await;
+
+        // A program account naming alice and the presale, but not at her contribution address
+        let presale = get_presale_pda(&token_launch::id(), &mint).0;
+        let contribution = get_contribution_pda(&token_launch::id(), &presale, &alice.pubkey()).0;
+        let forged = Contribution {
+            presale,
+            contributor: alice.pubkey(),
+            amount: 2_000_000_000,
+            settled: false,
+            bump: 255,
+        };
+        let forged_address = Pubkey::new_unique();
+        context.set_account(&forged_address, &Account {
+            lamports: 1_000_000_000,
+            data: forged.try_to_vec().unwrap(),
+            owner: token_launch::id(),
+            executable: false,
+            rent_epoch: 0,
+        }.into());
+
+        let mut refund = refund_instruction(&alice.pubkey(), &mint);
+        for meta in refund.accounts.iter_mut().filter(|meta| meta.pubkey == contribution) {
+            meta.pubkey = forged_address;
+        }
+        let error = process(&mut context, refund, &[&alice]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::InvalidPda as u32)));
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_sweeps_rounding_remainder() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        process(&mut context, create_presale_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+
+        // Shares of 199_999.9999 and 400_000.0000 tokens round down to leave one in the vault
+        let (alice, alice_tokens) = create_contributor(&mut context, &mint).await;
+        let (bob, bob_tokens) = create_contributor(&mut context, &mint).await;
+        process(&mut context, contribute_instruction(&alice.pubkey(), &mint, 1_000_000_000), &[&alice]).await.unwrap();
+        process(&mut context, contribute_instruction(&bob.pubkey(), &mint, 2_000_000_001), &[&bob]).await.unwrap();
+
+        set_time(&mut context, DEADLINE).await;
+        process(&mut context, withdraw_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+        process(&mut context, claim_instruction(&alice.pubkey(), &mint, &alice_tokens), &[&alice]).await.unwrap();
+
+        // Bob hasn't claimed yet, so nothing is left over to sweep
+        let error = process(&mut context, withdraw_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::AlreadyWithdrawn as u32)));
+
+        process(&mut context, claim_instruction(&bob.pubkey(), &mint, &bob_tokens), &[&bob]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &alice_tokens).await + token_balance(&mut context, &bob_tokens).await, TOKENS_FOR_SALE - 1);
+
+        context.get_new_latest_blockhash().await.unwrap();
+        process(&mut context, withdraw_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &creator_token_account).await, 1_000_000 - TOKENS_FOR_SALE + 1);
+        let vault = get_presale_vault_pda(&token_launch::id(), &get_presale_pda(&token_launch::id(), &mint).0).0;
+        assert_eq!(token_balance(&mut context, &vault).await, 0);
+
+        context.get_new_latest_blockhash().await.unwrap();
+        let result = process(&mut context, withdraw_instruction(&creator, &mint, &creator_token_account), &[]).await;
+        assert!(result.is_err(), "The remainder is only swept once");
+    }
+
+    #[tokio::test]
+    async fn test_only_the_creator_opens_a_presale() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let (mint, _) = launch_token(&mut context).await;
+        let (stranger, stranger_tokens) = create_contributor(&mut context, &mint).await;
+
+        // Otherwise anyone could take the mint's only presale address first
+        let instruction = create_presale_instruction(&stranger.pubkey(), &mint, &stranger_tokens);
+        let error = process(&mut context, instruction, &[&stranger]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAssetCreator as u32)));
+    }
+
+    #[tokio::test]
+    async fn test_presale_with_prefunded_pdas() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        let (alice, _) = create_contributor(&mut context, &mint).await;
+
+        // These addresses are predictable, so lamports sent there first must not block the presale
+        let presale = get_presale_pda(&token_launch::id(), &mint).0;
+        let vault = get_presale_vault_pda(&token_launch::id(), &presale).0;
+        let contribution = get_contribution_pda(&token_launch::id(), &presale, &alice.pubkey()).0;
+        for address in [presale, vault, contribution] {
+            process(&mut context, system_instruction::transfer(&creator, &address, 1_000_000), &[]).await.unwrap();
+        }
+
+        process(&mut context, create_presale_instruction(&creator, &mint, &creator_token_account), &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &vault).await, TOKENS_FOR_SALE);
+
+        process(&mut context, contribute_instruction(&alice.pubkey(), &mint, 1_000_000_000), &[&alice]).await.unwrap();
+        let presale_account = context.banks_client.get_account(presale).await.unwrap().unwrap();
+        assert_eq!(instruction::decode_presale(&presale_account.data).unwrap().total_raised, 1_000_000_000);
+    }
+}
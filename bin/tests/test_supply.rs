@@ -0,0 +1,275 @@
+#[cfg(test)]
+mod test_supply {
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        clock::Clock, instruction::{Instruction, InstructionError}, program_option::COption, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::{Transaction, TransactionError}
+    };
+    use token_launch::{
+        constants::TOKEN_PROGRAM_ID,
+        entrypoint::process_instruction,
+        error::LaunchpadError,
+        instruction,
+        state::{Asset, EmissionSchedule},
+        util::{get_creator_profile_pda, get_launched_asset_pda, get_mint_authority_pda},
+        AssetType, LaunchConfig,
+    };
+
+    // Helper function to create program test environment
+    fn create_program_test() -> ProgramTest {
+        ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        )
+    }
+
+    async fn process(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn advance_epochs(context: &mut ProgramTestContext, epochs: u64) {
+        let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+        clock.epoch += epochs;
+        context.set_sysvar(&clock);
+
+        // A transaction repeated after the clock moves would otherwise hit the status cache
+        context.get_new_latest_blockhash().await.unwrap();
+    }
+
+    async fn token_balance(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+        let account = context.banks_client.get_account(*token_account).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    async fn launched_asset(context: &mut ProgramTestContext, mint: &Pubkey) -> Asset {
+        let launched_asset = get_launched_asset_pda(&token_launch::id(), mint).0;
+        let account = context.banks_client.get_account(launched_asset).await.unwrap().unwrap();
+        *instruction::decode_asset(&account.data).unwrap()
+    }
+
+    // Launches recorded under `creator` so far, which is also the index of their next launch
+    async fn launch_count(context: &mut ProgramTestContext, creator: &Pubkey) -> u64 {
+        let creator_profile = get_creator_profile_pda(&token_launch::id(), creator).0;
+        match context.banks_client.get_account(creator_profile).await.unwrap() {
+            Some(account) => instruction::decode_creator_profile(&account.data).unwrap().launch_count,
+            None => 0,
+        }
+    }
+
+    // Launches a legacy token capped at `max_supply`, the payer's token account doubles as the treasury
+    async fn launch_token(context: &mut ProgramTestContext, max_supply: u64, emission_per_epoch: Option<u64>) -> (Pubkey, Pubkey) {
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+        let config = LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Capped Token".to_string(),
+            symbol: "CAP".to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/capped-metadata.json".to_string(),
+            creator: context.payer.pubkey(),
+            is_mutable: false,
+            max_supply,
+            emission: emission_per_epoch.map(|amount_per_epoch| EmissionSchedule {
+                amount_per_epoch,
+                treasury: token_account.pubkey(),
+            }),
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        };
+
+        let creator = context.payer.pubkey();
+        let launch_index = launch_count(context, &creator).await;
+
+        let instruction = instruction::launch_asset(&token_launch::id(), &creator, &mint.pubkey(), &token_account.pubkey(), &TOKEN_PROGRAM_ID, launch_index, config);
+
+        process(context, &[instruction], &[&mint, &token_account]).await.unwrap();
+
+        (mint.pubkey(), token_account.pubkey())
+    }
+
+    fn emit_instruction(mint: &Pubkey, treasury: &Pubkey) -> Instruction {
+        instruction::emit(&token_launch::id(), mint, treasury, &TOKEN_PROGRAM_ID)
+    }
+
+    fn mint_additional_instruction(creator: &Pubkey, mint: &Pubkey, destination: &Pubkey, amount: u64) -> Instruction {
+        instruction::mint_additional(&token_launch::id(), creator, mint, destination, &TOKEN_PROGRAM_ID, amount)
+    }
+
+    fn burn_instruction(owner: &Pubkey, mint: &Pubkey, token_account: &Pubkey, amount: u64) -> Instruction {
+        instruction::burn_tracked(&token_launch::id(), owner, mint, token_account, &TOKEN_PROGRAM_ID, amount)
+    }
+
+    async fn mint_supply(context: &mut ProgramTestContext, mint: &Pubkey) -> u64 {
+        let account = context.banks_client.get_account(*mint).await.unwrap().unwrap();
+        spl_token::state::Mint::unpack(&account.data).unwrap().supply
+    }
+
+    #[tokio::test]
+    async fn test_launch_hands_mint_authority_to_pda() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, _) = launch_token(&mut context, 2_000_000, None).await;
+
+        let mint_account = context.banks_client.get_account(mint).await.unwrap().unwrap();
+        let mint_state = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+        let mint_authority = get_mint_authority_pda(&token_launch::id(), &mint).0;
+        assert_eq!(mint_state.mint_authority, COption::Some(mint_authority));
+
+        let asset = launched_asset(&mut context, &mint).await;
+        assert_eq!(asset.mint, mint);
+        assert_eq!(asset.creator, context.payer.pubkey());
+        assert_eq!(asset.total_supply.get(), 1_000_000);
+        assert_eq!(asset.circulating_supply.get(), 1_000_000);
+        assert_eq!(asset.burned.get(), 0);
+        assert_eq!(asset.max_supply.get(), 2_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_mint_additional_up_to_cap() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, token_account) = launch_token(&mut context, 1_500_000, None).await;
+        let creator = context.payer.pubkey();
+
+        // One token past the cap is rejected
+        let result = process(&mut context, &[mint_additional_instruction(&creator, &mint, &token_account, 500_001)], &[]).await;
+        assert!(result.is_err(), "Minting past the cap should fail");
+
+        process(&mut context, &[mint_additional_instruction(&creator, &mint, &token_account, 500_000)], &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &token_account).await, 1_500_000);
+        assert_eq!(launched_asset(&mut context, &mint).await.total_supply.get(), 1_500_000);
+
+        let result = process(&mut context, &[mint_additional_instruction(&creator, &mint, &token_account, 1)], &[]).await;
+        assert!(result.is_err(), "Minting at the cap should fail");
+    }
+
+    #[tokio::test]
+    async fn test_mint_additional_requires_creator() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, token_account) = launch_token(&mut context, 2_000_000, None).await;
+
+        let stranger = Keypair::new();
+        let fund = system_instruction::transfer(&context.payer.pubkey(), &stranger.pubkey(), 1_000_000_000);
+        process(&mut context, &[fund], &[]).await.unwrap();
+
+        let error = process(&mut context, &[mint_additional_instruction(&stranger.pubkey(), &mint, &token_account, 1_000)], &[&stranger]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAssetCreator as u32)), "Only the creator may mint additional tokens");
+        assert_eq!(token_balance(&mut context, &token_account).await, 1_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_emit_accrues_per_epoch() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, treasury) = launch_token(&mut context, 2_000_000, Some(10_000)).await;
+
+        let result = process(&mut context, &[emit_instruction(&mint, &treasury)], &[]).await;
+        assert!(result.is_err(), "Nothing has accrued in the launch epoch");
+
+        advance_epochs(&mut context, 3).await;
+        process(&mut context, &[emit_instruction(&mint, &treasury)], &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &treasury).await, 1_030_000);
+
+        let asset = launched_asset(&mut context, &mint).await;
+        assert_eq!(asset.total_supply.get(), 1_030_000);
+
+        // Fresh blockhash so the retry isn't deduplicated as the same transaction
+        context.get_new_latest_blockhash().await.unwrap();
+        let result = process(&mut context, &[emit_instruction(&mint, &treasury)], &[]).await;
+        assert!(result.is_err(), "Emission for these epochs was already minted");
+    }
+
+    #[tokio::test]
+    async fn test_emit_stops_at_cap() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, treasury) = launch_token(&mut context, 1_025_000, Some(10_000)).await;
+
+        advance_epochs(&mut context, 5).await;
+        process(&mut context, &[emit_instruction(&mint, &treasury)], &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &treasury).await, 1_025_000);
+
+        advance_epochs(&mut context, 1).await;
+        let result = process(&mut context, &[emit_instruction(&mint, &treasury)], &[]).await;
+        assert!(result.is_err(), "Emission must not exceed the cap");
+    }
+
+    #[tokio::test]
+    async fn test_emit_rejects_other_treasury() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, _) = launch_token(&mut context, 2_000_000, Some(10_000)).await;
+        let (other_mint, other_token_account) = launch_token(&mut context, 2_000_000, None).await;
+        assert_ne!(mint, other_mint);
+
+        advance_epochs(&mut context, 1).await;
+        let result = process(&mut context, &[emit_instruction(&mint, &other_token_account)], &[]).await;
+        assert!(result.is_err(), "Emission may only go to the scheduled treasury");
+    }
+
+    #[tokio::test]
+    async fn test_burn_tracked_updates_record() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, token_account) = launch_token(&mut context, 2_000_000, None).await;
+        let owner = context.payer.pubkey();
+
+        process(&mut context, &[burn_instruction(&owner, &mint, &token_account, 250_000)], &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &token_account).await, 750_000);
+        assert_eq!(mint_supply(&mut context, &mint).await, 750_000);
+
+        let asset = launched_asset(&mut context, &mint).await;
+        assert_eq!(asset.total_supply.get(), 1_000_000);
+        assert_eq!(asset.burned.get(), 250_000);
+        assert_eq!(asset.circulating_supply.get(), 750_000);
+
+        // Minting after a burn keeps the record in step with the mint
+        process(&mut context, &[mint_additional_instruction(&owner, &mint, &token_account, 100_000)], &[]).await.unwrap();
+        let asset = launched_asset(&mut context, &mint).await;
+        assert_eq!(asset.circulating_supply.get(), mint_supply(&mut context, &mint).await);
+    }
+
+    #[tokio::test]
+    async fn test_burn_does_not_free_cap() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, token_account) = launch_token(&mut context, 1_000_000, None).await;
+        let owner = context.payer.pubkey();
+
+        process(&mut context, &[burn_instruction(&owner, &mint, &token_account, 250_000)], &[]).await.unwrap();
+        assert_eq!(mint_supply(&mut context, &mint).await, 750_000);
+
+        // The cap bounds everything ever minted, not what's left after the burn
+        let error = process(&mut context, &[mint_additional_instruction(&owner, &mint, &token_account, 1)], &[]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::SupplyCapExceeded as u32)));
+        assert_eq!(launched_asset(&mut context, &mint).await.total_supply.get(), 1_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_burn_tracked_requires_token_owner() {
+        let mut context = create_program_test().start_with_context().await;
+        let (mint, token_account) = launch_token(&mut context, 2_000_000, None).await;
+
+        let stranger = Keypair::new();
+        let fund = system_instruction::transfer(&context.payer.pubkey(), &stranger.pubkey(), 1_000_000_000);
+        process(&mut context, &[fund], &[]).await.unwrap();
+
+        let result = process(&mut context, &[burn_instruction(&stranger.pubkey(), &mint, &token_account, 1_000)], &[&stranger]).await;
+        assert!(result.is_err(), "Only the token account owner may burn");
+
+        let owner = context.payer.pubkey();
+        let result = process(&mut context, &[burn_instruction(&owner, &mint, &token_account, 1_000_001)], &[]).await;
+        assert!(result.is_err(), "Burning more than the balance should fail");
+
+        let asset = launched_asset(&mut context, &mint).await;
+        assert_eq!(asset.burned.get(), 0);
+        assert_eq!(asset.circulating_supply.get(), 1_000_000);
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod test_symbol {
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        account::Account, bpf_loader_upgradeable, instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::Transaction
+    };
+    use token_launch::{
+        constants::TOKEN_PROGRAM_ID,
+        entrypoint::process_instruction,
+        instruction,
+        state::{FeatureToggles, LaunchFees, SymbolRecord},
+        util::{get_creator_profile_pda, get_program_data_address, get_symbol_record_pda},
+        AssetType, LaunchConfig,
+    };
+
+    // Helper function to create program test environment, with a program data account naming `upgrade_authority`
+    fn create_program_test(upgrade_authority: &Pubkey) -> ProgramTest {
+        let mut program_test = ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        );
+
+        // UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address: Some(..) } in bincode
+        let mut data = 3u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(upgrade_authority.as_ref());
+
+        program_test.add_account(
+            get_program_data_address(&token_launch::id()),
+            Account {
+                lamports: 1_000_000_000,
+                data,
+                owner: bpf_loader_upgradeable::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        program_test
+    }
+
+    async fn process(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn symbol_record(context: &mut ProgramTestContext, symbol: &str) -> SymbolRecord {
+        let symbol_record = get_symbol_record_pda(&token_launch::id(), symbol).0;
+        let account = context.banks_client.get_account(symbol_record).await.unwrap().unwrap();
+        instruction::decode_symbol_record(&account.data).unwrap()
+    }
+
+    // Launches recorded under `creator` so far, which is also the index of their next launch
+    async fn launch_count(context: &mut ProgramTestContext, creator: &Pubkey) -> u64 {
+        let creator_profile = get_creator_profile_pda(&token_launch::id(), creator).0;
+        match context.banks_client.get_account(creator_profile).await.unwrap() {
+            Some(account) => instruction::decode_creator_profile(&account.data).unwrap().launch_count,
+            None => 0,
+        }
+    }
+
+    // Launches a legacy token with `symbol`, returning its mint
+    async fn launch_token(context: &mut ProgramTestContext, symbol: &str, unique_symbol: bool) -> Result<Pubkey, solana_program_test::BanksClientError> {
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+
+        let config = LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Symbol Token".to_string(),
+            symbol: symbol.to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/symbol-metadata.json".to_string(),
+            creator: context.payer.pubkey(),
+            is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        };
+
+        let creator = context.payer.pubkey();
+        let launch_index = launch_count(context, &creator).await;
+
+        let instruction = instruction::launch_asset(&token_launch::id(), &creator, &mint.pubkey(), &token_account.pubkey(), &TOKEN_PROGRAM_ID, launch_index, config);
+
+        process(context, &[instruction], &[&mint, &token_account]).await?;
+        Ok(mint.pubkey())
+    }
+
+    fn reserve_instruction(admin: &Pubkey, symbol: &str, reserved: bool) -> Instruction {
+        instruction::set_symbol_reserved(&token_launch::id(), admin, symbol.to_string(), reserved)
+    }
+
+    // Starts a test validator with the global config initialized, returning the admin
+    async fn setup() -> (ProgramTestContext, Keypair) {
+        let upgrade_authority = Keypair::new();
+        let mut context = create_program_test(&upgrade_authority.pubkey()).start_with_context().await;
+
+        let admin = Keypair::new();
+        for wallet in [upgrade_authority.pubkey(), admin.pubkey()] {
+            let transfer = system_instruction::transfer(&context.payer.pubkey(), &wallet, 1_000_000_000);
+            process(&mut context, &[transfer], &[]).await.unwrap();
+        }
+
+        let initialize = instruction::initialize_global_config(
+            &token_launch::id(),
+            &upgrade_authority.pubkey(),
+            admin.pubkey(),
+            admin.pubkey(),
+            Pubkey::new_unique(),
+            LaunchFees { spl_token_legacy: 0, spl_token_2022: 0, standard_nft: 0 },
+            FeatureToggles::default(),
+        );
+        process(&mut context, &[initialize], &[&upgrade_authority]).await.unwrap();
+
+        (context, admin)
+    }
+
+    #[tokio::test]
+    async fn test_unique_symbol_is_claimed_once() {
+        let (mut context, _) = setup().await;
+
+        let mint = launch_token(&mut context, "uniq", true).await.unwrap();
+        let record = symbol_record(&mut context, "UNIQ").await;
+        assert_eq!(record.symbol, "UNIQ");
+        assert_eq!(record.mint, mint);
+        assert!(!record.reserved);
+
+        let result = launch_token(&mut context, " UNIQ", true).await;
+        assert!(result.is_err(), "A claimed symbol can't be claimed again, whatever its case or padding");
+
+        // Launches that don't ask for uniqueness may still share the symbol
+        launch_token(&mut context, "Uniq", false).await.unwrap();
+        assert_eq!(symbol_record(&mut context, "UNIQ").await.mint, mint);
+    }
+
+    #[tokio::test]
+    async fn test_non_unique_launch_does_not_claim() {
+        let (mut context, _) = setup().await;
+
+        launch_token(&mut context, "SHARE", false).await.unwrap();
+        let symbol_record = get_symbol_record_pda(&token_launch::id(), "SHARE").0;
+        assert!(context.banks_client.get_account(symbol_record).await.unwrap().is_none());
+
+        launch_token(&mut context, "SHARE", true).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_prefunded_symbol_can_be_claimed() {
+        let (mut context, _) = setup().await;
+
+        // Anyone can derive the record's address and fund it before the first claim
+        let symbol_record_pda = get_symbol_record_pda(&token_launch::id(), "FUND").0;
+        let lamports = context.banks_client.get_rent().await.unwrap().minimum_balance(0);
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &symbol_record_pda, lamports);
+        process(&mut context, &[transfer], &[]).await.unwrap();
+
+        let mint = launch_token(&mut context, "FUND", true).await.unwrap();
+        assert_eq!(symbol_record(&mut context, "FUND").await.mint, mint);
+    }
+
+    #[tokio::test]
+    async fn test_reserved_symbol_blocks_every_launch() {
+        let (mut context, admin) = setup().await;
+
+        let stranger = Keypair::new();
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &stranger.pubkey(), 1_000_000_000);
+        process(&mut context, &[transfer], &[]).await.unwrap();
+        let result = process(&mut context, &[reserve_instruction(&stranger.pubkey(), "USDC", true)], &[&stranger]).await;
+        assert!(result.is_err(), "Only the admin may reserve symbols");
+
+        process(&mut context, &[reserve_instruction(&admin.pubkey(), "usdc", true)], &[&admin]).await.unwrap();
+        assert!(symbol_record(&mut context, "USDC").await.reserved);
+
+        let result = launch_token(&mut context, "USDC", false).await;
+        assert!(result.is_err(), "Reserved symbols are blocked even without uniqueness");
+        let result = launch_token(&mut context, "usdc", true).await;
+        assert!(result.is_err(), "Reserved symbols can't be claimed");
+
+        process(&mut context, &[reserve_instruction(&admin.pubkey(), "USDC", false)], &[&admin]).await.unwrap();
+        launch_token(&mut context, "USDC", true).await.unwrap();
+    }
+}
@@ -1,10 +1,10 @@
 #[cfg(test)]
 mod test_unhappy {
     
-    use borsh::BorshSerialize;
-    use token_launch::{constants::TOKEN_PROGRAM_ID, entrypoint::process_instruction, AssetType, LaunchConfig, LaunchpadInstruction};
-    use solana_program_test::{processor, ProgramTest};
-    use solana_sdk::{instruction::{AccountMeta, Instruction}, pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use token_launch::{constants::{LAUNCHED_ASSET_SEED, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID}, entrypoint::process_instruction, error::LaunchpadError, instruction, util::{get_creator_profile_pda, get_launch_index_pda, get_launched_asset_pda}, AssetType, LaunchConfig, LaunchpadInstruction};
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{account::Account, instruction::{Instruction, InstructionError}, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::{Transaction, TransactionError}};
 
     // Helper function to create program test environment
     fn create_program_test() -> ProgramTest {
@@ -21,21 +21,6 @@ mod test_unhappy {
         let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
 
         let mint = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(
-            &[b"launched_asset", mint.pubkey().as_ref()], 
-            &token_launch::id()
-        ).0;
-
-        // Missing token_account in accounts
-        let accounts = vec![
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new(mint.pubkey(), true),
-            // Missing token account
-            AccountMeta::new(metadata_account, false),
-            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false), 
-            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
-        ];
 
         let config = LaunchConfig {
             asset_type: AssetType::SplToken2022,
@@ -46,17 +31,24 @@ mod test_unhappy {
             metadata_uri: "https://example.com/wrong-metadata.json".to_string(),
             creator: payer.pubkey(),
             is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
-        let data = LaunchpadInstruction::LaunchAsset { config }
-            .try_to_vec()
-            .unwrap();
-
-        let instruction = Instruction {
-            program_id: token_launch::id(),
-            accounts,
-            data,
-        };
+        let mut instruction = instruction::launch_asset(
+            &token_launch::id(),
+            &payer.pubkey(),
+            &mint.pubkey(),
+            &Pubkey::new_unique(),
+            &TOKEN_2022_PROGRAM_ID,
+            0,
+            config,
+        );
+        // Missing token account
+        instruction.accounts.remove(2);
 
         let tx = Transaction::new_signed_with_payer(
             &[instruction],
@@ -76,21 +68,6 @@ mod test_unhappy {
 
         let mint = Keypair::new();
         let token_account = Keypair::new();
-        let metadata_account = Pubkey::find_program_address(
-            &[b"launched_asset", mint.pubkey().as_ref()], 
-            &token_launch::id()
-        ).0;
-
-        // Missing token_account in accounts
-        let accounts = vec![
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new(mint.pubkey(), true),
-            AccountMeta::new(token_account.pubkey(), true),
-            AccountMeta::new(metadata_account, false),
-            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false), // Should be TOKEN_2022_PROGRAM_ID
-            AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
-        ];
 
         let config = LaunchConfig {
             asset_type: AssetType::SplToken2022,
@@ -101,17 +78,22 @@ mod test_unhappy {
             metadata_uri: "https://example.com/missing-metadata.json".to_string(),
             creator: payer.pubkey(),
             is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         };
 
-        let data = LaunchpadInstruction::LaunchAsset { config }
-            .try_to_vec()
-            .unwrap();
-
-        let instruction = Instruction {
-            program_id: token_launch::id(),
-            accounts,
-            data,
-        };
+        let instruction = instruction::launch_asset(
+            &token_launch::id(),
+            &payer.pubkey(),
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &TOKEN_PROGRAM_ID, // Should be TOKEN_2022_PROGRAM_ID
+            0,
+            config,
+        );
 
         let tx = Transaction::new_signed_with_payer(
             &[instruction],
@@ -124,4 +106,320 @@ mod test_unhappy {
         assert!(result.is_err(), "Wrong token program passed should fail");
     }
 
-}
\ No newline at end of file
+    // Accounts of a launch, funded and signed by `payer` rather than the transaction fee payer
+    struct Launch {
+        payer: Keypair,
+        mint: Keypair,
+        token_account: Keypair,
+    }
+
+    impl Launch {
+        fn instruction(&self, asset_type: AssetType) -> Instruction {
+            let payer = self.payer.pubkey();
+            let token_program = match asset_type {
+                AssetType::SplTokenLegacy => TOKEN_PROGRAM_ID,
+                _ => TOKEN_2022_PROGRAM_ID,
+            };
+            let (decimals, total_supply) = match asset_type {
+                AssetType::StandardNft => (0, 1),
+                _ => (6, 1_000_000),
+            };
+            let config = LaunchConfig {
+                asset_type,
+                name: "Checked Token".to_string(),
+                symbol: "CHK".to_string(),
+                decimals,
+                total_supply,
+                metadata_uri: "https://example.com/checked-metadata.json".to_string(),
+                creator: payer,
+                is_mutable: false,
+                max_supply: total_supply,
+                emission: None,
+                unique_symbol: false,
+                deterministic_mint: false,
+                extensions: Vec::new(),
+            };
+
+            instruction::launch_asset(
+                &token_launch::id(),
+                &payer,
+                &self.mint.pubkey(),
+                &self.token_account.pubkey(),
+                &token_program,
+                0,
+                config,
+            )
+        }
+    }
+
+    // Starts `program_test` with a funded launch payer that isn't the fee payer, so its flags are up to the test
+    async fn start(program_test: ProgramTest) -> (ProgramTestContext, Launch) {
+        let mut context = program_test.start_with_context().await;
+        let launch = Launch {
+            payer: Keypair::new(),
+            mint: Keypair::new(),
+            token_account: Keypair::new(),
+        };
+
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &launch.payer.pubkey(), 1_000_000_000);
+        send(&mut context, transfer, &[]).await.unwrap();
+
+        (context, launch)
+    }
+
+    async fn send(context: &mut ProgramTestContext, instruction: Instruction, signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    // Sends `instruction`, signed by whichever launch keypairs it still marks as signers, and expects `expected`
+    async fn assert_launch_fails(context: &mut ProgramTestContext, launch: &Launch, instruction: Instruction, expected: InstructionError) {
+        let signers: Vec<&Keypair> = [&launch.payer, &launch.mint, &launch.token_account]
+            .into_iter()
+            .filter(|keypair| instruction.accounts.iter().any(|meta| meta.pubkey == keypair.pubkey() && meta.is_signer))
+            .collect();
+
+        let error = send(context, instruction, &signers).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, expected));
+    }
+
+    fn custom(error: LaunchpadError) -> InstructionError {
+        InstructionError::Custom(error as u32)
+    }
+
+    #[tokio::test]
+    async fn test_launch_payer_must_sign() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[0].is_signer = false;
+        instruction.accounts[11].is_signer = false;
+        instruction.accounts[11].pubkey = context.payer.pubkey();
+
+        assert_launch_fails(&mut context, &launch, instruction, InstructionError::MissingRequiredSignature).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_payer_must_be_writable() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[0].is_writable = false;
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::AccountNotWritable)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_creator_must_match_config() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[11].pubkey = context.payer.pubkey();
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::CreatorMismatch)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_mint_must_sign() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[1].is_signer = false;
+
+        assert_launch_fails(&mut context, &launch, instruction, InstructionError::MissingRequiredSignature).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_mint_must_be_writable() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[1].is_writable = false;
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::AccountNotWritable)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_mint_must_be_empty() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &launch.mint.pubkey(), 1_000_000);
+        send(&mut context, transfer, &[]).await.unwrap();
+
+        let instruction = launch.instruction(AssetType::SplTokenLegacy);
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::AccountNotEmpty)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_token_account_must_sign() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[2].is_signer = false;
+
+        assert_launch_fails(&mut context, &launch, instruction, InstructionError::MissingRequiredSignature).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_token_account_must_be_writable() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[2].is_writable = false;
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::AccountNotWritable)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_token_account_must_be_empty() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &launch.token_account.pubkey(), 1_000_000);
+        send(&mut context, transfer, &[]).await.unwrap();
+
+        let instruction = launch.instruction(AssetType::SplTokenLegacy);
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::AccountNotEmpty)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_wrong_launched_asset_pda() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[3].pubkey = get_launched_asset_pda(&token_launch::id(), &Pubkey::new_unique()).0;
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::InvalidPda)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_wrong_launched_asset_bump() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        let LaunchpadInstruction::LaunchAsset { config, mut bumps } = LaunchpadInstruction::try_from_slice(&instruction.data).unwrap() else {
+            unreachable!()
+        };
+        bumps.launched_asset = bumps.launched_asset.wrapping_sub(1);
+        instruction.data = LaunchpadInstruction::LaunchAsset { config, bumps }.try_to_vec().unwrap();
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::InvalidPda)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_non_canonical_launched_asset_bump() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        let LaunchpadInstruction::LaunchAsset { config, mut bumps } = LaunchpadInstruction::try_from_slice(&instruction.data).unwrap() else {
+            unreachable!()
+        };
+
+        // A lower bump that is off the curve too gives a valid address nothing else would look up
+        let (bump, address) = (0..bumps.launched_asset)
+            .rev()
+            .find_map(|bump| {
+                Pubkey::create_program_address(&[LAUNCHED_ASSET_SEED, launch.mint.pubkey().as_ref(), &[bump]], &token_launch::id())
+                    .ok()
+                    .map(|address| (bump, address))
+            })
+            .unwrap();
+        bumps.launched_asset = bump;
+        instruction.accounts[3].pubkey = address;
+        instruction.data = LaunchpadInstruction::LaunchAsset { config, bumps }.try_to_vec().unwrap();
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::InvalidPda)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_wrong_system_program() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[4].pubkey = Pubkey::new_unique();
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::IncorrectSystemProgram)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_nft_with_wrong_token_program() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::StandardNft);
+        instruction.accounts[5].pubkey = Pubkey::new_unique();
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::IncorrectTokenProgram)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_with_wrong_rent_sysvar() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+        instruction.accounts[6].pubkey = solana_sdk::sysvar::clock::id();
+
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::IncorrectSysvar)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_pdas_must_be_writable() {
+        for index in 7..=10 {
+            let (mut context, launch) = start(create_program_test()).await;
+            let mut instruction = launch.instruction(AssetType::SplTokenLegacy);
+            instruction.accounts[index].is_writable = false;
+
+            assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::AccountNotWritable)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_launch_pda_owned_by_another_program() {
+        let mut program_test = create_program_test();
+        let payer = Keypair::new();
+        program_test.add_account(
+            get_creator_profile_pda(&token_launch::id(), &payer.pubkey()).0,
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![0; 8],
+                owner: Pubkey::new_unique(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let (mut context, mut launch) = start(program_test).await;
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &payer.pubkey(), 1_000_000_000);
+        send(&mut context, transfer, &[]).await.unwrap();
+        launch.payer = payer;
+
+        let instruction = launch.instruction(AssetType::SplTokenLegacy);
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::InvalidAccountOwner)).await;
+    }
+
+    #[tokio::test]
+    async fn test_launch_index_must_be_empty() {
+        // Lamports alone don't make the index exist, anyone can send them, but allocated data does
+        let mut program_test = create_program_test();
+        let payer = Keypair::new();
+        program_test.add_account(
+            get_launch_index_pda(&token_launch::id(), &payer.pubkey(), 0).0,
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![0; 8],
+                owner: solana_sdk::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let (mut context, mut launch) = start(program_test).await;
+        let transfer = system_instruction::transfer(&context.payer.pubkey(), &payer.pubkey(), 1_000_000_000);
+        send(&mut context, transfer, &[]).await.unwrap();
+        launch.payer = payer;
+
+        let instruction = launch.instruction(AssetType::SplTokenLegacy);
+        assert_launch_fails(&mut context, &launch, instruction, custom(LaunchpadError::AccountNotEmpty)).await;
+    }
+
+    #[tokio::test]
+    async fn test_checked_launch_succeeds() {
+        let (mut context, launch) = start(create_program_test()).await;
+        let instruction = launch.instruction(AssetType::SplTokenLegacy);
+
+        send(&mut context, instruction, &[&launch.payer, &launch.mint, &launch.token_account]).await.unwrap();
+    }
+}
@@ -0,0 +1,275 @@
+#[cfg(test)]
+mod test_voucher {
+    use borsh::BorshSerialize;
+    use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        clock::Clock, instruction::{Instruction, InstructionError}, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction, transaction::{Transaction, TransactionError}
+    };
+    use token_launch::{
+        constants::TOKEN_PROGRAM_ID,
+        ed25519::new_ed25519_instruction,
+        entrypoint::process_instruction,
+        error::LaunchpadError,
+        instruction,
+        state::Voucher,
+        util::{get_creator_profile_pda, get_voucher_config_pda, get_voucher_nonce_pda},
+        AssetType, LaunchConfig, LaunchpadInstruction,
+    };
+
+    // Helper function to create program test environment
+    fn create_program_test() -> ProgramTest {
+        ProgramTest::new(
+            "token_launch",
+            token_launch::id(),
+            processor!(process_instruction),
+        )
+    }
+
+    async fn process(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), solana_program_test::BanksClientError> {
+        let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+        context.last_blockhash = recent_blockhash;
+        let mut all_signers = vec![&context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn set_time(context: &mut ProgramTestContext, unix_timestamp: i64) {
+        let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        context.set_sysvar(&clock);
+
+        // A transaction repeated after the clock moves would otherwise hit the status cache
+        context.get_new_latest_blockhash().await.unwrap();
+    }
+
+    async fn token_balance(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+        let account = context.banks_client.get_account(*token_account).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    // Launches recorded under `creator` so far, which is also the index of their next launch
+    async fn launch_count(context: &mut ProgramTestContext, creator: &Pubkey) -> u64 {
+        let creator_profile = get_creator_profile_pda(&token_launch::id(), creator).0;
+        match context.banks_client.get_account(creator_profile).await.unwrap() {
+            Some(account) => instruction::decode_creator_profile(&account.data).unwrap().launch_count,
+            None => 0,
+        }
+    }
+
+    // Launches a legacy token and returns its mint and the payer's token account
+    async fn launch_token(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+        let mint = Keypair::new();
+        let token_account = Keypair::new();
+        let config = LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Voucher Token".to_string(),
+            symbol: "VCH".to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/voucher-metadata.json".to_string(),
+            creator: context.payer.pubkey(),
+            is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
+        };
+
+        let creator = context.payer.pubkey();
+        let launch_index = launch_count(context, &creator).await;
+
+        let instruction = instruction::launch_asset(&token_launch::id(), &creator, &mint.pubkey(), &token_account.pubkey(), &TOKEN_PROGRAM_ID, launch_index, config);
+
+        process(context, &[instruction], &[&mint, &token_account]).await.unwrap();
+
+        (mint.pubkey(), token_account.pubkey())
+    }
+
+    // Creates a funded wallet with an empty token account for the mint
+    async fn create_wallet(context: &mut ProgramTestContext, mint: &Pubkey) -> (Keypair, Pubkey) {
+        let wallet = Keypair::new();
+        let token_account = Keypair::new();
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let instructions = [
+            system_instruction::transfer(&context.payer.pubkey(), &wallet.pubkey(), 1_000_000_000),
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &token_account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_account3(&spl_token::id(), &token_account.pubkey(), mint, &wallet.pubkey()).unwrap(),
+        ];
+
+        process(context, &instructions, &[&token_account]).await.unwrap();
+
+        (wallet, token_account.pubkey())
+    }
+
+    fn register_instruction(creator: &Pubkey, mint: &Pubkey, creator_token_account: &Pubkey, voucher_signer: &Pubkey) -> Instruction {
+        instruction::register_voucher_signer(&token_launch::id(), creator, mint, creator_token_account, &TOKEN_PROGRAM_ID, *voucher_signer, 10_000)
+    }
+
+    // Ed25519 verification followed by the claim, signed by `signer`
+    fn claim_instructions(signer: &Keypair, claimant: &Pubkey, claimant_token_account: &Pubkey, voucher: Voucher) -> Vec<Instruction> {
+        let message = voucher.try_to_vec().unwrap();
+        let signature: [u8; 64] = signer.sign_message(&message).as_ref().try_into().unwrap();
+
+        vec![
+            new_ed25519_instruction(&signer.pubkey(), &signature, &message),
+            instruction::claim_with_voucher(&token_launch::id(), claimant, claimant_token_account, &TOKEN_PROGRAM_ID, voucher),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_claim_with_voucher() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let voucher_signer = Keypair::new();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        let (alice, alice_tokens) = create_wallet(&mut context, &mint).await;
+
+        process(&mut context, &[register_instruction(&creator, &mint, &creator_token_account, &voucher_signer.pubkey())], &[]).await.unwrap();
+
+        let voucher = Voucher { mint, wallet: alice.pubkey(), amount: 2_500, nonce: 1, expiry: 100 };
+        process(&mut context, &claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher.clone()), &[&alice]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &alice_tokens).await, 2_500);
+
+        // A fresh blockhash keeps the replay from being deduplicated
+        context.get_new_latest_blockhash().await.unwrap();
+        let result = process(&mut context, &claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher), &[&alice]).await;
+        assert!(result.is_err(), "Replayed voucher should fail");
+
+        let voucher = Voucher { mint, wallet: alice.pubkey(), amount: 1_000, nonce: 2, expiry: 100 };
+        process(&mut context, &claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher), &[&alice]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &alice_tokens).await, 3_500);
+    }
+
+    #[tokio::test]
+    async fn test_claim_with_invalid_voucher() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let voucher_signer = Keypair::new();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        let (alice, alice_tokens) = create_wallet(&mut context, &mint).await;
+        let (bob, _) = create_wallet(&mut context, &mint).await;
+
+        process(&mut context, &[register_instruction(&creator, &mint, &creator_token_account, &voucher_signer.pubkey())], &[]).await.unwrap();
+
+        let voucher = Voucher { mint, wallet: alice.pubkey(), amount: 2_500, nonce: 1, expiry: 100 };
+
+        let result = process(&mut context, &claim_instructions(&Keypair::new(), &alice.pubkey(), &alice_tokens, voucher.clone()), &[&alice]).await;
+        assert!(result.is_err(), "Voucher from an unregistered signer should fail");
+
+        let result = process(&mut context, &claim_instructions(&voucher_signer, &bob.pubkey(), &alice_tokens, voucher.clone()), &[&bob]).await;
+        assert!(result.is_err(), "Voucher claimed by another wallet should fail");
+
+        let instructions = claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher.clone());
+        let result = process(&mut context, &instructions[1..], &[&alice]).await;
+        assert!(result.is_err(), "Claim without the Ed25519 instruction should fail");
+
+        let mut instructions = claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher.clone());
+        instructions[1].data = LaunchpadInstruction::ClaimWithVoucher {
+            voucher: Voucher { amount: 9_000, ..voucher.clone() },
+        }.try_to_vec().unwrap();
+        let result = process(&mut context, &instructions, &[&alice]).await;
+        assert!(result.is_err(), "Claim for more than the signed amount should fail");
+
+        set_time(&mut context, 100).await;
+        let result = process(&mut context, &claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher), &[&alice]).await;
+        assert!(result.is_err(), "Expired voucher should fail");
+
+        assert_eq!(token_balance(&mut context, &alice_tokens).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_only_the_creator_registers_a_voucher_signer() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let (mint, _) = launch_token(&mut context).await;
+        let (stranger, stranger_tokens) = create_wallet(&mut context, &mint).await;
+
+        // Otherwise anyone could take the mint's only voucher config and pick its signer
+        let instruction = register_instruction(&stranger.pubkey(), &mint, &stranger_tokens, &stranger.pubkey());
+        let error = process(&mut context, &[instruction], &[&stranger]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAssetCreator as u32)));
+    }
+
+    #[tokio::test]
+    async fn test_claim_with_prefunded_nonce() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let voucher_signer = Keypair::new();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        let (alice, alice_tokens) = create_wallet(&mut context, &mint).await;
+
+        process(&mut context, &[register_instruction(&creator, &mint, &creator_token_account, &voucher_signer.pubkey())], &[]).await.unwrap();
+
+        // Nonce addresses are predictable, so lamports sent there first must not burn the voucher
+        let voucher_config = get_voucher_config_pda(&token_launch::id(), &mint).0;
+        let nonce = get_voucher_nonce_pda(&token_launch::id(), &voucher_config, 1).0;
+        process(&mut context, &[system_instruction::transfer(&creator, &nonce, 1_000_000)], &[]).await.unwrap();
+
+        let voucher = Voucher { mint, wallet: alice.pubkey(), amount: 2_500, nonce: 1, expiry: 100 };
+        process(&mut context, &claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher.clone()), &[&alice]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &alice_tokens).await, 2_500);
+
+        context.get_new_latest_blockhash().await.unwrap();
+        let error = process(&mut context, &claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher), &[&alice]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(1, InstructionError::Custom(LaunchpadError::VoucherNonceUsed as u32)));
+    }
+
+    #[tokio::test]
+    async fn test_reclaim_voucher_tokens() {
+        let mut context = create_program_test().start_with_context().await;
+        set_time(&mut context, 0).await;
+
+        let creator = context.payer.pubkey();
+        let voucher_signer = Keypair::new();
+        let (mint, creator_token_account) = launch_token(&mut context).await;
+        let (alice, alice_tokens) = create_wallet(&mut context, &mint).await;
+
+        process(&mut context, &[register_instruction(&creator, &mint, &creator_token_account, &voucher_signer.pubkey())], &[]).await.unwrap();
+
+        let voucher = Voucher { mint, wallet: alice.pubkey(), amount: 2_500, nonce: 1, expiry: 100 };
+        process(&mut context, &claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher), &[&alice]).await.unwrap();
+
+        let reclaim = |creator: &Pubkey, creator_token_account: &Pubkey| {
+            instruction::reclaim_voucher_tokens(&token_launch::id(), creator, &mint, creator_token_account, &TOKEN_PROGRAM_ID)
+        };
+
+        let error = process(&mut context, &[reclaim(&alice.pubkey(), &alice_tokens)], &[&alice]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::NotAssetCreator as u32)));
+
+        let before = token_balance(&mut context, &creator_token_account).await;
+        process(&mut context, &[reclaim(&creator, &creator_token_account)], &[]).await.unwrap();
+        assert_eq!(token_balance(&mut context, &creator_token_account).await, before + 7_500);
+
+        // Outstanding vouchers can no longer be redeemed
+        let voucher = Voucher { mint, wallet: alice.pubkey(), amount: 1_000, nonce: 2, expiry: 100 };
+        let error = process(&mut context, &claim_instructions(&voucher_signer, &alice.pubkey(), &alice_tokens, voucher), &[&alice]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(1, InstructionError::Custom(LaunchpadError::VoucherTokensReclaimed as u32)));
+
+        context.get_new_latest_blockhash().await.unwrap();
+        let error = process(&mut context, &[reclaim(&creator, &creator_token_account)], &[]).await.unwrap_err().unwrap();
+        assert_eq!(error, TransactionError::InstructionError(0, InstructionError::Custom(LaunchpadError::VoucherTokensReclaimed as u32)));
+    }
+}
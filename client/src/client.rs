@@ -0,0 +1,352 @@
+use borsh::BorshDeserialize;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    instruction::Instruction, message::Message, program_error::ProgramError, pubkey::Pubkey, signature::{Keypair, Signature}, signer::Signer, transaction::Transaction
+};
+use token_launch::{
+    event::{parse_events, LaunchpadEvent},
+    instruction::{self, decode_asset, decode_creator_profile},
+    util::{get_creator_profile_pda, get_deterministic_mint_pda, get_launched_asset_pda, validate_launch_config},
+    Asset, AssetType, LaunchConfig, LaunchpadInstruction,
+};
+
+use crate::{
+    error::ClientError,
+    launch::{token_program_for, LaunchOptions, LaunchResult, PreparedLaunch, Submission},
+    mint::MintState,
+    transaction::encode_transaction,
+    transport::{Simulation, Transport},
+};
+
+/// Narrows `list_assets`, every set field has to match
+#[derive(Debug, Default, Clone)]
+pub struct AssetFilter {
+    pub creator: Option<Pubkey>,
+    pub asset_type: Option<AssetType>,
+    /// Earliest launch timestamp, inclusive
+    pub launched_after: Option<i64>,
+    /// Latest launch timestamp, inclusive
+    pub launched_before: Option<i64>,
+}
+
+impl AssetFilter {
+    /// Whether `asset` falls in the launch time range, which memcmp filters can't express so it's checked after fetching
+    pub fn matches_launch_time(&self, asset: &Asset) -> bool {
+        let launched = asset.launch_timestamp.get();
+        self.launched_after.map_or(true, |after| launched >= after) && self.launched_before.map_or(true, |before| launched <= before)
+    }
+
+    /// getProgramAccounts filters matching launched asset records
+    pub fn to_rpc_filters(&self) -> Vec<RpcFilterType> {
+        let mut filters = vec![
+            RpcFilterType::DataSize(Asset::LEN as u64),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(Asset::DISCRIMINATOR_OFFSET, &Asset::DISCRIMINATOR)),
+        ];
+        if let Some(creator) = &self.creator {
+            filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(Asset::CREATOR_OFFSET, creator.as_ref())));
+        }
+        if let Some(asset_type) = &self.asset_type {
+            filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(Asset::ASSET_TYPE_OFFSET, &[asset_type.clone() as u8])));
+        }
+        filters
+    }
+}
+
+/// Launchpad operations over a `Transport`, with `payer` paying fees and rent
+pub struct LaunchpadClient<T: Transport, S: Signer> {
+    transport: T,
+    payer: S,
+    program_id: Pubkey,
+}
+
+impl<T: Transport, S: Signer> LaunchpadClient<T, S> {
+    pub fn new(transport: T, payer: S, program_id: Pubkey) -> Self {
+        LaunchpadClient { transport, payer, program_id }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn payer(&self) -> &S {
+        &self.payer
+    }
+
+    pub fn program_id(&self) -> &Pubkey {
+        &self.program_id
+    }
+
+    /// Index the creator's next launch will be recorded at
+    pub async fn next_launch_index(&self, creator: &Pubkey) -> Result<u64, ClientError> {
+        let creator_profile = get_creator_profile_pda(&self.program_id, creator).0;
+
+        match self.get_account_data(&creator_profile).await {
+            Ok(data) => decode_creator_profile(&data)
+                .map(|profile| profile.launch_count)
+                .map_err(|error| ClientError::InvalidAccount(creator_profile, error)),
+            Err(ClientError::AccountNotFound(_)) => Ok(0),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Validates `config` and builds its launch instruction paid by the payer
+    /// The mint is the creator's next deterministic mint or a fresh keypair
+    pub async fn prepare_launch(&self, config: LaunchConfig) -> Result<PreparedLaunch, ClientError> {
+        self.prepare_launch_for(config, &self.payer.pubkey(), None).await
+    }
+
+    async fn prepare_launch_for(&self, config: LaunchConfig, fee_payer: &Pubkey, mint: Option<&Keypair>) -> Result<PreparedLaunch, ClientError> {
+        validate_launch_config(&config).map_err(ClientError::InvalidConfig)?;
+
+        let launch_index = self.next_launch_index(&config.creator).await?;
+        let (mint, mint_keypair) = match (config.deterministic_mint, mint) {
+            (true, None) => (get_deterministic_mint_pda(&self.program_id, &config.creator, launch_index).0, None),
+            (true, Some(_)) => return Err(ClientError::DeterministicMintKeypair),
+            (false, mint) => {
+                let mint_keypair = mint.map_or_else(Keypair::new, Keypair::insecure_clone);
+                (mint_keypair.pubkey(), Some(mint_keypair))
+            }
+        };
+        let token_account = Keypair::new();
+        let asset_type = config.asset_type.clone();
+
+        let instruction = instruction::launch_asset(
+            &self.program_id,
+            fee_payer,
+            &mint,
+            &token_account.pubkey(),
+            &token_program_for(&asset_type),
+            launch_index,
+            config,
+        );
+
+        Ok(PreparedLaunch {
+            instruction,
+            asset_type,
+            mint,
+            mint_keypair,
+            token_account,
+            launched_asset: get_launched_asset_pda(&self.program_id, &mint).0,
+            launch_index,
+        })
+    }
+
+    /// Launches `config` and, unless a relayer pays, sends it and collects the events it logged
+    pub async fn launch(&self, config: LaunchConfig, options: LaunchOptions<'_>) -> Result<LaunchResult, ClientError> {
+        let creator = options.creator.unwrap_or(&self.payer);
+        if config.creator != creator.pubkey() {
+            return Err(ClientError::CreatorMismatch { config: config.creator, signer: creator.pubkey() });
+        }
+
+        let fee_payer = options.relayer.unwrap_or(self.payer.pubkey());
+        let launch = self.prepare_launch_for(config, &fee_payer, options.mint).await?;
+
+        let mut signers = launch.signers();
+        signers.push(creator);
+
+        let recent_blockhash = self.transport.get_latest_blockhash().await?;
+        let mut tx = Transaction::new_with_payer(std::slice::from_ref(&launch.instruction), Some(&fee_payer));
+        let (submission, events) = match options.relayer {
+            None => {
+                signers.push(&self.payer);
+                tx.try_sign(&signers, recent_blockhash)?;
+                let confirmation = self.transport.send_and_confirm(&tx).await?;
+
+                // The launch landed either way, its events are only a convenience
+                let events = match &confirmation.logs {
+                    Some(logs) => parse_events(&self.program_id, logs),
+                    None => self.transaction_events(&confirmation.signature).await.unwrap_or_default(),
+                };
+                (Submission::Sent(confirmation.signature), events)
+            }
+            Some(_) => {
+                tx.try_partial_sign(&signers, recent_blockhash)?;
+                (Submission::PartiallySigned(encode_transaction(&tx)?), Vec::new())
+            }
+        };
+
+        Ok(LaunchResult {
+            asset_type: launch.asset_type,
+            mint: launch.mint,
+            token_account: launch.token_account.pubkey(),
+            launched_asset: launch.launched_asset,
+            launch_index: launch.launch_index,
+            submission,
+            events,
+        })
+    }
+
+    /// Co-signs a partially signed launch as its relayer and sends it, refusing anything but a single launch paid by the relayer
+    pub async fn cosign(&self, mut tx: Transaction) -> Result<Signature, ClientError> {
+        if tx.message.account_keys.first() != Some(&self.payer.pubkey()) {
+            return Err(ClientError::NotRelayer(self.payer.pubkey()));
+        }
+
+        // Only sponsor launchpad instructions, anything else could spend the relayer's funds
+        for instruction in &tx.message.instructions {
+            if tx.message.account_keys.get(instruction.program_id_index as usize) != Some(&self.program_id) {
+                return Err(ClientError::ForeignProgram);
+            }
+        }
+
+        // Other launchpad instructions can spend the relayer's funds too when they name it, e.g. as a contributor
+        let [instruction] = tx.message.instructions.as_slice() else {
+            return Err(ClientError::NotALaunch);
+        };
+        if !matches!(LaunchpadInstruction::try_from_slice(&instruction.data), Ok(LaunchpadInstruction::LaunchAsset { .. })) {
+            return Err(ClientError::NotALaunch);
+        }
+
+        // The relayer is key 0, it may only pay for the launch and never stand in for another account
+        if instruction.accounts.first() != Some(&0) || instruction.accounts[1..].contains(&0) {
+            return Err(ClientError::RelayerNotPayer);
+        }
+
+        let recent_blockhash = tx.message.recent_blockhash;
+        tx.try_partial_sign(&[&self.payer], recent_blockhash)?;
+        tx.verify().map_err(ClientError::Verification)?;
+
+        Ok(self.transport.send_and_confirm(&tx).await?.signature)
+    }
+
+    /// Launched asset record of `mint`
+    pub async fn fetch_asset(&self, mint: &Pubkey) -> Result<Asset, ClientError> {
+        let launched_asset = get_launched_asset_pda(&self.program_id, mint).0;
+        let data = self.get_account_data(&launched_asset).await?;
+
+        decode_asset(&data)
+            .copied()
+            .map_err(|error| ClientError::InvalidAccount(launched_asset, error))
+    }
+
+    /// Base state of the mint account at `mint`, under either token program
+    pub async fn fetch_mint(&self, mint: &Pubkey) -> Result<MintState, ClientError> {
+        let account = self.transport.get_account(mint).await?.ok_or(ClientError::AccountNotFound(*mint))?;
+
+        MintState::unpack(account.owner, &account.data)
+            .ok_or(ClientError::InvalidAccount(*mint, ProgramError::InvalidAccountData))
+    }
+
+    /// Simulates `instructions` paid by the payer, without signatures so a launch can be checked before its keypairs sign
+    pub async fn simulate(&self, instructions: &[Instruction]) -> Result<Simulation, ClientError> {
+        let recent_blockhash = self.transport.get_latest_blockhash().await?;
+        let message = Message::new_with_blockhash(instructions, Some(&self.payer.pubkey()), &recent_blockhash);
+
+        self.transport.simulate(&Transaction::new_unsigned(message)).await
+    }
+
+    /// Events the launchpad logged in a confirmed transaction
+    pub async fn transaction_events(&self, signature: &Signature) -> Result<Vec<LaunchpadEvent>, ClientError> {
+        let logs = self.transport.get_transaction_logs(signature).await?;
+        Ok(parse_events(&self.program_id, &logs))
+    }
+
+    async fn get_account_data(&self, address: &Pubkey) -> Result<Vec<u8>, ClientError> {
+        self.transport
+            .get_account(address)
+            .await?
+            .map(|account| account.data)
+            .ok_or(ClientError::AccountNotFound(*address))
+    }
+}
+
+// getProgramAccounts has no counterpart in the bank, so listing needs RPC
+impl<S: Signer> LaunchpadClient<RpcClient, S> {
+    /// Launched asset records matching `filter` with their addresses, fetched in one getProgramAccounts call in no particular order
+    pub async fn list_assets(&self, filter: &AssetFilter) -> Result<Vec<(Pubkey, Asset)>, ClientError> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filter.to_rpc_filters()),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        let accounts = self.transport.get_program_accounts_with_config(&self.program_id, config).await?;
+
+        let mut assets = Vec::with_capacity(accounts.len());
+        for (address, account) in accounts {
+            let asset = decode_asset(&account.data).map_err(|error| ClientError::InvalidAccount(address, error))?;
+            if filter.matches_launch_time(asset) {
+                assets.push((address, *asset));
+            }
+        }
+        Ok(assets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshSerialize;
+    use token_launch::state::Distributor;
+
+    #[test]
+    fn test_asset_filter_matches_record_offsets() {
+        let creator = Pubkey::new_unique();
+        let filter = AssetFilter { creator: Some(creator), asset_type: Some(AssetType::StandardNft), ..AssetFilter::default() };
+        let filters = filter.to_rpc_filters();
+        assert_eq!(filters.len(), 4);
+
+        let mut data = vec![0u8; Asset::LEN];
+        Asset::init(&mut data).unwrap();
+        data[Asset::ASSET_TYPE_OFFSET] = 2;
+        data[Asset::CREATOR_OFFSET..Asset::CREATOR_OFFSET + 32].copy_from_slice(creator.as_ref());
+        for filter in &filters {
+            match filter {
+                RpcFilterType::DataSize(size) => assert_eq!(*size, Asset::LEN as u64),
+                RpcFilterType::Memcmp(memcmp) => assert!(memcmp.bytes_match(&data)),
+                _ => panic!("Unexpected filter"),
+            }
+        }
+
+        assert_eq!(AssetFilter::default().to_rpc_filters().len(), 2);
+    }
+
+    #[test]
+    fn test_asset_filter_skips_distributors_of_the_same_size() {
+        let num_leaves = (1..).find(|n| Distributor::space(*n) == Asset::LEN).unwrap();
+        let distributor = Distributor {
+            creator: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            token_program: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            merkle_root: [7; 32],
+            total_amount: 1_000,
+            total_claimed: 0,
+            num_leaves,
+            num_claimed: 0,
+            expiry: 0,
+            clawed_back: false,
+            bump: 255,
+            claimed_bitmap: vec![0; Distributor::bitmap_len(num_leaves)],
+        };
+        let data = distributor.try_to_vec().unwrap();
+        assert_eq!(data.len(), Asset::LEN);
+
+        let matched = AssetFilter::default().to_rpc_filters().iter().all(|filter| match filter {
+            RpcFilterType::DataSize(size) => *size == data.len() as u64,
+            RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(&data),
+            _ => panic!("Unexpected filter"),
+        });
+        assert!(!matched);
+    }
+
+    #[test]
+    fn test_asset_filter_launch_time_range() {
+        let mut data = vec![0u8; Asset::LEN];
+        let asset = Asset::init(&mut data).unwrap();
+        asset.launch_timestamp.set(1_700_000_000);
+
+        assert!(AssetFilter::default().matches_launch_time(asset));
+        let range = AssetFilter { launched_after: Some(1_700_000_000), launched_before: Some(1_700_000_000), ..AssetFilter::default() };
+        assert!(range.matches_launch_time(asset));
+        assert!(!AssetFilter { launched_after: Some(1_700_000_001), ..AssetFilter::default() }.matches_launch_time(asset));
+        assert!(!AssetFilter { launched_before: Some(1_699_999_999), ..AssetFilter::default() }.matches_launch_time(asset));
+    }
+}
@@ -0,0 +1,69 @@
+use solana_client::client_error::ClientError as RpcError;
+use solana_sdk::{program_error::ProgramError, pubkey::Pubkey, signer::SignerError, transaction::TransactionError};
+use thiserror::Error;
+
+// Errors returned by `LaunchpadClient`
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("Invalid launch config: {0}")]
+    InvalidConfig(ProgramError),
+    #[error("Launch config names creator {config}, but {signer} signs as creator")]
+    CreatorMismatch { config: Pubkey, signer: Pubkey },
+    #[error("A deterministic mint is derived by the program and can't be launched with a mint keypair")]
+    DeterministicMintKeypair,
+    #[error("Account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("Account {0} could not be decoded: {1}")]
+    InvalidAccount(Pubkey, ProgramError),
+    #[error("Transaction fee payer is not the relayer {0}")]
+    NotRelayer(Pubkey),
+    #[error("Transaction calls a program other than the launchpad")]
+    ForeignProgram,
+    #[error("Transaction must hold exactly one launch instruction")]
+    NotALaunch,
+    #[error("Relayer may only appear as the launch's payer")]
+    RelayerNotPayer,
+    #[error("Transaction signatures do not verify: {0}")]
+    Verification(TransactionError),
+    #[error("Transaction has no log messages")]
+    MissingLogs,
+    #[error("Invalid transaction encoding: {0}")]
+    Encoding(String),
+    #[error("Signing failed: {0}")]
+    Signer(#[from] SignerError),
+    // Boxed, the transport errors are much larger than the others
+    #[error("RPC request failed: {0}")]
+    Rpc(Box<RpcError>),
+    #[cfg(feature = "banks-client")]
+    #[error("Bank request failed: {0}")]
+    Banks(Box<solana_banks_client::BanksClientError>),
+}
+
+impl ClientError {
+    /// Error of a transaction that was processed and failed, `None` for failures before it ran
+    pub fn transaction_error(&self) -> Option<TransactionError> {
+        match self {
+            ClientError::Rpc(error) => error.get_transaction_error(),
+            #[cfg(feature = "banks-client")]
+            ClientError::Banks(error) => match error.as_ref() {
+                solana_banks_client::BanksClientError::TransactionError(error)
+                | solana_banks_client::BanksClientError::SimulationError { err: error, .. } => Some(error.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl From<RpcError> for ClientError {
+    fn from(error: RpcError) -> Self {
+        ClientError::Rpc(Box::new(error))
+    }
+}
+
+#[cfg(feature = "banks-client")]
+impl From<solana_banks_client::BanksClientError> for ClientError {
+    fn from(error: solana_banks_client::BanksClientError) -> Self {
+        ClientError::Banks(Box::new(error))
+    }
+}
@@ -0,0 +1,72 @@
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::{Keypair, Signature}, signer::Signer};
+use token_launch::{constants::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID}, event::LaunchpadEvent, AssetType};
+
+/// How a launch is signed and paid for, by default the client's payer is also the creator
+#[derive(Default)]
+pub struct LaunchOptions<'a> {
+    /// Signer the launch is attributed to, it must match the config's creator
+    pub creator: Option<&'a dyn Signer>,
+    /// Relayer paying the fees, the transaction is then only partially signed for it to co-sign
+    pub relayer: Option<Pubkey>,
+    /// Mint keypair to launch with instead of a fresh one, so a retried launch can't create a second mint
+    pub mint: Option<&'a Keypair>,
+}
+
+/// A launch instruction with the keypairs that have to sign it
+pub struct PreparedLaunch {
+    pub instruction: Instruction,
+    pub asset_type: AssetType,
+    pub mint: Pubkey,
+    /// Fresh mint keypair, `None` for a deterministic mint the program signs for
+    pub mint_keypair: Option<Keypair>,
+    pub token_account: Keypair,
+    pub launched_asset: Pubkey,
+    pub launch_index: u64,
+}
+
+impl PreparedLaunch {
+    /// Keypairs created for the launch that the instruction needs, the creator and fee payer sign besides them
+    pub fn signers(&self) -> Vec<&dyn Signer> {
+        let keypairs = std::iter::once(&self.token_account).chain(&self.mint_keypair);
+        keypairs
+            .filter(|keypair| self.instruction.accounts.iter().any(|meta| meta.is_signer && meta.pubkey == keypair.pubkey()))
+            .map(|keypair| keypair as &dyn Signer)
+            .collect()
+    }
+}
+
+/// A sent transaction's signature, or a partially signed transaction waiting for its relayer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Submission {
+    Sent(Signature),
+    PartiallySigned(String),
+}
+
+#[derive(Debug)]
+pub struct LaunchResult {
+    pub asset_type: AssetType,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub launched_asset: Pubkey,
+    pub launch_index: u64,
+    pub submission: Submission,
+    /// Events the launch logged, empty until a relayer sends it or when the transaction can't be fetched back
+    pub events: Vec<LaunchpadEvent>,
+}
+
+impl LaunchResult {
+    pub fn signature(&self) -> Option<&Signature> {
+        match &self.submission {
+            Submission::Sent(signature) => Some(signature),
+            Submission::PartiallySigned(_) => None,
+        }
+    }
+}
+
+/// Token program each asset type is launched with, NFTs go to Token-2022
+pub fn token_program_for(asset_type: &AssetType) -> Pubkey {
+    match asset_type {
+        AssetType::SplTokenLegacy => TOKEN_PROGRAM_ID,
+        AssetType::SplToken2022 | AssetType::StandardNft => TOKEN_2022_PROGRAM_ID,
+    }
+}
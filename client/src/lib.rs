@@ -0,0 +1,26 @@
+//! Async client for the launchpad program: launches, launched asset lookups and simulation over RPC or an in-process bank
+//!
+//! ```no_run
+//! # async fn run(rpc_client: solana_client::nonblocking::rpc_client::RpcClient, payer: solana_sdk::signature::Keypair, config: token_launch::LaunchConfig) -> Result<(), token_launch_client::ClientError> {
+//! use token_launch_client::{LaunchOptions, LaunchpadClient};
+//!
+//! let client = LaunchpadClient::new(rpc_client, payer, token_launch::id());
+//! let result = client.launch(config, LaunchOptions::default()).await?;
+//! let asset = client.fetch_asset(&result.mint).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod client;
+pub mod error;
+pub mod launch;
+pub mod mint;
+pub mod transaction;
+pub mod transport;
+
+pub use client::{AssetFilter, LaunchpadClient};
+pub use error::ClientError;
+pub use launch::{LaunchOptions, LaunchResult, PreparedLaunch, Submission};
+pub use mint::{Discrepancy, MintState};
+pub use transaction::{decode_transaction, encode_transaction};
+pub use transport::{Confirmation, Simulation, Transport};
@@ -0,0 +1,197 @@
+use std::fmt;
+
+use solana_sdk::pubkey::Pubkey;
+use token_launch::{util::get_mint_authority_pda, Asset, AssetType};
+
+use crate::launch::token_program_for;
+
+/// Base mint fields, laid out the same by both token programs, Token-2022 extensions follow them
+#[derive(Debug, Clone, PartialEq)]
+pub struct MintState {
+    /// Owner of the mint account
+    pub token_program: Pubkey,
+    pub mint_authority: Option<Pubkey>,
+    pub supply: u64,
+    pub decimals: u8,
+    pub freeze_authority: Option<Pubkey>,
+}
+
+impl MintState {
+    pub const LEN: usize = 82;
+
+    /// Decodes the mint in `data`, `None` when it isn't an initialized mint
+    pub fn unpack(token_program: Pubkey, data: &[u8]) -> Option<MintState> {
+        let data = data.get(..Self::LEN)?;
+        if data[45] != 1 {
+            return None;
+        }
+
+        Some(MintState {
+            token_program,
+            mint_authority: unpack_option_key(&data[0..36])?,
+            supply: u64::from_le_bytes(data[36..44].try_into().ok()?),
+            decimals: data[44],
+            freeze_authority: unpack_option_key(&data[46..82])?,
+        })
+    }
+
+    /// Ways the mint differs from what launching `asset` left it as
+    pub fn discrepancies(&self, program_id: &Pubkey, mint: &Pubkey, asset: &Asset) -> Vec<Discrepancy> {
+        let mut discrepancies = Vec::new();
+
+        if asset.mint != *mint {
+            discrepancies.push(Discrepancy::RecordMint { record: asset.mint });
+        }
+
+        if let Ok(asset_type) = asset.asset_type() {
+            let token_program = token_program_for(&asset_type);
+            if self.token_program != token_program {
+                discrepancies.push(Discrepancy::TokenProgram { expected: token_program, actual: self.token_program });
+            }
+
+            // NFTs are capped at one by revoking the authority, fungible mints hand it to the program
+            let mint_authority = match asset_type {
+                AssetType::StandardNft => None,
+                _ => Some(get_mint_authority_pda(program_id, mint).0),
+            };
+            if self.mint_authority != mint_authority {
+                discrepancies.push(Discrepancy::MintAuthority { expected: mint_authority, actual: self.mint_authority });
+            }
+        }
+
+        if self.freeze_authority != Some(asset.creator) {
+            discrepancies.push(Discrepancy::FreezeAuthority { expected: asset.creator, actual: self.freeze_authority });
+        }
+
+        if self.supply != asset.circulating_supply.get() {
+            discrepancies.push(Discrepancy::Supply { record: asset.circulating_supply.get(), mint: self.supply });
+        }
+
+        if self.supply > asset.max_supply.get() {
+            discrepancies.push(Discrepancy::OverCap { supply: self.supply, max_supply: asset.max_supply.get() });
+        }
+
+        discrepancies
+    }
+}
+
+// COption<Pubkey>, a u32 tag then the key
+fn unpack_option_key(data: &[u8]) -> Option<Option<Pubkey>> {
+    match data[0..4] {
+        [0, 0, 0, 0] => Some(None),
+        [1, 0, 0, 0] => Some(Some(Pubkey::try_from(&data[4..36]).ok()?)),
+        _ => None,
+    }
+}
+
+/// A launched asset record disagreeing with its mint account
+#[derive(Debug, Clone, PartialEq)]
+pub enum Discrepancy {
+    RecordMint { record: Pubkey },
+    TokenProgram { expected: Pubkey, actual: Pubkey },
+    MintAuthority { expected: Option<Pubkey>, actual: Option<Pubkey> },
+    FreezeAuthority { expected: Pubkey, actual: Option<Pubkey> },
+    Supply { record: u64, mint: u64 },
+    OverCap { supply: u64, max_supply: u64 },
+}
+
+impl fmt::Display for Discrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Discrepancy::RecordMint { record } => write!(f, "Record belongs to mint {}", record),
+            Discrepancy::TokenProgram { expected, actual } => {
+                write!(f, "Mint is owned by {}, the asset type launches with {}", actual, expected)
+            }
+            Discrepancy::MintAuthority { expected, actual } => {
+                write!(f, "Mint authority is {}, expected {}", display_key(actual), display_key(expected))
+            }
+            Discrepancy::FreezeAuthority { expected, actual } => {
+                write!(f, "Freeze authority is {}, expected the creator {}", display_key(actual), expected)
+            }
+            Discrepancy::Supply { record, mint } => write!(
+                f,
+                "Record circulates {} but the mint supply is {}, tokens were burned outside BurnTracked",
+                record, mint
+            ),
+            Discrepancy::OverCap { supply, max_supply } => {
+                write!(f, "Mint supply {} is over the supply cap {}", supply, max_supply)
+            }
+        }
+    }
+}
+
+fn display_key(key: &Option<Pubkey>) -> String {
+    key.map_or("none".to_string(), |key| key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use token_launch::{constants::TOKEN_PROGRAM_ID, pod::PodU64};
+
+    fn mint_data(mint_authority: Option<Pubkey>, supply: u64, freeze_authority: Option<Pubkey>) -> Vec<u8> {
+        let mut data = vec![0u8; MintState::LEN];
+        if let Some(authority) = mint_authority {
+            data[0] = 1;
+            data[4..36].copy_from_slice(authority.as_ref());
+        }
+        data[36..44].copy_from_slice(&supply.to_le_bytes());
+        data[44] = 6;
+        data[45] = 1;
+        if let Some(authority) = freeze_authority {
+            data[46] = 1;
+            data[50..82].copy_from_slice(authority.as_ref());
+        }
+        data
+    }
+
+    #[test]
+    fn test_unpack_mint() {
+        let authority = Pubkey::new_unique();
+        let state = MintState::unpack(TOKEN_PROGRAM_ID, &mint_data(Some(authority), 1_000, None)).unwrap();
+        assert_eq!(state.mint_authority, Some(authority));
+        assert_eq!(state.supply, 1_000);
+        assert_eq!(state.decimals, 6);
+        assert_eq!(state.freeze_authority, None);
+
+        // Token-2022 extensions after the base layout are ignored
+        let mut extended = mint_data(None, 1, Some(authority));
+        extended.extend_from_slice(&[0u8; 100]);
+        assert!(MintState::unpack(TOKEN_PROGRAM_ID, &extended).is_some());
+
+        let mut uninitialized = mint_data(None, 0, None);
+        uninitialized[45] = 0;
+        assert!(MintState::unpack(TOKEN_PROGRAM_ID, &uninitialized).is_none());
+        assert!(MintState::unpack(TOKEN_PROGRAM_ID, &[0u8; 40]).is_none());
+    }
+
+    #[test]
+    fn test_discrepancies() {
+        let program_id = token_launch::id();
+        let mint = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let mint_authority = get_mint_authority_pda(&program_id, &mint).0;
+
+        let mut data = vec![0u8; Asset::LEN];
+        let asset = Asset::init(&mut data).unwrap();
+        asset.asset_type = AssetType::SplTokenLegacy as u8;
+        asset.creator = creator;
+        asset.mint = mint;
+        asset.circulating_supply = PodU64::from(1_000);
+        asset.max_supply = PodU64::from(5_000);
+
+        let state = MintState::unpack(TOKEN_PROGRAM_ID, &mint_data(Some(mint_authority), 1_000, Some(creator))).unwrap();
+        assert!(state.discrepancies(&program_id, &mint, asset).is_empty());
+
+        let state = MintState::unpack(TOKEN_PROGRAM_ID, &mint_data(None, 6_000, None)).unwrap();
+        assert_eq!(
+            state.discrepancies(&program_id, &mint, asset),
+            vec![
+                Discrepancy::MintAuthority { expected: Some(mint_authority), actual: None },
+                Discrepancy::FreezeAuthority { expected: creator, actual: None },
+                Discrepancy::Supply { record: 1_000, mint: 6_000 },
+                Discrepancy::OverCap { supply: 6_000, max_supply: 5_000 },
+            ]
+        );
+    }
+}
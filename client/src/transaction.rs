@@ -0,0 +1,35 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_sdk::transaction::Transaction;
+
+use crate::error::ClientError;
+
+/// Encodes a transaction as base64 wire bytes, the format relayers co-sign
+pub fn encode_transaction(tx: &Transaction) -> Result<String, ClientError> {
+    let bytes = bincode::serialize(tx).map_err(|error| ClientError::Encoding(error.to_string()))?;
+    Ok(STANDARD.encode(bytes))
+}
+
+pub fn decode_transaction(encoded: &str) -> Result<Transaction, ClientError> {
+    let bytes = STANDARD.decode(encoded.trim()).map_err(|error| ClientError::Encoding(error.to_string()))?;
+    bincode::deserialize(&bytes).map_err(|error| ClientError::Encoding(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction};
+
+    #[test]
+    fn test_transaction_round_trips() {
+        let relayer = Pubkey::new_unique();
+        let sender = Keypair::new();
+        let transfer = system_instruction::transfer(&sender.pubkey(), &Pubkey::new_unique(), 1_000);
+        let mut tx = Transaction::new_with_payer(&[transfer], Some(&relayer));
+        tx.try_partial_sign(&[&sender], Hash::new_unique()).unwrap();
+
+        let decoded = decode_transaction(&format!("{}\n", encode_transaction(&tx).unwrap())).unwrap();
+        assert_eq!(decoded, tx);
+
+        assert!(matches!(decode_transaction("not base64!"), Err(ClientError::Encoding(_))));
+    }
+}
@@ -0,0 +1,133 @@
+use std::future::Future;
+
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcSimulateTransactionConfig, RpcTransactionConfig},
+};
+use solana_sdk::{
+    account::Account, commitment_config::CommitmentConfig, hash::Hash, pubkey::Pubkey, signature::Signature, transaction::{Transaction, TransactionError}
+};
+use solana_transaction_status::{option_serializer::OptionSerializer, UiTransactionEncoding};
+use token_launch::event::{parse_events, LaunchpadEvent};
+
+use crate::error::ClientError;
+
+#[cfg(feature = "banks-client")]
+use solana_banks_client::{BanksClient, BanksClientError};
+
+/// A transaction that landed, with its log messages when the transport returns them right away
+#[derive(Debug, Clone)]
+pub struct Confirmation {
+    pub signature: Signature,
+    pub logs: Option<Vec<String>>,
+}
+
+/// Outcome of a simulated transaction, it failed when `err` is set
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    pub err: Option<TransactionError>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+impl Simulation {
+    /// Events `program_id` logged during the simulation
+    pub fn events(&self, program_id: &Pubkey) -> Vec<LaunchpadEvent> {
+        parse_events(program_id, &self.logs)
+    }
+}
+
+/// What the client needs from a cluster, implemented over RPC and, with the `banks-client` feature, an in-process bank
+pub trait Transport {
+    fn get_latest_blockhash(&self) -> impl Future<Output = Result<Hash, ClientError>> + Send;
+
+    fn send_and_confirm(&self, tx: &Transaction) -> impl Future<Output = Result<Confirmation, ClientError>> + Send;
+
+    /// Runs `tx` without committing it, signatures aren't verified
+    fn simulate(&self, tx: &Transaction) -> impl Future<Output = Result<Simulation, ClientError>> + Send;
+
+    /// The account at `address`, `None` when it doesn't exist
+    fn get_account(&self, address: &Pubkey) -> impl Future<Output = Result<Option<Account>, ClientError>> + Send;
+
+    /// Log messages of a confirmed transaction, for transports whose confirmations don't carry them
+    fn get_transaction_logs(&self, _signature: &Signature) -> impl Future<Output = Result<Vec<String>, ClientError>> + Send {
+        async { Err(ClientError::MissingLogs) }
+    }
+}
+
+impl Transport for RpcClient {
+    async fn get_latest_blockhash(&self) -> Result<Hash, ClientError> {
+        Ok(RpcClient::get_latest_blockhash(self).await?)
+    }
+
+    async fn send_and_confirm(&self, tx: &Transaction) -> Result<Confirmation, ClientError> {
+        let signature = self.send_and_confirm_transaction(tx).await?;
+        Ok(Confirmation { signature, logs: None })
+    }
+
+    async fn simulate(&self, tx: &Transaction) -> Result<Simulation, ClientError> {
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+        let result = self.simulate_transaction_with_config(tx, config).await?.value;
+
+        Ok(Simulation {
+            err: result.err,
+            logs: result.logs.unwrap_or_default(),
+            units_consumed: result.units_consumed,
+        })
+    }
+
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, ClientError> {
+        Ok(self.get_account_with_commitment(address, self.commitment()).await?.value)
+    }
+
+    async fn get_transaction_logs(&self, signature: &Signature) -> Result<Vec<String>, ClientError> {
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Json),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+        let transaction = self.get_transaction_with_config(signature, config).await?;
+
+        match transaction.transaction.meta.map(|meta| meta.log_messages) {
+            Some(OptionSerializer::Some(logs)) => Ok(logs),
+            _ => Err(ClientError::MissingLogs),
+        }
+    }
+}
+
+// Every call goes through a clone, the bank client's methods take `&mut self` but share one connection
+#[cfg(feature = "banks-client")]
+impl Transport for BanksClient {
+    async fn get_latest_blockhash(&self) -> Result<Hash, ClientError> {
+        Ok(BanksClient::get_latest_blockhash(&mut self.clone()).await?)
+    }
+
+    async fn send_and_confirm(&self, tx: &Transaction) -> Result<Confirmation, ClientError> {
+        let result = BanksClient::process_transaction_with_metadata(&mut self.clone(), tx.clone()).await?;
+        result.result.map_err(BanksClientError::from)?;
+
+        Ok(Confirmation {
+            signature: tx.signatures[0],
+            logs: result.metadata.map(|metadata| metadata.log_messages),
+        })
+    }
+
+    async fn simulate(&self, tx: &Transaction) -> Result<Simulation, ClientError> {
+        let result = BanksClient::simulate_transaction(&mut self.clone(), tx.clone()).await?;
+        let details = result.simulation_details;
+
+        Ok(Simulation {
+            err: result.result.and_then(Result::err),
+            logs: details.as_ref().map(|details| details.logs.clone()).unwrap_or_default(),
+            units_consumed: details.map(|details| details.units_consumed),
+        })
+    }
+
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, ClientError> {
+        Ok(BanksClient::get_account(&mut self.clone(), *address).await?)
+    }
+}
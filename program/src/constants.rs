@@ -3,9 +3,29 @@ use solana_program::pubkey;
 
 // Program constants
 pub const LAUNCHED_ASSET_SEED: &[u8] = b"launched_asset";
+pub const PRESALE_SEED: &[u8] = b"presale";
+pub const PRESALE_VAULT_SEED: &[u8] = b"presale_vault";
+pub const CONTRIBUTION_SEED: &[u8] = b"contribution";
+pub const DISTRIBUTOR_SEED: &[u8] = b"distributor";
+pub const DISTRIBUTOR_VAULT_SEED: &[u8] = b"distributor_vault";
+pub const VOUCHER_CONFIG_SEED: &[u8] = b"voucher_config";
+pub const VOUCHER_VAULT_SEED: &[u8] = b"voucher_vault";
+pub const VOUCHER_NONCE_SEED: &[u8] = b"voucher_nonce";
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+pub const GLOBAL_CONFIG_SEED: &[u8] = b"global_config";
+pub const CREATOR_PROFILE_SEED: &[u8] = b"creator_profile";
+pub const LAUNCH_INDEX_SEED: &[u8] = b"launch_index";
+pub const SYMBOL_RECORD_SEED: &[u8] = b"symbol_record";
+pub const DETERMINISTIC_MINT_SEED: &[u8] = b"mint";
 pub const MAX_NAME_LENGTH: usize = 32;
 pub const MAX_SYMBOL_LENGTH: usize = 10;
 pub const MAX_URI_LENGTH: usize = 200;
+pub const MAX_WEBSITE_LENGTH: usize = 100;
+pub const MAX_SOCIALS_LENGTH: usize = 200;
+// Keeps the claim bitmap within the 10KiB limit for accounts created through CPI
+pub const MAX_DISTRIBUTOR_LEAVES: u64 = 65_536;
+// A Token-2022 transfer fee can take at most the whole transfer
+pub const MAX_TRANSFER_FEE_BASIS_POINTS: u16 = 10_000;
 
 pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
 pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
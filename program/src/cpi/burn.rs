@@ -0,0 +1,61 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke
+};
+
+pub struct BurnInstructionData {
+    pub discriminator: u8, // 8
+    pub amount: u64,
+}
+
+impl BurnInstructionData {
+    pub const LEN: usize = 9;
+
+    /// Encode on the stack, the instruction copies it once into the data it owns
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut data = [0u8; Self::LEN];
+        data[0] = self.discriminator;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        data
+    }
+}
+
+/// CPI call to burn tokens from a token account
+pub fn process<'a>(
+    token_program: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    owner: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+
+    // Instruction discriminator for `Burn` is 8
+    let data = BurnInstructionData {
+        discriminator: 8,
+        amount,
+    }
+    .pack();
+
+    let accounts = vec![
+        AccountMeta::new(*token_account.key, false),
+        AccountMeta::new(*mint.key, false),
+        AccountMeta::new_readonly(*owner.key, true),
+    ];
+
+    let ix = Instruction {
+        program_id: *token_program.key,
+        accounts,
+        data: data.to_vec(),
+    };
+
+    invoke(
+        &ix,
+        &[
+            token_account.clone(),
+            mint.clone(),
+            owner.clone(),
+        ],
+    )
+}
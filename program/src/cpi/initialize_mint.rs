@@ -1,8 +1,8 @@
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, instruction::{AccountMeta, Instruction}, msg, program::invoke, rent::Rent, system_instruction};
-use borsh::BorshSerialize;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, instruction::{AccountMeta, Instruction}, program::invoke};
 use solana_program::pubkey::Pubkey;
 
-#[derive(BorshSerialize)]
+use crate::{cpi::initialize_mint_extension, extension::{mint_len, ExtensionType}, state::MintExtension, util::create_pda_account};
+
 pub struct InitializeMintInstructionData {
     pub discriminator: u8,        // 20
     pub decimals: u8,
@@ -11,41 +11,74 @@ pub struct InitializeMintInstructionData {
     pub freeze_authority: Pubkey,
 }
 
-/// CPI call to initializeMint
+impl InitializeMintInstructionData {
+    pub const LEN: usize = 67;
+
+    /// Encode on the stack, the instruction copies it once into the data it owns
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut data = [0u8; Self::LEN];
+        data[0] = self.discriminator;
+        data[1] = self.decimals;
+        data[2..34].copy_from_slice(self.mint_authority.as_ref());
+        data[34] = self.freeze_authority_option;
+        data[35..67].copy_from_slice(self.freeze_authority.as_ref());
+        data
+    }
+}
+
+/// Create a mint carrying `extensions` and CPI call to initializeMint, `payer` funds the mint account
+/// Any authority an extension has goes to the freeze authority
+/// `signer_seeds` are only needed when the mint is a PDA
+#[allow(clippy::too_many_arguments)]
 pub fn process<'a>(
-    mint_authority: &AccountInfo<'a>,
-    freeze_authority: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    mint_authority: &Pubkey,
+    freeze_authority: &Pubkey,
     mint: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
-    rent: &Rent,
     decimals: u8,
+    extensions: &[MintExtension],
+    signer_seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    
-    // Serialize instruction data
-    // Create mint account
-    let mint_space = 82; 
-    let mint_lamports = rent.minimum_balance(mint_space);
+    let extension_types: Vec<ExtensionType> = extensions.iter().map(MintExtension::extension_type).collect();
+    create_account(payer, mint, system_program, token_program, &extension_types, signer_seeds)?;
+    for extension in extensions {
+        initialize_mint_extension::process(mint, token_program, extension, freeze_authority)?;
+    }
+    initialize(mint, token_program, mint_authority, freeze_authority, decimals)
+}
 
-    invoke(
-        &system_instruction::create_account(
-            mint_authority.key,
-            mint.key,
-            mint_lamports,
-            82,
-            token_program.key,
-        ),
-        &[mint_authority.clone(), mint.clone(), system_program.clone()],
-    )?;
+/// Create a mint account sized for `extensions`, a deterministic mint may already hold lamports
+/// Token-2022 extensions must be initialized between this and `initialize`
+pub fn create_account<'a>(
+    payer: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    extensions: &[ExtensionType],
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    create_pda_account(payer, mint, system_program, mint_len(extensions)?, token_program.key, signer_seeds)
+}
 
+/// CPI call to initializeMint
+pub fn initialize<'a>(
+    mint: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    mint_authority: &Pubkey,
+    freeze_authority: &Pubkey,
+    decimals: u8,
+) -> ProgramResult {
+    // Serialize instruction data
     let data = InitializeMintInstructionData {
         discriminator: 20,
-        decimals: decimals,
-        mint_authority: *mint_authority.key,
+        decimals,
+        mint_authority: *mint_authority,
         freeze_authority_option: 1,
-        freeze_authority: *freeze_authority.key,
+        freeze_authority: *freeze_authority,
     }
-    .try_to_vec()?;
+    .pack();
 
     // Prepare instruction
     let ix = Instruction {
@@ -53,7 +86,7 @@ pub fn process<'a>(
         accounts: vec![
             AccountMeta::new(*mint.key, false),
         ],
-        data,
+        data: data.to_vec(),
     };
 
     invoke(
@@ -64,7 +97,7 @@ pub fn process<'a>(
         ],
     )?;
 
-    msg!("Mint account initialized: {}", mint.key);
+    verbose_msg!("Mint account initialized: {}", mint.key);
 
     Ok(())
 }
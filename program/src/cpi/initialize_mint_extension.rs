@@ -0,0 +1,81 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, instruction::{AccountMeta, Instruction}, program::invoke, pubkey::Pubkey};
+
+use crate::state::MintExtension;
+
+pub struct InitializeMintExtensionInstructionData<'e> {
+    pub extension: &'e MintExtension,
+    pub authority: Pubkey,
+}
+
+impl InitializeMintExtensionInstructionData<'_> {
+    // Longest encoding, a transfer fee with both of its authorities
+    pub const MAX_LEN: usize = 2 + 2 * 33 + 2 + 8;
+
+    /// Encode the Token-2022 instruction initializing the extension, `authority` fills every authority it has
+    /// Extensions differ in length, so unlike the other instructions this one is written into a Vec
+    pub fn pack(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::MAX_LEN);
+        match self.extension {
+            MintExtension::TransferFee { basis_points, maximum_fee } => {
+                // TransferFeeExtension, InitializeTransferFeeConfig
+                data.extend_from_slice(&[26, 0]);
+                for _ in 0..2 {
+                    data.push(1);
+                    data.extend_from_slice(self.authority.as_ref());
+                }
+                data.extend_from_slice(&basis_points.to_le_bytes());
+                data.extend_from_slice(&maximum_fee.to_le_bytes());
+            }
+            MintExtension::MintCloseAuthority => {
+                data.extend_from_slice(&[25, 1]);
+                data.extend_from_slice(self.authority.as_ref());
+            }
+            MintExtension::NonTransferable => data.push(32),
+            MintExtension::PermanentDelegate => {
+                data.push(35);
+                data.extend_from_slice(self.authority.as_ref());
+            }
+            MintExtension::InterestBearing { rate } => {
+                // InterestBearingMintExtension, Initialize
+                data.extend_from_slice(&[33, 0]);
+                data.extend_from_slice(self.authority.as_ref());
+                data.extend_from_slice(&rate.to_le_bytes());
+            }
+        }
+        data
+    }
+}
+
+/// CPI call initializing `extension` on a created but uninitialized Token-2022 mint
+pub fn process<'a>(
+    mint: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    extension: &MintExtension,
+    authority: &Pubkey,
+) -> ProgramResult {
+    let data = InitializeMintExtensionInstructionData {
+        extension,
+        authority: *authority,
+    }
+    .pack();
+
+    let ix = Instruction {
+        program_id: *token_program.key,
+        accounts: vec![
+            AccountMeta::new(*mint.key, false),
+        ],
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            mint.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    verbose_msg!("Initialized {:?} on mint {}", extension.extension_type(), mint.key);
+
+    Ok(())
+}
@@ -1,45 +1,61 @@
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, instruction::{AccountMeta, Instruction}, program::invoke, rent::Rent, system_instruction};
-use borsh::BorshSerialize;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, instruction::{AccountMeta, Instruction}, program::invoke};
 use solana_program::pubkey::Pubkey;
 
-#[derive(BorshSerialize)]
+use crate::{extension::{account_len, token_account_extensions, ExtensionType}, util::create_pda_account};
+
 pub struct InitializeTokenAccountInstructionData {
     pub discriminator: u8,  // 18
     pub owner: Pubkey
 }
 
+impl InitializeTokenAccountInstructionData {
+    pub const LEN: usize = 33;
+
+    /// Encode on the stack, the instruction copies it once into the data it owns
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut data = [0u8; Self::LEN];
+        data[0] = self.discriminator;
+        data[1..33].copy_from_slice(self.owner.as_ref());
+        data
+    }
+}
+
 
-/// CPI call to initializeMint
+/// CPI call to initializeAccount3, the account is sized for the extensions the mint requires
+/// An immutable owner is initialized first when the mint's tokens are non-transferable
+/// `signer_seeds` are only needed when the token account is a PDA, which may already hold lamports
 pub fn process<'a>(
     payer: &AccountInfo<'a>,
     token_account: &AccountInfo<'a>,
     mint: &AccountInfo<'a>,
+    owner: &Pubkey,
     system_program: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
-    rent: &Rent,
+    signer_seeds: &[&[&[u8]]],
 ) -> ProgramResult {
 
     // Create token account
-    let account_space = 165;
-    let account_lamports = rent.minimum_balance(account_space);
+    let extensions = token_account_extensions(token_program.key, &mint.data.borrow())?;
+    create_pda_account(payer, token_account, system_program, account_len(&extensions)?, token_program.key, signer_seeds)?;
+
+    // Instruction discriminator for `InitializeImmutableOwner` is 22
+    if extensions.contains(&ExtensionType::ImmutableOwner) {
+        invoke(
+            &Instruction {
+                program_id: *token_program.key,
+                accounts: vec![AccountMeta::new(*token_account.key, false)],
+                data: vec![22],
+            },
+            &[token_account.clone(), token_program.clone()],
+        )?;
+    }
 
-    invoke(
-        &system_instruction::create_account(
-            payer.key,
-            token_account.key,
-            account_lamports,
-            account_space as u64,
-            token_program.key,
-        ),
-        &[payer.clone(), token_account.clone(), system_program.clone()],
-    )?;
-    
     // Instruction discriminator for `InitializeAccount` is 18
     let data = InitializeTokenAccountInstructionData {
         discriminator: 18,
-        owner: *payer.key
+        owner: *owner
     }
-    .try_to_vec()?;
+    .pack();
 
     let accounts = vec![
         AccountMeta::new(*token_account.key, false),
@@ -49,7 +65,7 @@ pub fn process<'a>(
     let ix = Instruction {
         program_id: *token_program.key,
         accounts,
-        data,
+        data: data.to_vec(),
     };
 
     invoke(
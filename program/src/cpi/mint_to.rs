@@ -2,17 +2,28 @@ use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
-    program::invoke
+    program::invoke_signed
 };
-use borsh::BorshSerialize;
 
-#[derive(BorshSerialize)]
 pub struct MintToInstructionData {
     pub discriminator: u8, // 7
     pub amount: u64
 }
 
+impl MintToInstructionData {
+    pub const LEN: usize = 9;
+
+    /// Encode on the stack, the instruction copies it once into the data it owns
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut data = [0u8; Self::LEN];
+        data[0] = self.discriminator;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        data
+    }
+}
+
 /// CPI call to mint tokens to a token account
+/// `signer_seeds` are only needed when the mint authority is a PDA
 pub fn process<'a>(
     token_program: &AccountInfo<'a>,
     mint: &AccountInfo<'a>,
@@ -20,6 +31,7 @@ pub fn process<'a>(
     mint_authority: &AccountInfo<'a>,
     amount: u64,
     remaining_accounts: &[AccountInfo<'a>], // optional multisig
+    signer_seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     
     // Instruction discriminator for `MintTo` is 7
@@ -27,18 +39,18 @@ pub fn process<'a>(
         discriminator: 7,
         amount
     }
-    .try_to_vec()?;
+    .pack();
 
     let accounts = vec![
-        AccountMeta::new(*mint.key, true),
-        AccountMeta::new(*token_account.key, true),
+        AccountMeta::new(*mint.key, false),
+        AccountMeta::new(*token_account.key, false),
         AccountMeta::new_readonly(*mint_authority.key, true),
     ];
 
     let ix = Instruction {
         program_id: *token_program.key,
         accounts,
-        data,
+        data: data.to_vec(),
     };
 
     let mut cpi_accounts = vec![
@@ -48,8 +60,9 @@ pub fn process<'a>(
     ];
     cpi_accounts.extend_from_slice(remaining_accounts);
 
-    invoke(
+    invoke_signed(
         &ix,
         &cpi_accounts,
+        signer_seeds,
     )
 }
@@ -1,9 +1,98 @@
+pub mod burn;
 pub mod initialize_mint;
+pub mod initialize_mint_extension;
 pub mod initialize_token_account;
 pub mod mint_to;
 pub mod set_authority;
+pub mod transfer_checked;
 
+pub use burn::process as process_burn;
 pub use initialize_mint::process as process_initialize_mint;
+pub use initialize_mint_extension::process as process_initialize_mint_extension;
 pub use initialize_token_account::process as process_initialize_token_account;
 pub use mint_to::process as process_mint_to;
 pub use set_authority::process as process_set_authority;
+pub use transfer_checked::process as process_transfer_checked;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MintExtension;
+    use solana_program::pubkey::Pubkey;
+    use spl_token_2022::instruction::{self as token_instruction, AuthorityType};
+
+    #[test]
+    fn test_packed_data_matches_token_program_instructions() {
+        let program_id = spl_token_2022::id();
+        let (mint, account, authority) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+        let data = initialize_mint::InitializeMintInstructionData {
+            discriminator: 20,
+            decimals: 6,
+            mint_authority: authority,
+            freeze_authority_option: 1,
+            freeze_authority: account,
+        };
+        let expected = token_instruction::initialize_mint2(&program_id, &mint, &authority, Some(&account), 6).unwrap();
+        assert_eq!(expected.data, data.pack());
+
+        let data = initialize_token_account::InitializeTokenAccountInstructionData { discriminator: 18, owner: authority };
+        let expected = token_instruction::initialize_account3(&program_id, &account, &mint, &authority).unwrap();
+        assert_eq!(expected.data, data.pack());
+
+        let data = mint_to::MintToInstructionData { discriminator: 7, amount: 1_000_000 };
+        let expected = token_instruction::mint_to(&program_id, &mint, &account, &authority, &[], 1_000_000).unwrap();
+        assert_eq!(expected.data, data.pack());
+
+        let data = burn::BurnInstructionData { discriminator: 8, amount: 42 };
+        let expected = token_instruction::burn(&program_id, &account, &mint, &authority, &[], 42).unwrap();
+        assert_eq!(expected.data, data.pack());
+
+        let data = transfer_checked::TransferCheckedInstructionData { discriminator: 12, amount: 7, decimals: 9 };
+        let expected = token_instruction::transfer_checked(&program_id, &account, &mint, &authority, &authority, &[], 7, 9).unwrap();
+        assert_eq!(expected.data, data.pack());
+
+        let data = set_authority::SetAuthorityInstructionData {
+            discriminator: 6,
+            authority_type: 0,
+            new_authority_option: 1,
+            new_authority: authority,
+        };
+        let expected = token_instruction::set_authority(&program_id, &mint, Some(&authority), AuthorityType::MintTokens, &account, &[]).unwrap();
+        assert_eq!(expected.data, data.pack());
+
+        // The token program stops reading after a `None` tag, the zeroed key that follows is ignored
+        let data = set_authority::SetAuthorityInstructionData {
+            discriminator: 6,
+            authority_type: 0,
+            new_authority_option: 0,
+            new_authority: Pubkey::default(),
+        };
+        let expected = token_instruction::set_authority(&program_id, &mint, None, AuthorityType::MintTokens, &account, &[]).unwrap();
+        assert!(data.pack().starts_with(&expected.data));
+    }
+
+    #[test]
+    fn test_packed_extensions_match_token_program_instructions() {
+        let program_id = spl_token_2022::id();
+        let (mint, authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let pack = |extension: &MintExtension| initialize_mint_extension::InitializeMintExtensionInstructionData { extension, authority }.pack();
+
+        let extension = MintExtension::TransferFee { basis_points: 250, maximum_fee: 5_000 };
+        let expected = spl_token_2022::extension::transfer_fee::instruction::initialize_transfer_fee_config(&program_id, &mint, Some(&authority), Some(&authority), 250, 5_000).unwrap();
+        assert_eq!(pack(&extension), expected.data);
+        assert_eq!(pack(&extension).len(), initialize_mint_extension::InitializeMintExtensionInstructionData::MAX_LEN);
+
+        let expected = token_instruction::initialize_mint_close_authority(&program_id, &mint, Some(&authority)).unwrap();
+        assert_eq!(pack(&MintExtension::MintCloseAuthority), expected.data);
+
+        let expected = token_instruction::initialize_non_transferable_mint(&program_id, &mint).unwrap();
+        assert_eq!(pack(&MintExtension::NonTransferable), expected.data);
+
+        let expected = token_instruction::initialize_permanent_delegate(&program_id, &mint, &authority).unwrap();
+        assert_eq!(pack(&MintExtension::PermanentDelegate), expected.data);
+
+        let expected = spl_token_2022::extension::interest_bearing_mint::instruction::initialize(&program_id, &mint, Some(authority), -300).unwrap();
+        assert_eq!(pack(&MintExtension::InterestBearing { rate: -300 }), expected.data);
+    }
+}
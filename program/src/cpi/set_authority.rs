@@ -1,7 +1,5 @@
-use borsh::BorshSerialize;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, instruction::{AccountMeta, Instruction}, program::invoke, pubkey::Pubkey};
 
-#[derive(BorshSerialize)]
 pub struct SetAuthorityInstructionData {
     pub discriminator: u8,        // 6
     pub authority_type: u8, // 0 for MintTokens, 1 for FreezeAccount
@@ -9,11 +7,26 @@ pub struct SetAuthorityInstructionData {
     pub new_authority: Pubkey,
 }
 
-/// CPI call to set_authority
+impl SetAuthorityInstructionData {
+    pub const LEN: usize = 35;
+
+    /// Encode on the stack, the instruction copies it once into the data it owns
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut data = [0u8; Self::LEN];
+        data[0] = self.discriminator;
+        data[1] = self.authority_type;
+        data[2] = self.new_authority_option;
+        data[3..35].copy_from_slice(self.new_authority.as_ref());
+        data
+    }
+}
+
+/// CPI call to set_authority, `None` revokes the mint authority
 pub fn process<'a>(
     mint: &AccountInfo<'a>,
     authority: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
+    new_authority: Option<&Pubkey>,
 ) -> ProgramResult {
     // Discriminator for SetAuthority (usually 6)
 
@@ -21,20 +34,20 @@ pub fn process<'a>(
     let data = SetAuthorityInstructionData {
         discriminator: 6,
         authority_type: 0, // AuthorityType::MintTokens
-        new_authority_option: 0, // None Authority
-        new_authority: Pubkey::default(), // Default Pubkey for None
-    }.try_to_vec()?;
+        new_authority_option: new_authority.is_some() as u8,
+        new_authority: new_authority.copied().unwrap_or_default(), // Default Pubkey for None
+    }.pack();
 
     // Build full account metas
     let accounts = vec![
-        AccountMeta::new(*mint.key, true),
+        AccountMeta::new(*mint.key, false),
         AccountMeta::new_readonly(*authority.key, authority.is_signer),
     ];
 
     let ix = Instruction {
         program_id: *token_program.key,
         accounts,
-        data,
+        data: data.to_vec(),
     };
 
     invoke(
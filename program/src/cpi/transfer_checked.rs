@@ -0,0 +1,72 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed
+};
+
+pub struct TransferCheckedInstructionData {
+    pub discriminator: u8, // 12
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl TransferCheckedInstructionData {
+    pub const LEN: usize = 10;
+
+    /// Encode on the stack, the instruction copies it once into the data it owns
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut data = [0u8; Self::LEN];
+        data[0] = self.discriminator;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        data[9] = self.decimals;
+        data
+    }
+}
+
+/// CPI call to transfer tokens between token accounts
+/// `signer_seeds` are only needed when the authority is a PDA
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a>(
+    token_program: &AccountInfo<'a>,
+    source: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+
+    // Instruction discriminator for `TransferChecked` is 12
+    let data = TransferCheckedInstructionData {
+        discriminator: 12,
+        amount,
+        decimals,
+    }
+    .pack();
+
+    let accounts = vec![
+        AccountMeta::new(*source.key, false),
+        AccountMeta::new_readonly(*mint.key, false),
+        AccountMeta::new(*destination.key, false),
+        AccountMeta::new_readonly(*authority.key, true),
+    ];
+
+    let ix = Instruction {
+        program_id: *token_program.key,
+        accounts,
+        data: data.to_vec(),
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            source.clone(),
+            mint.clone(),
+            destination.clone(),
+            authority.clone(),
+        ],
+        signer_seeds,
+    )
+}
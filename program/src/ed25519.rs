@@ -0,0 +1,107 @@
+use solana_program::{
+    ed25519_program, instruction::Instruction, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::error::LaunchpadError;
+
+// Layout of an Ed25519 program instruction carrying a single signature
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBLIC_KEY_OFFSET: usize = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN;
+const SIGNATURE_OFFSET: usize = PUBLIC_KEY_OFFSET + 32;
+const MESSAGE_OFFSET: usize = SIGNATURE_OFFSET + 64;
+// Instruction index meaning "this same instruction"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Build the Ed25519 program instruction verifying `signature` over `message`
+pub fn new_ed25519_instruction(signer: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Instruction {
+    let mut data = Vec::with_capacity(MESSAGE_OFFSET + message.len());
+    data.extend_from_slice(&[1, 0]);
+    for value in [
+        SIGNATURE_OFFSET as u16,
+        CURRENT_INSTRUCTION,
+        PUBLIC_KEY_OFFSET as u16,
+        CURRENT_INSTRUCTION,
+        MESSAGE_OFFSET as u16,
+        message.len() as u16,
+        CURRENT_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Read the signer and message out of a verified Ed25519 program instruction
+pub fn parse_ed25519_instruction(program_id: &Pubkey, data: &[u8]) -> Result<(Pubkey, Vec<u8>), ProgramError> {
+    if !program_id.eq(&ed25519_program::id()) {
+        return Err(LaunchpadError::VoucherSignatureMissing.into());
+    }
+
+    // Exactly one signature whose key and message live in this instruction
+    if data.len() < MESSAGE_OFFSET || data[0] != 1 {
+        return Err(LaunchpadError::VoucherSignatureMissing.into());
+    }
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = SIGNATURE_OFFSETS_START;
+    let public_key_offset = read_u16(offsets + 4) as usize;
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+
+    let indexes = [read_u16(offsets + 2), read_u16(offsets + 6), read_u16(offsets + 12)];
+    if indexes.iter().any(|index| *index != CURRENT_INSTRUCTION) {
+        return Err(LaunchpadError::VoucherSignatureMissing.into());
+    }
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(LaunchpadError::VoucherSignatureMissing)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(LaunchpadError::VoucherSignatureMissing)?;
+
+    Ok((Pubkey::try_from(public_key).unwrap(), message.to_vec()))
+}
+
+#[cfg(test)]
+mod ed25519_tests {
+    use super::*;
+
+    #[test]
+    fn test_ed25519_instruction_round_trip() {
+        let signer = Pubkey::new_unique();
+        let message = b"wallet may claim".to_vec();
+
+        let instruction = new_ed25519_instruction(&signer, &[7; 64], &message);
+        let (parsed_signer, parsed_message) = parse_ed25519_instruction(&instruction.program_id, &instruction.data).unwrap();
+
+        assert_eq!(parsed_signer, signer);
+        assert_eq!(parsed_message, message);
+    }
+
+    #[test]
+    fn test_ed25519_instruction_from_other_program() {
+        let instruction = new_ed25519_instruction(&Pubkey::new_unique(), &[7; 64], b"message");
+
+        let result = parse_ed25519_instruction(&Pubkey::new_unique(), &instruction.data);
+        assert_eq!(result, Err(LaunchpadError::VoucherSignatureMissing.into()));
+    }
+
+    #[test]
+    fn test_ed25519_instruction_with_external_message() {
+        let mut instruction = new_ed25519_instruction(&Pubkey::new_unique(), &[7; 64], b"message");
+        // Point the message at another instruction in the transaction
+        instruction.data[14..16].copy_from_slice(&0u16.to_le_bytes());
+
+        let result = parse_ed25519_instruction(&instruction.program_id, &instruction.data);
+        assert_eq!(result, Err(LaunchpadError::VoucherSignatureMissing.into()));
+    }
+}
@@ -2,7 +2,7 @@ use borsh::BorshDeserialize;
 use solana_program::program_error::ProgramError;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 use solana_program::entrypoint;
-use crate::processor::launch_asset;
+use crate::processor::{burn_tracked, check_not_paused, claim, claim_presale_tokens, claim_with_voucher, clawback, contribute, create_distributor, create_presale, emit, initialize_global_config, launch_asset, mint_additional, reclaim_voucher_tokens, refund, register_voucher_signer, rotate_admin, set_paused, set_symbol_reserved, update_creator_profile, update_global_config, withdraw_fees, withdraw_presale};
 use crate::state::LaunchpadInstruction;
 
 // Program entrypoint
@@ -18,9 +18,79 @@ pub fn process_instruction(
         |_| ProgramError::InvalidInstructionData
     )?;
 
+    if let Some(class) = instruction.pause_class() {
+        check_not_paused(program_id, accounts, class)?;
+    }
+
     match instruction {
-        LaunchpadInstruction::LaunchAsset { config } => {
-            launch_asset(program_id, accounts, config)
+        LaunchpadInstruction::LaunchAsset { config, bumps } => {
+            launch_asset(program_id, accounts, config, bumps)
+        }
+        LaunchpadInstruction::CreatePresale { soft_cap, tokens_for_sale, deadline } => {
+            create_presale(program_id, accounts, soft_cap, tokens_for_sale, deadline)
+        }
+        LaunchpadInstruction::Contribute { amount } => {
+            contribute(program_id, accounts, amount)
+        }
+        LaunchpadInstruction::ClaimPresaleTokens => {
+            claim_presale_tokens(program_id, accounts)
+        }
+        LaunchpadInstruction::WithdrawPresale => {
+            withdraw_presale(program_id, accounts)
+        }
+        LaunchpadInstruction::Refund => {
+            refund(program_id, accounts)
+        }
+        LaunchpadInstruction::CreateDistributor { merkle_root, total_amount, num_leaves, expiry } => {
+            create_distributor(program_id, accounts, merkle_root, total_amount, num_leaves, expiry)
+        }
+        LaunchpadInstruction::Claim { index, amount, proof } => {
+            claim(program_id, accounts, index, amount, proof)
+        }
+        LaunchpadInstruction::Clawback => {
+            clawback(program_id, accounts)
+        }
+        LaunchpadInstruction::RegisterVoucherSigner { voucher_signer, amount } => {
+            register_voucher_signer(program_id, accounts, voucher_signer, amount)
+        }
+        LaunchpadInstruction::ClaimWithVoucher { voucher } => {
+            claim_with_voucher(program_id, accounts, voucher)
+        }
+        LaunchpadInstruction::Emit => {
+            emit(program_id, accounts)
+        }
+        LaunchpadInstruction::MintAdditional { amount } => {
+            mint_additional(program_id, accounts, amount)
+        }
+        LaunchpadInstruction::BurnTracked { amount } => {
+            burn_tracked(program_id, accounts, amount)
+        }
+        LaunchpadInstruction::InitializeGlobalConfig { admin, guardian, treasury, launch_fees, toggles } => {
+            initialize_global_config(program_id, accounts, admin, guardian, treasury, launch_fees, toggles)
+        }
+        LaunchpadInstruction::UpdateGlobalConfig { launch_fees, treasury, toggles, guardian } => {
+            update_global_config(program_id, accounts, launch_fees, treasury, toggles, guardian)
+        }
+        LaunchpadInstruction::RotateAdmin { new_admin } => {
+            rotate_admin(program_id, accounts, new_admin)
+        }
+        LaunchpadInstruction::WithdrawFees { amount } => {
+            withdraw_fees(program_id, accounts, amount)
+        }
+        LaunchpadInstruction::UpdateCreatorProfile { name, website, socials } => {
+            update_creator_profile(program_id, accounts, name, website, socials)
+        }
+        LaunchpadInstruction::Pause { class } => {
+            set_paused(program_id, accounts, class, true)
+        }
+        LaunchpadInstruction::Unpause { class } => {
+            set_paused(program_id, accounts, class, false)
+        }
+        LaunchpadInstruction::SetSymbolReserved { symbol, reserved } => {
+            set_symbol_reserved(program_id, accounts, symbol, reserved)
+        }
+        LaunchpadInstruction::ReclaimVoucherTokens => {
+            reclaim_voucher_tokens(program_id, accounts)
         }
     }
 }
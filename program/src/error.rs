@@ -0,0 +1,163 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+// Errors returned by the launchpad program
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LaunchpadError {
+    #[error("Token program does not match the asset type")]
+    IncorrectTokenProgram = 1,
+    #[error("Account address does not match the expected PDA")]
+    InvalidPda,
+    #[error("Account is not owned by the expected program")]
+    InvalidAccountOwner,
+    #[error("Presale deadline must be in the future")]
+    InvalidDeadline,
+    #[error("Presale has already ended")]
+    PresaleEnded,
+    #[error("Presale is still running")]
+    PresaleActive,
+    #[error("Presale soft cap was reached")]
+    SoftCapReached,
+    #[error("Presale soft cap was not reached")]
+    SoftCapNotReached,
+    #[error("Contribution has already been claimed or refunded")]
+    AlreadySettled,
+    #[error("Presale funds have already been withdrawn")]
+    AlreadyWithdrawn,
+    #[error("Arithmetic overflow")]
+    MathOverflow,
+    #[error("Mint was not launched through this program")]
+    NotLaunchedAsset,
+    #[error("Distributor has too many leaves")]
+    TooManyLeaves,
+    #[error("Merkle proof is invalid")]
+    InvalidProof,
+    #[error("Airdrop entry has already been claimed")]
+    AlreadyClaimed,
+    #[error("Distributor has expired")]
+    DistributorExpired,
+    #[error("Distributor has not expired yet")]
+    DistributorActive,
+    #[error("Distributor has already been clawed back")]
+    AlreadyClawedBack,
+    #[error("Transaction has no Ed25519 signature for the voucher")]
+    VoucherSignatureMissing,
+    #[error("Voucher was not signed by the registered signer or does not match the claim")]
+    InvalidVoucher,
+    #[error("Voucher has expired")]
+    VoucherExpired,
+    #[error("Voucher nonce has already been used")]
+    VoucherNonceUsed,
+    #[error("Minting would exceed the supply cap")]
+    SupplyCapExceeded,
+    #[error("Launched asset has no emission schedule")]
+    NoEmissionSchedule,
+    #[error("No emission has accrued since the last emitted epoch")]
+    NothingToEmit,
+    #[error("Signer is not the program's upgrade authority")]
+    NotUpgradeAuthority,
+    #[error("Launches of this asset type are disabled")]
+    AssetTypeDisabled,
+    #[error("Global config account was not passed to the instruction")]
+    GlobalConfigMissing,
+    #[error("Instruction class is paused")]
+    Paused,
+    #[error("Launch index account does not match the creator's launch count")]
+    InvalidLaunchIndex,
+    #[error("Symbol is already taken by another launch")]
+    SymbolTaken,
+    #[error("Symbol is reserved")]
+    SymbolReserved,
+    #[error("Account must be writable")]
+    AccountNotWritable,
+    #[error("Account to be created already holds lamports or data")]
+    AccountNotEmpty,
+    #[error("Account is not the system program")]
+    IncorrectSystemProgram,
+    #[error("Account is not the expected sysvar")]
+    IncorrectSysvar,
+    #[error("Signer is not the launched asset's creator")]
+    NotAssetCreator,
+    #[error("Voucher tokens were reclaimed by the creator")]
+    VoucherTokensReclaimed,
+    #[error("Creator signer does not match the launch config's creator")]
+    CreatorMismatch,
+    #[error("Signer is not the launchpad admin")]
+    NotAdmin,
+    #[error("Signer is not the launchpad guardian")]
+    NotGuardian,
+}
+
+impl From<LaunchpadError> for ProgramError {
+    fn from(e: LaunchpadError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+// Maps a custom program error code back to its variant, for clients reporting failed transactions
+#[cfg(feature = "client")]
+impl TryFrom<u32> for LaunchpadError {
+    type Error = ProgramError;
+
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        match code {
+            1 => Ok(LaunchpadError::IncorrectTokenProgram),
+            2 => Ok(LaunchpadError::InvalidPda),
+            3 => Ok(LaunchpadError::InvalidAccountOwner),
+            4 => Ok(LaunchpadError::InvalidDeadline),
+            5 => Ok(LaunchpadError::PresaleEnded),
+            6 => Ok(LaunchpadError::PresaleActive),
+            7 => Ok(LaunchpadError::SoftCapReached),
+            8 => Ok(LaunchpadError::SoftCapNotReached),
+            9 => Ok(LaunchpadError::AlreadySettled),
+            10 => Ok(LaunchpadError::AlreadyWithdrawn),
+            11 => Ok(LaunchpadError::MathOverflow),
+            12 => Ok(LaunchpadError::NotLaunchedAsset),
+            13 => Ok(LaunchpadError::TooManyLeaves),
+            14 => Ok(LaunchpadError::InvalidProof),
+            15 => Ok(LaunchpadError::AlreadyClaimed),
+            16 => Ok(LaunchpadError::DistributorExpired),
+            17 => Ok(LaunchpadError::DistributorActive),
+            18 => Ok(LaunchpadError::AlreadyClawedBack),
+            19 => Ok(LaunchpadError::VoucherSignatureMissing),
+            20 => Ok(LaunchpadError::InvalidVoucher),
+            21 => Ok(LaunchpadError::VoucherExpired),
+            22 => Ok(LaunchpadError::VoucherNonceUsed),
+            23 => Ok(LaunchpadError::SupplyCapExceeded),
+            24 => Ok(LaunchpadError::NoEmissionSchedule),
+            25 => Ok(LaunchpadError::NothingToEmit),
+            26 => Ok(LaunchpadError::NotUpgradeAuthority),
+            27 => Ok(LaunchpadError::AssetTypeDisabled),
+            28 => Ok(LaunchpadError::GlobalConfigMissing),
+            29 => Ok(LaunchpadError::Paused),
+            30 => Ok(LaunchpadError::InvalidLaunchIndex),
+            31 => Ok(LaunchpadError::SymbolTaken),
+            32 => Ok(LaunchpadError::SymbolReserved),
+            33 => Ok(LaunchpadError::AccountNotWritable),
+            34 => Ok(LaunchpadError::AccountNotEmpty),
+            35 => Ok(LaunchpadError::IncorrectSystemProgram),
+            36 => Ok(LaunchpadError::IncorrectSysvar),
+            37 => Ok(LaunchpadError::NotAssetCreator),
+            38 => Ok(LaunchpadError::VoucherTokensReclaimed),
+            39 => Ok(LaunchpadError::CreatorMismatch),
+            40 => Ok(LaunchpadError::NotAdmin),
+            41 => Ok(LaunchpadError::NotGuardian),
+            _ => Err(ProgramError::Custom(code)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        for code in 1..=41 {
+            let error = LaunchpadError::try_from(code).unwrap();
+            assert_eq!(ProgramError::from(error), ProgramError::Custom(code));
+        }
+        assert!(LaunchpadError::try_from(0).is_err());
+        assert!(LaunchpadError::try_from(42).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+use crate::state::{AssetType, FeatureToggles, LaunchFees, PauseClass};
+
+/// Leads every event's log data, telling launchpad events apart from other `Program data:` lines
+pub const EVENT_TAG: &[u8; 8] = b"lp_event";
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Typed record of a state change, logged with `sol_log_data` for indexers
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub enum LaunchpadEvent {
+    AssetLaunched {
+        mint: Pubkey,
+        creator: Pubkey,
+        payer: Pubkey,
+        asset_type: AssetType,
+        launch_index: u64,
+        total_supply: u64,
+        max_supply: u64,
+    },
+    LaunchFeeCollected {
+        mint: Pubkey,
+        payer: Pubkey,
+        amount: u64,
+    },
+    MintAuthorityTransferred {
+        mint: Pubkey,
+        authority: Pubkey,
+    },
+    MintAuthorityRevoked {
+        mint: Pubkey,
+    },
+    SymbolClaimed {
+        symbol: String,
+        mint: Pubkey,
+    },
+    SymbolReservationChanged {
+        symbol: String,
+        reserved: bool,
+    },
+    CreatorProfileUpdated {
+        creator: Pubkey,
+    },
+    GlobalConfigInitialized {
+        admin: Pubkey,
+        guardian: Pubkey,
+        treasury: Pubkey,
+    },
+    GlobalConfigUpdated {
+        launch_fees: Option<LaunchFees>,
+        treasury: Option<Pubkey>,
+        toggles: Option<FeatureToggles>,
+        guardian: Option<Pubkey>,
+    },
+    AdminRotated {
+        previous_admin: Pubkey,
+        new_admin: Pubkey,
+    },
+    FeesWithdrawn {
+        treasury: Pubkey,
+        amount: u64,
+    },
+    PauseChanged {
+        class: PauseClass,
+        paused: bool,
+    },
+    PresaleCreated {
+        presale: Pubkey,
+        mint: Pubkey,
+        creator: Pubkey,
+        soft_cap: u64,
+        tokens_for_sale: u64,
+        deadline: i64,
+    },
+    Contributed {
+        presale: Pubkey,
+        contributor: Pubkey,
+        amount: u64,
+        total_raised: u64,
+    },
+    PresaleTokensClaimed {
+        presale: Pubkey,
+        contributor: Pubkey,
+        amount: u64,
+    },
+    PresaleWithdrawn {
+        presale: Pubkey,
+        lamports: u64,
+    },
+    PresaleTokensReturned {
+        presale: Pubkey,
+        amount: u64,
+    },
+    Refunded {
+        presale: Pubkey,
+        contributor: Pubkey,
+        amount: u64,
+    },
+    DistributorCreated {
+        distributor: Pubkey,
+        mint: Pubkey,
+        total_amount: u64,
+        num_leaves: u64,
+        expiry: i64,
+    },
+    AirdropClaimed {
+        distributor: Pubkey,
+        claimant: Pubkey,
+        index: u64,
+        amount: u64,
+    },
+    DistributorClawedBack {
+        distributor: Pubkey,
+        amount: u64,
+    },
+    VoucherSignerRegistered {
+        voucher_config: Pubkey,
+        mint: Pubkey,
+        voucher_signer: Pubkey,
+        amount: u64,
+    },
+    VoucherClaimed {
+        voucher_config: Pubkey,
+        claimant: Pubkey,
+        nonce: u64,
+        amount: u64,
+    },
+    Emitted {
+        mint: Pubkey,
+        treasury: Pubkey,
+        amount: u64,
+        epoch: u64,
+    },
+    MintedAdditional {
+        mint: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    Burned {
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        circulating_supply: u64,
+    },
+    VoucherTokensReclaimed {
+        voucher_config: Pubkey,
+        amount: u64,
+    },
+}
+
+impl LaunchpadEvent {
+    /// Log the event, it shows up as `Program data: <tag> <event>` in base64
+    pub fn emit(&self) -> ProgramResult {
+        sol_log_data(&[EVENT_TAG, &self.try_to_vec()?]);
+        Ok(())
+    }
+
+    /// Decode the fields of one `sol_log_data` call, `None` unless they hold a launchpad event
+    pub fn decode(fields: &[&[u8]]) -> Option<Self> {
+        match fields {
+            [tag, data] if *tag == EVENT_TAG => Self::try_from_slice(data).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Events logged by `program_id` in a transaction's log messages
+/// Data logged by programs it invokes, or by programs invoking it, is skipped
+pub fn parse_events(program_id: &Pubkey, logs: &[String]) -> Vec<LaunchpadEvent> {
+    let program_id = program_id.to_string();
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_PREFIX) {
+            if invocations.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+
+            let fields: Option<Vec<Vec<u8>>> = data
+                .split(' ')
+                .map(|field| STANDARD.decode(field).ok())
+                .collect();
+            if let Some(fields) = fields {
+                let fields: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+                events.extend(LaunchpadEvent::decode(&fields));
+            }
+            continue;
+        }
+
+        // "Program <id> invoke [depth]", then "Program <id> success" or "Program <id> failed: <error>"
+        let mut words = log.split(' ');
+        if let (Some("Program"), Some(program), Some(status)) = (words.next(), words.next(), words.next()) {
+            match status {
+                "invoke" => invocations.push(program),
+                "success" | "failed:" => {
+                    invocations.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_log(fields: &[&[u8]]) -> String {
+        let encoded: Vec<String> = fields.iter().map(|field| STANDARD.encode(field)).collect();
+        format!("{}{}", PROGRAM_DATA_PREFIX, encoded.join(" "))
+    }
+
+    #[test]
+    fn test_parse_events_only_reads_the_program_own_data() {
+        let program_id = Pubkey::new_unique();
+        let other_program = Pubkey::new_unique();
+        let event = LaunchpadEvent::MintAuthorityRevoked { mint: Pubkey::new_unique() };
+        let data = event.try_to_vec().unwrap();
+
+        let logs = vec![
+            format!("Program {} invoke [1]", other_program),
+            // Same bytes, but logged by the caller
+            data_log(&[EVENT_TAG, &data]),
+            format!("Program {} invoke [2]", program_id),
+            "Program log: Removed Mint Authority".to_string(),
+            format!("Program {} invoke [3]", other_program),
+            data_log(&[EVENT_TAG, &data]),
+            format!("Program {} success", other_program),
+            data_log(&[EVENT_TAG, &data]),
+            // Untagged data is someone else's format
+            data_log(&[&data]),
+            format!("Program {} consumed 1000 of 200000 compute units", program_id),
+            format!("Program {} success", program_id),
+            format!("Program {} success", other_program),
+        ];
+
+        assert_eq!(parse_events(&program_id, &logs), vec![event]);
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert_eq!(LaunchpadEvent::decode(&[EVENT_TAG, &[255]]), None);
+        assert_eq!(LaunchpadEvent::decode(&[b"other_tg", &[0]]), None);
+        assert_eq!(LaunchpadEvent::decode(&[]), None);
+    }
+}
@@ -0,0 +1,317 @@
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::constants::TOKEN_2022_PROGRAM_ID;
+
+/// Size of a mint without extensions, the same under both token programs
+pub const MINT_BASE_LEN: usize = 82;
+/// Size of a token account without extensions, the same under both token programs
+pub const ACCOUNT_BASE_LEN: usize = 165;
+
+// Token-2022 pads a mint to the account length, so both account kinds start their extensions at the same offset
+const ACCOUNT_TYPE_LEN: usize = 1;
+const TLV_HEADER_LEN: usize = 4;
+// An extended account of exactly this length would be mistaken for a multisig, Token-2022 grows it by a type's width
+const MULTISIG_LEN: usize = 355;
+
+/// Token-2022 extension types, numbered as in the token program's TLV entries
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum ExtensionType {
+    TransferFeeConfig = 1,
+    TransferFeeAmount,
+    MintCloseAuthority,
+    ConfidentialTransferMint,
+    ConfidentialTransferAccount,
+    DefaultAccountState,
+    ImmutableOwner,
+    MemoTransfer,
+    NonTransferable,
+    InterestBearingConfig,
+    CpiGuard,
+    PermanentDelegate,
+    NonTransferableAccount,
+    TransferHook,
+    TransferHookAccount,
+    ConfidentialTransferFeeConfig,
+    ConfidentialTransferFeeAmount,
+    MetadataPointer,
+    TokenMetadata,
+    GroupPointer,
+    TokenGroup,
+    GroupMemberPointer,
+    TokenGroupMember,
+}
+
+impl ExtensionType {
+    const ALL: [ExtensionType; 23] = [
+        ExtensionType::TransferFeeConfig,
+        ExtensionType::TransferFeeAmount,
+        ExtensionType::MintCloseAuthority,
+        ExtensionType::ConfidentialTransferMint,
+        ExtensionType::ConfidentialTransferAccount,
+        ExtensionType::DefaultAccountState,
+        ExtensionType::ImmutableOwner,
+        ExtensionType::MemoTransfer,
+        ExtensionType::NonTransferable,
+        ExtensionType::InterestBearingConfig,
+        ExtensionType::CpiGuard,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::NonTransferableAccount,
+        ExtensionType::TransferHook,
+        ExtensionType::TransferHookAccount,
+        ExtensionType::ConfidentialTransferFeeConfig,
+        ExtensionType::ConfidentialTransferFeeAmount,
+        ExtensionType::MetadataPointer,
+        ExtensionType::TokenMetadata,
+        ExtensionType::GroupPointer,
+        ExtensionType::TokenGroup,
+        ExtensionType::GroupMemberPointer,
+        ExtensionType::TokenGroupMember,
+    ];
+
+    /// Extension type stored under `value` in a TLV entry, `None` for types this program doesn't know
+    pub fn from_u16(value: u16) -> Option<Self> {
+        Self::ALL.iter().copied().find(|extension| *extension as u16 == value)
+    }
+
+    /// Length of the extension's value, `None` for variable-length extensions which are sized by reallocation
+    pub fn value_len(&self) -> Option<usize> {
+        let len = match self {
+            ExtensionType::TransferFeeConfig => 108,
+            ExtensionType::TransferFeeAmount => 8,
+            ExtensionType::MintCloseAuthority => 32,
+            ExtensionType::ConfidentialTransferMint => 65,
+            ExtensionType::ConfidentialTransferAccount => 295,
+            ExtensionType::DefaultAccountState => 1,
+            ExtensionType::ImmutableOwner => 0,
+            ExtensionType::MemoTransfer => 1,
+            ExtensionType::NonTransferable => 0,
+            ExtensionType::InterestBearingConfig => 52,
+            ExtensionType::CpiGuard => 1,
+            ExtensionType::PermanentDelegate => 32,
+            ExtensionType::NonTransferableAccount => 0,
+            ExtensionType::TransferHook => 64,
+            ExtensionType::TransferHookAccount => 1,
+            ExtensionType::ConfidentialTransferFeeConfig => 129,
+            ExtensionType::ConfidentialTransferFeeAmount => 64,
+            ExtensionType::MetadataPointer => 64,
+            ExtensionType::TokenMetadata => return None,
+            ExtensionType::GroupPointer => 64,
+            ExtensionType::TokenGroup => 72,
+            ExtensionType::GroupMemberPointer => 64,
+            ExtensionType::TokenGroupMember => 68,
+        };
+        Some(len)
+    }
+
+    /// Account extensions Token-2022 requires on every token account of a mint with `mint_extensions`
+    pub fn required_account_extensions(mint_extensions: &[ExtensionType]) -> Vec<ExtensionType> {
+        mint_extensions
+            .iter()
+            .filter_map(|extension| match extension {
+                ExtensionType::TransferFeeConfig => Some(ExtensionType::TransferFeeAmount),
+                ExtensionType::NonTransferable => Some(ExtensionType::NonTransferableAccount),
+                ExtensionType::TransferHook => Some(ExtensionType::TransferHookAccount),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Size of a mint carrying `extensions`
+pub fn mint_len(extensions: &[ExtensionType]) -> Result<usize, ProgramError> {
+    extended_len(MINT_BASE_LEN, extensions)
+}
+
+/// Size of a token account carrying `extensions`
+pub fn account_len(extensions: &[ExtensionType]) -> Result<usize, ProgramError> {
+    extended_len(ACCOUNT_BASE_LEN, extensions)
+}
+
+// Base state, then once any extension is present: padding up to the account length, the account type byte and a TLV entry per distinct extension
+fn extended_len(base_len: usize, extensions: &[ExtensionType]) -> Result<usize, ProgramError> {
+    if extensions.is_empty() {
+        return Ok(base_len);
+    }
+
+    let mut len = ACCOUNT_BASE_LEN + ACCOUNT_TYPE_LEN;
+    for (i, extension) in extensions.iter().enumerate() {
+        if extensions[..i].contains(extension) {
+            continue;
+        }
+
+        let value_len = extension.value_len().ok_or_else(|| {
+            msg!("{:?} has a variable length", extension);
+            ProgramError::InvalidArgument
+        })?;
+        len += TLV_HEADER_LEN + value_len;
+    }
+
+    if len == MULTISIG_LEN {
+        len += std::mem::size_of::<u16>();
+    }
+
+    Ok(len)
+}
+
+/// Extension types present in a mint's data, skipping types this program doesn't know
+pub fn mint_extensions(mint_data: &[u8]) -> Result<Vec<ExtensionType>, ProgramError> {
+    let mut extensions = Vec::new();
+    if mint_data.len() <= MINT_BASE_LEN {
+        return Ok(extensions);
+    }
+
+    let mut offset = ACCOUNT_BASE_LEN + ACCOUNT_TYPE_LEN;
+    while offset + TLV_HEADER_LEN <= mint_data.len() {
+        let extension_type = u16::from_le_bytes([mint_data[offset], mint_data[offset + 1]]);
+        let value_len = u16::from_le_bytes([mint_data[offset + 2], mint_data[offset + 3]]) as usize;
+
+        // The rest of the data is zeroed space that no extension took
+        if extension_type == 0 {
+            break;
+        }
+
+        if let Some(extension) = ExtensionType::from_u16(extension_type) {
+            extensions.push(extension);
+        }
+        offset += TLV_HEADER_LEN + value_len;
+    }
+
+    if offset > mint_data.len() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(extensions)
+}
+
+/// Extensions a new token account for the mint owned by `token_program` carries
+/// On top of the ones the mint requires, a non-transferable token is only minted into accounts with an immutable owner
+pub fn token_account_extensions(token_program: &Pubkey, mint_data: &[u8]) -> Result<Vec<ExtensionType>, ProgramError> {
+    if !token_program.eq(&TOKEN_2022_PROGRAM_ID) {
+        return Ok(Vec::new());
+    }
+
+    let mint_extensions = mint_extensions(mint_data)?;
+    let mut extensions = ExtensionType::required_account_extensions(&mint_extensions);
+    if mint_extensions.contains(&ExtensionType::NonTransferable) {
+        extensions.push(ExtensionType::ImmutableOwner);
+    }
+    Ok(extensions)
+}
+
+/// Size of a new token account for the mint owned by `token_program`, with the extensions it carries
+pub fn token_account_len(token_program: &Pubkey, mint_data: &[u8]) -> Result<usize, ProgramError> {
+    account_len(&token_account_extensions(token_program, mint_data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spl_token_2022::{
+        extension::{non_transferable::NonTransferable, transfer_fee::TransferFeeConfig, transfer_hook::TransferHook, ExtensionType as SplExtensionType, StateWithExtensionsMut},
+        state::{Account, Mint},
+    };
+
+    fn spl(extensions: &[ExtensionType]) -> Vec<SplExtensionType> {
+        extensions
+            .iter()
+            .map(|extension| SplExtensionType::try_from(*extension as u16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_base_lengths_match_token_2022() {
+        assert_eq!(mint_len(&[]).unwrap(), SplExtensionType::try_calculate_account_len::<Mint>(&[]).unwrap());
+        assert_eq!(account_len(&[]).unwrap(), SplExtensionType::try_calculate_account_len::<Account>(&[]).unwrap());
+    }
+
+    #[test]
+    fn test_every_fixed_extension_matches_token_2022() {
+        for extension in ExtensionType::ALL.iter().filter(|extension| extension.value_len().is_some()) {
+            let extensions = [*extension];
+            assert_eq!(
+                mint_len(&extensions).unwrap(),
+                SplExtensionType::try_calculate_account_len::<Mint>(&spl(&extensions)).unwrap(),
+                "{:?}",
+                extension
+            );
+            assert_eq!(
+                account_len(&extensions).unwrap(),
+                SplExtensionType::try_calculate_account_len::<Account>(&spl(&extensions)).unwrap(),
+                "{:?}",
+                extension
+            );
+        }
+    }
+
+    #[test]
+    fn test_extension_sets_match_token_2022() {
+        let sets: [&[ExtensionType]; 4] = [
+            &[ExtensionType::TransferFeeConfig, ExtensionType::MintCloseAuthority, ExtensionType::MetadataPointer],
+            &[ExtensionType::TransferFeeAmount, ExtensionType::ImmutableOwner, ExtensionType::MemoTransfer, ExtensionType::CpiGuard],
+            &[ExtensionType::PermanentDelegate, ExtensionType::PermanentDelegate],
+            // Lands on the multisig length before adjustment
+            &[ExtensionType::TransferFeeConfig, ExtensionType::MintCloseAuthority, ExtensionType::PermanentDelegate, ExtensionType::MemoTransfer],
+        ];
+        assert_eq!(mint_len(sets[3]).unwrap(), MULTISIG_LEN + 2);
+
+        for extensions in sets {
+            assert_eq!(
+                mint_len(extensions).unwrap(),
+                SplExtensionType::try_calculate_account_len::<Mint>(&spl(extensions)).unwrap(),
+                "{:?}",
+                extensions
+            );
+        }
+    }
+
+    #[test]
+    fn test_variable_length_extension_is_rejected() {
+        assert_eq!(mint_len(&[ExtensionType::TokenMetadata]), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_required_account_extensions_match_token_2022() {
+        let mint_extensions: Vec<ExtensionType> = ExtensionType::ALL.to_vec();
+        assert_eq!(
+            spl(&ExtensionType::required_account_extensions(&mint_extensions)),
+            SplExtensionType::get_required_init_account_extensions(&spl(&mint_extensions))
+        );
+    }
+
+    #[test]
+    fn test_token_account_len_reads_mint_extensions() {
+        let extensions = [ExtensionType::TransferFeeConfig, ExtensionType::TransferHook];
+        let mut mint_data = vec![0; mint_len(&extensions).unwrap()];
+        {
+            let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut mint_data).unwrap();
+            mint.init_extension::<TransferFeeConfig>(true).unwrap();
+            mint.init_extension::<TransferHook>(true).unwrap();
+        }
+
+        assert_eq!(mint_extensions(&mint_data).unwrap(), extensions);
+
+        let expected = SplExtensionType::try_calculate_account_len::<Account>(&[
+            SplExtensionType::TransferFeeAmount,
+            SplExtensionType::TransferHookAccount,
+        ]).unwrap();
+        assert_eq!(token_account_len(&TOKEN_2022_PROGRAM_ID, &mint_data).unwrap(), expected);
+
+        // Non-transferable tokens also need an immutable owner to be minted into the account
+        let mut mint_data = vec![0; mint_len(&[ExtensionType::NonTransferable]).unwrap()];
+        StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut mint_data).unwrap().init_extension::<NonTransferable>(true).unwrap();
+        assert_eq!(
+            token_account_extensions(&TOKEN_2022_PROGRAM_ID, &mint_data).unwrap(),
+            [ExtensionType::NonTransferableAccount, ExtensionType::ImmutableOwner]
+        );
+        let expected = SplExtensionType::try_calculate_account_len::<Account>(&[
+            SplExtensionType::NonTransferableAccount,
+            SplExtensionType::ImmutableOwner,
+        ]).unwrap();
+        assert_eq!(token_account_len(&TOKEN_2022_PROGRAM_ID, &mint_data).unwrap(), expected);
+
+        // Legacy mints never carry extensions
+        assert_eq!(token_account_len(&crate::constants::TOKEN_PROGRAM_ID, &[0; MINT_BASE_LEN]).unwrap(), ACCOUNT_BASE_LEN);
+        assert_eq!(token_account_len(&TOKEN_2022_PROGRAM_ID, &[0; MINT_BASE_LEN]).unwrap(), ACCOUNT_BASE_LEN);
+    }
+}
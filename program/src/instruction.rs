@@ -0,0 +1,574 @@
+//! Instruction builders and account decoders for off-chain callers, account lists follow `LaunchpadInstruction`'s docs
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction}, program_error::ProgramError, pubkey::Pubkey, system_program, sysvar
+};
+
+use crate::{
+    state::{
+        Asset, AssetType, Contribution, CreatorProfile, Distributor, FeatureToggles, GlobalConfig, LaunchConfig, LaunchFees, LaunchIndex,
+        LaunchpadInstruction, PauseClass, Presale, SymbolRecord, Voucher, VoucherConfig,
+    },
+    util::{
+        get_contribution_pda, get_creator_profile_pda, get_distributor_pda, get_distributor_vault_pda, get_global_config_pda,
+        get_launch_bumps, get_launch_index_pda, get_launched_asset_pda, get_mint_authority_pda, get_presale_pda,
+        get_presale_vault_pda, get_program_data_address, get_symbol_record_pda, get_voucher_config_pda, get_voucher_nonce_pda,
+        get_voucher_vault_pda,
+    },
+};
+
+fn new_instruction(program_id: &Pubkey, instruction: &LaunchpadInstruction, accounts: Vec<AccountMeta>) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        // Serializing into a vector can't fail
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Launch `config` as the creator's `launch_index`th launch
+/// The mint signs unless `config.deterministic_mint` makes it the PDA of (creator, launch index)
+pub fn launch_asset(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    token_program: &Pubkey,
+    launch_index: u64,
+    config: LaunchConfig,
+) -> Instruction {
+    let creator = config.creator;
+    // The token account is only created when there is an initial supply, NFTs always have one
+    let mints_supply = config.total_supply > 0 || config.asset_type == AssetType::StandardNft;
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(*mint, !config.deterministic_mint),
+        AccountMeta::new(*token_account, mints_supply),
+        AccountMeta::new(get_launched_asset_pda(program_id, mint).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new(get_global_config_pda(program_id).0, false),
+        AccountMeta::new(get_creator_profile_pda(program_id, &creator).0, false),
+        AccountMeta::new(get_launch_index_pda(program_id, &creator, launch_index).0, false),
+        AccountMeta::new(get_symbol_record_pda(program_id, &config.symbol).0, false),
+        AccountMeta::new_readonly(creator, true),
+    ];
+
+    let bumps = get_launch_bumps(program_id, mint);
+    new_instruction(program_id, &LaunchpadInstruction::LaunchAsset { config, bumps }, accounts)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_presale(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    creator_token_account: &Pubkey,
+    token_program: &Pubkey,
+    soft_cap: u64,
+    tokens_for_sale: u64,
+    deadline: i64,
+) -> Instruction {
+    let presale = get_presale_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new(*creator, true),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(get_launched_asset_pda(program_id, mint).0, false),
+        AccountMeta::new(*creator_token_account, false),
+        AccountMeta::new(presale, false),
+        AccountMeta::new(get_presale_vault_pda(program_id, &presale).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::CreatePresale { soft_cap, tokens_for_sale, deadline }, accounts)
+}
+
+pub fn contribute(program_id: &Pubkey, contributor: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    let presale = get_presale_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new(*contributor, true),
+        AccountMeta::new(presale, false),
+        AccountMeta::new(get_contribution_pda(program_id, &presale, contributor).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::Contribute { amount }, accounts)
+}
+
+pub fn claim_presale_tokens(
+    program_id: &Pubkey,
+    contributor: &Pubkey,
+    mint: &Pubkey,
+    contributor_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let presale = get_presale_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new_readonly(*contributor, true),
+        AccountMeta::new(presale, false),
+        AccountMeta::new(get_contribution_pda(program_id, &presale, contributor).0, false),
+        AccountMeta::new(get_presale_vault_pda(program_id, &presale).0, false),
+        AccountMeta::new(*contributor_token_account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::ClaimPresaleTokens, accounts)
+}
+
+pub fn withdraw_presale(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    creator_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let presale = get_presale_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new(*creator, true),
+        AccountMeta::new(presale, false),
+        AccountMeta::new(get_presale_vault_pda(program_id, &presale).0, false),
+        AccountMeta::new(*creator_token_account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::WithdrawPresale, accounts)
+}
+
+pub fn refund(program_id: &Pubkey, contributor: &Pubkey, mint: &Pubkey) -> Instruction {
+    let presale = get_presale_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new(*contributor, true),
+        AccountMeta::new(presale, false),
+        AccountMeta::new(get_contribution_pda(program_id, &presale, contributor).0, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::Refund, accounts)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_distributor(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    creator_token_account: &Pubkey,
+    token_program: &Pubkey,
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    num_leaves: u64,
+    expiry: i64,
+) -> Instruction {
+    let distributor = get_distributor_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new(*creator, true),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(get_launched_asset_pda(program_id, mint).0, false),
+        AccountMeta::new(*creator_token_account, false),
+        AccountMeta::new(distributor, false),
+        AccountMeta::new(get_distributor_vault_pda(program_id, &distributor).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(
+        program_id,
+        &LaunchpadInstruction::CreateDistributor { merkle_root, total_amount, num_leaves, expiry },
+        accounts,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn claim(
+    program_id: &Pubkey,
+    claimant: &Pubkey,
+    mint: &Pubkey,
+    claimant_token_account: &Pubkey,
+    token_program: &Pubkey,
+    index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let distributor = get_distributor_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new_readonly(*claimant, true),
+        AccountMeta::new(distributor, false),
+        AccountMeta::new(get_distributor_vault_pda(program_id, &distributor).0, false),
+        AccountMeta::new(*claimant_token_account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::Claim { index, amount, proof }, accounts)
+}
+
+pub fn clawback(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    creator_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let distributor = get_distributor_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new_readonly(*creator, true),
+        AccountMeta::new(distributor, false),
+        AccountMeta::new(get_distributor_vault_pda(program_id, &distributor).0, false),
+        AccountMeta::new(*creator_token_account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::Clawback, accounts)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn register_voucher_signer(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    creator_token_account: &Pubkey,
+    token_program: &Pubkey,
+    voucher_signer: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let voucher_config = get_voucher_config_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new(*creator, true),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(get_launched_asset_pda(program_id, mint).0, false),
+        AccountMeta::new(*creator_token_account, false),
+        AccountMeta::new(voucher_config, false),
+        AccountMeta::new(get_voucher_vault_pda(program_id, &voucher_config).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::RegisterVoucherSigner { voucher_signer, amount }, accounts)
+}
+
+/// Claim with `voucher`, it must be preceded by the Ed25519 verification from `ed25519::new_ed25519_instruction`
+pub fn claim_with_voucher(
+    program_id: &Pubkey,
+    claimant: &Pubkey,
+    claimant_token_account: &Pubkey,
+    token_program: &Pubkey,
+    voucher: Voucher,
+) -> Instruction {
+    let voucher_config = get_voucher_config_pda(program_id, &voucher.mint).0;
+    let accounts = vec![
+        AccountMeta::new(*claimant, true),
+        AccountMeta::new_readonly(voucher_config, false),
+        AccountMeta::new(get_voucher_vault_pda(program_id, &voucher_config).0, false),
+        AccountMeta::new(*claimant_token_account, false),
+        AccountMeta::new_readonly(voucher.mint, false),
+        AccountMeta::new(get_voucher_nonce_pda(program_id, &voucher_config, voucher.nonce).0, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::ClaimWithVoucher { voucher }, accounts)
+}
+
+pub fn reclaim_voucher_tokens(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    creator_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let voucher_config = get_voucher_config_pda(program_id, mint).0;
+    let accounts = vec![
+        AccountMeta::new_readonly(*creator, true),
+        AccountMeta::new(voucher_config, false),
+        AccountMeta::new(get_voucher_vault_pda(program_id, &voucher_config).0, false),
+        AccountMeta::new(*creator_token_account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::ReclaimVoucherTokens, accounts)
+}
+
+pub fn emit(program_id: &Pubkey, mint: &Pubkey, treasury: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(get_launched_asset_pda(program_id, mint).0, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new(*treasury, false),
+        AccountMeta::new_readonly(get_mint_authority_pda(program_id, mint).0, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::Emit, accounts)
+}
+
+pub fn mint_additional(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*creator, true),
+        AccountMeta::new(get_launched_asset_pda(program_id, mint).0, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new(*destination, false),
+        AccountMeta::new_readonly(get_mint_authority_pda(program_id, mint).0, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::MintAdditional { amount }, accounts)
+}
+
+pub fn burn_tracked(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new(get_launched_asset_pda(program_id, mint).0, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new(*token_account, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::BurnTracked { amount }, accounts)
+}
+
+pub fn initialize_global_config(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    admin: Pubkey,
+    guardian: Pubkey,
+    treasury: Pubkey,
+    launch_fees: LaunchFees,
+    toggles: FeatureToggles,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*upgrade_authority, true),
+        AccountMeta::new(get_global_config_pda(program_id).0, false),
+        AccountMeta::new_readonly(get_program_data_address(program_id), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    new_instruction(
+        program_id,
+        &LaunchpadInstruction::InitializeGlobalConfig { admin, guardian, treasury, launch_fees, toggles },
+        accounts,
+    )
+}
+
+pub fn update_global_config(
+    program_id: &Pubkey,
+    admin: &Pubkey,
+    launch_fees: Option<LaunchFees>,
+    treasury: Option<Pubkey>,
+    toggles: Option<FeatureToggles>,
+    guardian: Option<Pubkey>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(
+        program_id,
+        &LaunchpadInstruction::UpdateGlobalConfig { launch_fees, treasury, toggles, guardian },
+        accounts,
+    )
+}
+
+pub fn rotate_admin(program_id: &Pubkey, admin: &Pubkey, new_admin: Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::RotateAdmin { new_admin }, accounts)
+}
+
+pub fn withdraw_fees(program_id: &Pubkey, admin: &Pubkey, treasury: &Pubkey, amount: u64) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(get_global_config_pda(program_id).0, false),
+        AccountMeta::new(*treasury, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::WithdrawFees { amount }, accounts)
+}
+
+pub fn update_creator_profile(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    name: String,
+    website: String,
+    socials: String,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*creator, true),
+        AccountMeta::new(get_creator_profile_pda(program_id, creator).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::UpdateCreatorProfile { name, website, socials }, accounts)
+}
+
+/// `Pause` the class, or `Unpause` it when `paused` is false
+pub fn set_paused(program_id: &Pubkey, guardian: &Pubkey, class: PauseClass, paused: bool) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*guardian, true),
+        AccountMeta::new(get_global_config_pda(program_id).0, false),
+    ];
+
+    let instruction = if paused {
+        LaunchpadInstruction::Pause { class }
+    } else {
+        LaunchpadInstruction::Unpause { class }
+    };
+    new_instruction(program_id, &instruction, accounts)
+}
+
+pub fn set_symbol_reserved(program_id: &Pubkey, admin: &Pubkey, symbol: String, reserved: bool) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(get_global_config_pda(program_id).0, false),
+        AccountMeta::new(get_symbol_record_pda(program_id, &symbol).0, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    new_instruction(program_id, &LaunchpadInstruction::SetSymbolReserved { symbol, reserved }, accounts)
+}
+
+// Accounts sized for their longest values keep trailing zeroes, so they're read without requiring the whole buffer
+fn decode<T: BorshDeserialize>(data: &[u8]) -> Result<T, ProgramError> {
+    Ok(T::deserialize(&mut &data[..])?)
+}
+
+pub fn decode_global_config(data: &[u8]) -> Result<GlobalConfig, ProgramError> {
+    decode(data)
+}
+
+/// Borrows the launched asset record, see `Asset::load`
+pub fn decode_asset(data: &[u8]) -> Result<&Asset, ProgramError> {
+    Asset::load(data)
+}
+
+pub fn decode_presale(data: &[u8]) -> Result<Presale, ProgramError> {
+    decode(data)
+}
+
+pub fn decode_contribution(data: &[u8]) -> Result<Contribution, ProgramError> {
+    decode(data)
+}
+
+pub fn decode_distributor(data: &[u8]) -> Result<Distributor, ProgramError> {
+    decode(data)
+}
+
+pub fn decode_voucher_config(data: &[u8]) -> Result<VoucherConfig, ProgramError> {
+    decode(data)
+}
+
+pub fn decode_creator_profile(data: &[u8]) -> Result<CreatorProfile, ProgramError> {
+    decode(data)
+}
+
+pub fn decode_launch_index(data: &[u8]) -> Result<LaunchIndex, ProgramError> {
+    decode(data)
+}
+
+pub fn decode_symbol_record(data: &[u8]) -> Result<SymbolRecord, ProgramError> {
+    decode(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::TOKEN_PROGRAM_ID;
+
+    fn config(creator: Pubkey, deterministic_mint: bool) -> LaunchConfig {
+        LaunchConfig {
+            asset_type: AssetType::SplTokenLegacy,
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: 1_000_000,
+            metadata_uri: "https://example.com/metadata.json".to_string(),
+            creator,
+            is_mutable: false,
+            max_supply: 1_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint,
+            extensions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_launch_asset_round_trips() {
+        let program_id = crate::id();
+        let payer = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let instruction = launch_asset(&program_id, &payer, &mint, &Pubkey::new_unique(), &TOKEN_PROGRAM_ID, 3, config(creator, false));
+        assert_eq!(instruction.accounts.len(), 12);
+        assert!(instruction.accounts[1].is_signer);
+        assert_eq!(instruction.accounts[3].pubkey, get_launched_asset_pda(&program_id, &mint).0);
+        assert_eq!(instruction.accounts[9].pubkey, get_launch_index_pda(&program_id, &creator, 3).0);
+        assert_eq!(instruction.accounts[11], AccountMeta::new_readonly(creator, true));
+
+        let LaunchpadInstruction::LaunchAsset { config: decoded, bumps } = LaunchpadInstruction::try_from_slice(&instruction.data).unwrap() else {
+            panic!("Not a launch");
+        };
+        assert_eq!(decoded.creator, creator);
+        assert_eq!(bumps, get_launch_bumps(&program_id, &mint));
+
+        // The program signs for a deterministic mint
+        let instruction = launch_asset(&program_id, &payer, &mint, &Pubkey::new_unique(), &TOKEN_PROGRAM_ID, 0, config(creator, true));
+        assert!(!instruction.accounts[1].is_signer);
+        assert!(instruction.accounts[2].is_signer);
+
+        // Without an initial supply no token account is created, so its keypair doesn't have to sign
+        let instruction = launch_asset(&program_id, &payer, &mint, &Pubkey::new_unique(), &TOKEN_PROGRAM_ID, 0, LaunchConfig { total_supply: 0, ..config(creator, false) });
+        assert!(!instruction.accounts[2].is_signer);
+    }
+
+    #[test]
+    fn test_decode_tolerates_padding() {
+        let profile = CreatorProfile { creator: Pubkey::new_unique(), launch_count: 2, name: "Jane".to_string(), ..Default::default() };
+        let mut data = profile.try_to_vec().unwrap();
+        data.resize(CreatorProfile::LEN, 0);
+
+        let decoded = decode_creator_profile(&data).unwrap();
+        assert_eq!(decoded.launch_count, 2);
+        assert_eq!(decoded.name, "Jane");
+
+        assert!(decode_global_config(&[0; 8]).is_err());
+    }
+}
@@ -1,9 +1,26 @@
+// `msg!` for progress logs, compiled out without the `verbose-logging` feature. Failures still log their reason
+macro_rules! verbose_msg {
+    ($($arg:tt)*) => {
+        if cfg!(feature = "verbose-logging") {
+            solana_program::msg!($($arg)*);
+        }
+    };
+}
+
 pub mod state;
 pub mod processor;
 pub mod constants;
 pub mod entrypoint;
 pub mod util;
 pub mod cpi;
+pub mod ed25519;
+pub mod error;
+pub mod event;
+pub mod extension;
+#[cfg(feature = "client")]
+pub mod instruction;
+pub mod merkle;
+pub mod pod;
 
 pub use solana_program;
 pub use state::*;
@@ -0,0 +1,60 @@
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+// Domain separators so a leaf can never be passed off as an inner node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hash of a single airdrop entry
+pub fn hash_leaf(index: u64, wallet: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        &index.to_le_bytes(),
+        wallet.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Hash of two sibling nodes, sorted so proofs don't need to carry a direction
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Check a leaf against a merkle root
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling));
+
+    computed == *root
+}
+
+#[cfg(test)]
+mod merkle_tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_proof_two_leaves() {
+        let wallet_a = Pubkey::new_unique();
+        let wallet_b = Pubkey::new_unique();
+        let leaf_a = hash_leaf(0, &wallet_a, 100);
+        let leaf_b = hash_leaf(1, &wallet_b, 200);
+        let root = hash_pair(&leaf_a, &leaf_b);
+
+        assert!(verify_proof(&[leaf_b], &root, leaf_a));
+        assert!(verify_proof(&[leaf_a], &root, leaf_b));
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_wrong_amount() {
+        let wallet_a = Pubkey::new_unique();
+        let wallet_b = Pubkey::new_unique();
+        let leaf_a = hash_leaf(0, &wallet_a, 100);
+        let leaf_b = hash_leaf(1, &wallet_b, 200);
+        let root = hash_pair(&leaf_a, &leaf_b);
+
+        assert!(!verify_proof(&[leaf_b], &root, hash_leaf(0, &wallet_a, 1_000)));
+        assert!(!verify_proof(&[leaf_b], &root, hash_leaf(1, &wallet_a, 100)));
+    }
+}
@@ -0,0 +1,103 @@
+use bytemuck::{Pod, Zeroable};
+
+// Little-endian integers with an alignment of 1, so zero-copy records have no padding and borrow from any account data
+
+/// `u64` stored as little-endian bytes
+#[derive(Pod, Zeroable, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct PodU64([u8; 8]);
+
+impl PodU64 {
+    pub fn get(&self) -> u64 {
+        u64::from_le_bytes(self.0)
+    }
+
+    pub fn set(&mut self, value: u64) {
+        self.0 = value.to_le_bytes();
+    }
+}
+
+impl From<u64> for PodU64 {
+    fn from(value: u64) -> Self {
+        PodU64(value.to_le_bytes())
+    }
+}
+
+impl std::fmt::Debug for PodU64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+/// `i64` stored as little-endian bytes
+#[derive(Pod, Zeroable, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct PodI64([u8; 8]);
+
+impl PodI64 {
+    pub fn get(&self) -> i64 {
+        i64::from_le_bytes(self.0)
+    }
+
+    pub fn set(&mut self, value: i64) {
+        self.0 = value.to_le_bytes();
+    }
+}
+
+impl From<i64> for PodI64 {
+    fn from(value: i64) -> Self {
+        PodI64(value.to_le_bytes())
+    }
+}
+
+impl std::fmt::Debug for PodI64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+/// Copy `value` into a zero-padded buffer, `None` if it doesn't fit
+pub fn pad<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let bytes = value.as_bytes();
+    if bytes.len() > N {
+        return None;
+    }
+
+    let mut padded = [0u8; N];
+    padded[..bytes.len()].copy_from_slice(bytes);
+    Some(padded)
+}
+
+/// The string in a zero-padded buffer, empty if it isn't UTF-8
+pub fn unpad(padded: &[u8]) -> &str {
+    let len = padded.iter().rposition(|byte| *byte != 0).map_or(0, |last| last + 1);
+    std::str::from_utf8(&padded[..len]).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pod_integers_round_trip() {
+        let mut value = PodU64::from(u64::MAX - 1);
+        assert_eq!(value.get(), u64::MAX - 1);
+        value.set(42);
+        assert_eq!(bytemuck::bytes_of(&value), &42u64.to_le_bytes());
+
+        let timestamp = PodI64::from(-1_700_000_000);
+        assert_eq!(timestamp.get(), -1_700_000_000);
+        assert_eq!(std::mem::align_of::<PodU64>(), 1);
+    }
+
+    #[test]
+    fn test_pad_and_unpad() {
+        let padded: [u8; 10] = pad("TEST").unwrap();
+        assert_eq!(&padded, b"TEST\0\0\0\0\0\0");
+        assert_eq!(unpad(&padded), "TEST");
+
+        assert_eq!(unpad(&pad::<4>("FULL").unwrap()), "FULL");
+        assert!(pad::<4>("TOO LONG").is_none());
+        assert_eq!(unpad(&[0u8; 8]), "");
+    }
+}
@@ -0,0 +1,259 @@
+use solana_program::{
+    account_info::{ next_account_info, AccountInfo }, bpf_loader_upgradeable::{self, UpgradeableLoaderState}, entrypoint::ProgramResult, msg, program_error::ProgramError, program_utils::limited_deserialize, pubkey::Pubkey, rent::Rent, sysvar::Sysvar
+};
+use crate::{
+    constants::GLOBAL_CONFIG_SEED, error::LaunchpadError, event::LaunchpadEvent, state::{ FeatureToggles, GlobalConfig, LaunchFees, PauseClass, PauseFlags }, util::{create_pda_account, get_program_data_address}
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Create the global config, restricted to the program's upgrade authority
+pub fn initialize_global_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    admin: Pubkey,
+    guardian: Pubkey,
+    treasury: Pubkey,
+    launch_fees: LaunchFees,
+    toggles: FeatureToggles,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let upgrade_authority = next_account_info(accounts_iter)?;
+    let global_config_account = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    if !upgrade_authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !program_data.key.eq(&get_program_data_address(program_id)) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if !program_data.owner.eq(&bpf_loader_upgradeable::id()) {
+        return Err(LaunchpadError::InvalidAccountOwner.into());
+    }
+
+    let state: UpgradeableLoaderState = limited_deserialize(
+        &program_data.data.borrow(),
+        UpgradeableLoaderState::size_of_programdata_metadata() as u64,
+    )
+    .map_err(|_| ProgramError::InvalidAccountData)?;
+    match state {
+        UpgradeableLoaderState::ProgramData { upgrade_authority_address: Some(authority), .. }
+            if authority.eq(upgrade_authority.key) => {}
+        _ => return Err(LaunchpadError::NotUpgradeAuthority.into()),
+    }
+
+    let (global_config_pda, bump) = Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED], program_id);
+    if !global_config_account.key.eq(&global_config_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    msg!("Creating global config account: {}", global_config_pda);
+
+    create_pda_account(
+        upgrade_authority,
+        global_config_account,
+        system_program,
+        GlobalConfig::LEN,
+        program_id,
+        &[&[GLOBAL_CONFIG_SEED, &[bump]]],
+    )?;
+
+    let global_config = GlobalConfig {
+        admin,
+        guardian,
+        treasury,
+        launch_fees,
+        toggles,
+        paused: PauseFlags::default(),
+        bump,
+    };
+    global_config.serialize(&mut &mut global_config_account.data.borrow_mut()[..])?;
+
+    msg!("Global config initialized with admin {} and guardian {}", admin, guardian);
+    LaunchpadEvent::GlobalConfigInitialized { admin, guardian, treasury }.emit()?;
+
+    Ok(())
+}
+
+/// Change the launch fees, treasury, feature toggles or guardian
+pub fn update_global_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    launch_fees: Option<LaunchFees>,
+    treasury: Option<Pubkey>,
+    toggles: Option<FeatureToggles>,
+    guardian: Option<Pubkey>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let admin = next_account_info(accounts_iter)?;
+    let global_config_account = next_account_info(accounts_iter)?;
+
+    let mut global_config = load_global_config_as_admin(program_id, global_config_account, admin)?;
+
+    if let Some(launch_fees) = &launch_fees {
+        msg!("Launch fees updated: {:?}", launch_fees);
+        global_config.launch_fees = launch_fees.clone();
+    }
+    if let Some(treasury) = treasury {
+        msg!("Treasury updated: {}", treasury);
+        global_config.treasury = treasury;
+    }
+    if let Some(toggles) = &toggles {
+        msg!("Feature toggles updated: {:?}", toggles);
+        global_config.toggles = toggles.clone();
+    }
+    if let Some(guardian) = guardian {
+        msg!("Guardian updated: {}", guardian);
+        global_config.guardian = guardian;
+    }
+
+    global_config.serialize(&mut &mut global_config_account.data.borrow_mut()[..])?;
+
+    LaunchpadEvent::GlobalConfigUpdated { launch_fees, treasury, toggles, guardian }.emit()?;
+
+    Ok(())
+}
+
+/// Hand the admin role to another key
+pub fn rotate_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_admin: Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let admin = next_account_info(accounts_iter)?;
+    let global_config_account = next_account_info(accounts_iter)?;
+
+    let mut global_config = load_global_config_as_admin(program_id, global_config_account, admin)?;
+
+    global_config.admin = new_admin;
+    global_config.serialize(&mut &mut global_config_account.data.borrow_mut()[..])?;
+
+    msg!("Admin rotated from {} to {}", admin.key, new_admin);
+    LaunchpadEvent::AdminRotated { previous_admin: *admin.key, new_admin }.emit()?;
+
+    Ok(())
+}
+
+/// Send collected launch fees to the treasury, the config keeps its rent exempt balance
+pub fn withdraw_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let admin = next_account_info(accounts_iter)?;
+    let global_config_account = next_account_info(accounts_iter)?;
+    let treasury = next_account_info(accounts_iter)?;
+
+    let global_config = load_global_config_as_admin(program_id, global_config_account, admin)?;
+
+    if !treasury.key.eq(&global_config.treasury) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let available = global_config_account
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(global_config_account.data_len()));
+    if amount == 0 || amount > available {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    **global_config_account.try_borrow_mut_lamports()? -= amount;
+    **treasury.try_borrow_mut_lamports()? = treasury
+        .lamports()
+        .checked_add(amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    msg!("Withdrew {} lamports of launch fees to {}", amount, treasury.key);
+    LaunchpadEvent::FeesWithdrawn { treasury: *treasury.key, amount }.emit()?;
+
+    Ok(())
+}
+
+/// Pause or unpause an instruction class, restricted to the guardian
+pub fn set_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    class: PauseClass,
+    paused: bool,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let guardian = next_account_info(accounts_iter)?;
+    let global_config_account = next_account_info(accounts_iter)?;
+
+    if !guardian.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut global_config = load_global_config(program_id, global_config_account)?
+        .ok_or(ProgramError::UninitializedAccount)?;
+
+    if !guardian.key.eq(&global_config.guardian) {
+        return Err(LaunchpadError::NotGuardian.into());
+    }
+
+    global_config.paused.set(class, paused);
+    global_config.serialize(&mut &mut global_config_account.data.borrow_mut()[..])?;
+
+    if paused {
+        msg!("Paused {:?} instructions, guardian {}", class, guardian.key);
+    } else {
+        msg!("Unpaused {:?} instructions, guardian {}", class, guardian.key);
+    }
+    LaunchpadEvent::PauseChanged { class, paused }.emit()?;
+
+    Ok(())
+}
+
+/// Fail unless the global config is among the accounts and `class` isn't paused
+pub(crate) fn check_not_paused(program_id: &Pubkey, accounts: &[AccountInfo], class: PauseClass) -> ProgramResult {
+    let (global_config_pda, _) = Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED], program_id);
+    let global_config_account = accounts
+        .iter()
+        .find(|account| account.key.eq(&global_config_pda))
+        .ok_or(LaunchpadError::GlobalConfigMissing)?;
+
+    if let Some(global_config) = load_global_config(program_id, global_config_account)? {
+        if global_config.paused.is_paused(class) {
+            msg!("{:?} instructions are paused", class);
+            return Err(LaunchpadError::Paused.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper function to load the global config, `None` while it hasn't been initialized
+pub(crate) fn load_global_config(program_id: &Pubkey, global_config_account: &AccountInfo) -> Result<Option<GlobalConfig>, ProgramError> {
+    let (global_config_pda, _) = Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED], program_id);
+    if !global_config_account.key.eq(&global_config_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if !global_config_account.owner.eq(program_id) {
+        return Ok(None);
+    }
+
+    Ok(Some(GlobalConfig::try_from_slice(&global_config_account.data.borrow())?))
+}
+
+/// Helper function to load the global config after checking the admin signed
+pub(crate) fn load_global_config_as_admin(program_id: &Pubkey, global_config_account: &AccountInfo, admin: &AccountInfo) -> Result<GlobalConfig, ProgramError> {
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let global_config = load_global_config(program_id, global_config_account)?
+        .ok_or(ProgramError::UninitializedAccount)?;
+
+    if !admin.key.eq(&global_config.admin) {
+        return Err(LaunchpadError::NotAdmin.into());
+    }
+
+    Ok(global_config)
+}
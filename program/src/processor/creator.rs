@@ -0,0 +1,176 @@
+use solana_program::{
+    account_info::{ next_account_info, AccountInfo }, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+use crate::{
+    constants::{CREATOR_PROFILE_SEED, LAUNCH_INDEX_SEED, MAX_NAME_LENGTH, MAX_SOCIALS_LENGTH, MAX_WEBSITE_LENGTH}, error::LaunchpadError, event::LaunchpadEvent, state::{ CreatorProfile, LaunchIndex }, util::create_pda_account
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Set the display info of the creator's profile
+pub fn update_creator_profile(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: String,
+    website: String,
+    socials: String,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let creator = next_account_info(accounts_iter)?;
+    let creator_profile_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    for (field, value, max_length) in [
+        ("Name", &name, MAX_NAME_LENGTH),
+        ("Website", &website, MAX_WEBSITE_LENGTH),
+        ("Socials", &socials, MAX_SOCIALS_LENGTH),
+    ] {
+        if value.len() > max_length {
+            msg!("{} too long: {} > {}", field, value.len(), max_length);
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    let mut creator_profile = load_or_create_creator_profile(
+        program_id,
+        creator,
+        creator.key,
+        creator_profile_account,
+        system_program,
+    )?;
+
+    creator_profile.name = name;
+    creator_profile.website = website;
+    creator_profile.socials = socials;
+    creator_profile.serialize(&mut &mut creator_profile_account.data.borrow_mut()[..])?;
+
+    msg!("Creator profile updated: {}", creator.key);
+    LaunchpadEvent::CreatorProfileUpdated { creator: *creator.key }.emit()?;
+
+    Ok(())
+}
+
+/// Index a new launch under its creator, called by `launch_asset`, returns the launch's index
+pub(crate) fn record_launch<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    creator: &Pubkey,
+    mint: &Pubkey,
+    creator_profile_account: &AccountInfo<'a>,
+    launch_index_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<u64, ProgramError> {
+    let mut creator_profile = load_or_create_creator_profile(
+        program_id,
+        payer,
+        creator,
+        creator_profile_account,
+        system_program,
+    )?;
+
+    let index = creator_profile.launch_count;
+    let index_bytes = index.to_le_bytes();
+    let (launch_index_pda, bump) = Pubkey::find_program_address(
+        &[LAUNCH_INDEX_SEED, creator.as_ref(), &index_bytes],
+        program_id,
+    );
+    if !launch_index_account.key.eq(&launch_index_pda) {
+        msg!("Expected launch index {} at {}", index, launch_index_pda);
+        return Err(LaunchpadError::InvalidLaunchIndex.into());
+    }
+
+    create_pda_account(
+        payer,
+        launch_index_account,
+        system_program,
+        LaunchIndex::LEN,
+        program_id,
+        &[&[LAUNCH_INDEX_SEED, creator.as_ref(), &index_bytes, &[bump]]],
+    )?;
+
+    let launch_index = LaunchIndex {
+        creator: *creator,
+        index,
+        mint: *mint,
+        bump,
+    };
+    launch_index.serialize(&mut &mut launch_index_account.data.borrow_mut()[..])?;
+
+    creator_profile.launch_count = index.checked_add(1).ok_or(LaunchpadError::MathOverflow)?;
+    creator_profile.serialize(&mut &mut creator_profile_account.data.borrow_mut()[..])?;
+
+    verbose_msg!("Launch {} of creator {}", index, creator);
+
+    Ok(index)
+}
+
+/// Index the creator's next launch will be recorded at
+pub(crate) fn next_launch_index(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    creator_profile_account: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    Ok(load_creator_profile(program_id, creator, creator_profile_account)?
+        .map_or(0, |creator_profile| creator_profile.launch_count))
+}
+
+/// Helper function to load a creator profile, `None` until the creator's first launch or profile update
+fn load_creator_profile(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    creator_profile_account: &AccountInfo,
+) -> Result<Option<CreatorProfile>, ProgramError> {
+    let (creator_profile_pda, _) = Pubkey::find_program_address(
+        &[CREATOR_PROFILE_SEED, creator.as_ref()],
+        program_id,
+    );
+    if !creator_profile_account.key.eq(&creator_profile_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if !creator_profile_account.owner.eq(program_id) {
+        return Ok(None);
+    }
+
+    // The account is sized for the longest display info, so trailing bytes are expected
+    Ok(Some(CreatorProfile::deserialize(&mut &creator_profile_account.data.borrow()[..])?))
+}
+
+/// Helper function to load a creator profile, `payer` funds it on first use
+fn load_or_create_creator_profile<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    creator: &Pubkey,
+    creator_profile_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<CreatorProfile, ProgramError> {
+    if let Some(creator_profile) = load_creator_profile(program_id, creator, creator_profile_account)? {
+        return Ok(creator_profile);
+    }
+
+    let (creator_profile_pda, bump) = Pubkey::find_program_address(
+        &[CREATOR_PROFILE_SEED, creator.as_ref()],
+        program_id,
+    );
+
+    verbose_msg!("Creating creator profile account: {}", creator_profile_pda);
+
+    create_pda_account(
+        payer,
+        creator_profile_account,
+        system_program,
+        CreatorProfile::LEN,
+        program_id,
+        &[&[CREATOR_PROFILE_SEED, creator.as_ref(), &[bump]]],
+    )?;
+
+    Ok(CreatorProfile {
+        creator: *creator,
+        bump,
+        ..CreatorProfile::default()
+    })
+}
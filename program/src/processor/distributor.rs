@@ -0,0 +1,302 @@
+use solana_program::{
+    account_info::{ next_account_info, AccountInfo }, clock::Clock, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar
+};
+use crate::{
+    constants::{DISTRIBUTOR_SEED, DISTRIBUTOR_VAULT_SEED, LAUNCHED_ASSET_SEED, MAX_DISTRIBUTOR_LEAVES, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID}, cpi::{initialize_token_account, transfer_checked}, error::LaunchpadError, event::LaunchpadEvent, merkle::{hash_leaf, verify_proof}, state::{Asset, Distributor}, util::{create_pda_account, get_mint_decimals}
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Escrow an airdrop allocation under a merkle root
+pub fn create_distributor(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    num_leaves: u64,
+    expiry: i64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let creator = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let launched_asset = next_account_info(accounts_iter)?;
+    let creator_token_account = next_account_info(accounts_iter)?;
+    let distributor_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !token_program.key.eq(&TOKEN_PROGRAM_ID) && !token_program.key.eq(&TOKEN_2022_PROGRAM_ID) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    // Only tokens launched through this program can be distributed
+    let (launched_asset_pda, _) = Pubkey::find_program_address(
+        &[LAUNCHED_ASSET_SEED, mint.key.as_ref()],
+        program_id,
+    );
+    if !launched_asset.key.eq(&launched_asset_pda) || !launched_asset.owner.eq(program_id) {
+        return Err(LaunchpadError::NotLaunchedAsset.into());
+    }
+
+    // There is one distributor per mint, so only the asset's creator may take it
+    if !Asset::load(&launched_asset.data.borrow())?.creator.eq(creator.key) {
+        return Err(LaunchpadError::NotAssetCreator.into());
+    }
+
+    if expiry <= Clock::get()?.unix_timestamp {
+        return Err(LaunchpadError::InvalidDeadline.into());
+    }
+
+    if total_amount == 0 || num_leaves == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if num_leaves > MAX_DISTRIBUTOR_LEAVES {
+        return Err(LaunchpadError::TooManyLeaves.into());
+    }
+
+    let (distributor_pda, distributor_bump) = Pubkey::find_program_address(
+        &[DISTRIBUTOR_SEED, mint.key.as_ref()],
+        program_id,
+    );
+    if !distributor_account.key.eq(&distributor_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    let (vault_pda, vault_bump) = Pubkey::find_program_address(
+        &[DISTRIBUTOR_VAULT_SEED, distributor_pda.as_ref()],
+        program_id,
+    );
+    if !vault.key.eq(&vault_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    msg!("Creating distributor account: {}", distributor_pda);
+
+    let space = Distributor::space(num_leaves);
+    create_pda_account(
+        creator,
+        distributor_account,
+        system_program,
+        space,
+        program_id,
+        &[&[DISTRIBUTOR_SEED, mint.key.as_ref(), &[distributor_bump]]],
+    )?;
+
+    // The vault is owned by the distributor PDA so only this program can move the tokens
+    initialize_token_account::process(
+        creator,
+        vault,
+        mint,
+        &distributor_pda,
+        system_program,
+        token_program,
+        &[&[DISTRIBUTOR_VAULT_SEED, distributor_pda.as_ref(), &[vault_bump]]],
+    )?;
+
+    transfer_checked::process(
+        token_program,
+        creator_token_account,
+        mint,
+        vault,
+        creator,
+        total_amount,
+        get_mint_decimals(mint)?,
+        &[],
+    )?;
+
+    let distributor = Distributor {
+        creator: *creator.key,
+        mint: *mint.key,
+        token_program: *token_program.key,
+        vault: vault_pda,
+        merkle_root,
+        total_amount,
+        total_claimed: 0,
+        num_leaves,
+        num_claimed: 0,
+        expiry,
+        clawed_back: false,
+        bump: distributor_bump,
+        claimed_bitmap: vec![0; Distributor::bitmap_len(num_leaves)],
+    };
+    distributor.serialize(&mut &mut distributor_account.data.borrow_mut()[..])?;
+
+    msg!("Distributor escrowed {} tokens for {} wallets", total_amount, num_leaves);
+    LaunchpadEvent::DistributorCreated {
+        distributor: *distributor_account.key,
+        mint: *mint.key,
+        total_amount,
+        num_leaves,
+        expiry,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Pay out an airdrop entry after checking its merkle proof
+pub fn claim(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let claimant = next_account_info(accounts_iter)?;
+    let distributor_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let claimant_token_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !claimant.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut distributor = load_distributor(program_id, distributor_account)?;
+    check_distributor_accounts(&distributor, vault, mint, token_program)?;
+
+    if Clock::get()?.unix_timestamp >= distributor.expiry || distributor.clawed_back {
+        return Err(LaunchpadError::DistributorExpired.into());
+    }
+
+    if index >= distributor.num_leaves {
+        return Err(LaunchpadError::InvalidProof.into());
+    }
+
+    if distributor.is_claimed(index) {
+        return Err(LaunchpadError::AlreadyClaimed.into());
+    }
+
+    let leaf = hash_leaf(index, claimant.key, amount);
+    if !verify_proof(&proof, &distributor.merkle_root, leaf) {
+        return Err(LaunchpadError::InvalidProof.into());
+    }
+
+    distributor.total_claimed = distributor.total_claimed
+        .checked_add(amount)
+        .filter(|claimed| *claimed <= distributor.total_amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    distributor.num_claimed += 1;
+    distributor.set_claimed(index);
+
+    transfer_checked::process(
+        token_program,
+        vault,
+        mint,
+        claimant_token_account,
+        distributor_account,
+        amount,
+        get_mint_decimals(mint)?,
+        &[&[DISTRIBUTOR_SEED, distributor.mint.as_ref(), &[distributor.bump]]],
+    )?;
+
+    distributor.serialize(&mut &mut distributor_account.data.borrow_mut()[..])?;
+
+    msg!("Claimed airdrop entry {} for {} tokens", index, amount);
+    LaunchpadEvent::AirdropClaimed { distributor: *distributor_account.key, claimant: *claimant.key, index, amount }.emit()?;
+
+    Ok(())
+}
+
+/// Return unclaimed tokens to the creator after expiry
+pub fn clawback(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let creator = next_account_info(accounts_iter)?;
+    let distributor_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let creator_token_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut distributor = load_distributor(program_id, distributor_account)?;
+    check_distributor_accounts(&distributor, vault, mint, token_program)?;
+
+    if !creator.key.eq(&distributor.creator) {
+        return Err(LaunchpadError::NotAssetCreator.into());
+    }
+
+    if Clock::get()?.unix_timestamp < distributor.expiry {
+        return Err(LaunchpadError::DistributorActive.into());
+    }
+
+    if distributor.clawed_back {
+        return Err(LaunchpadError::AlreadyClawedBack.into());
+    }
+
+    let remaining = distributor.total_amount - distributor.total_claimed;
+
+    transfer_checked::process(
+        token_program,
+        vault,
+        mint,
+        creator_token_account,
+        distributor_account,
+        remaining,
+        get_mint_decimals(mint)?,
+        &[&[DISTRIBUTOR_SEED, distributor.mint.as_ref(), &[distributor.bump]]],
+    )?;
+
+    distributor.clawed_back = true;
+    distributor.serialize(&mut &mut distributor_account.data.borrow_mut()[..])?;
+
+    msg!("Clawed back {} unclaimed tokens", remaining);
+    LaunchpadEvent::DistributorClawedBack { distributor: *distributor_account.key, amount: remaining }.emit()?;
+
+    Ok(())
+}
+
+/// Helper function to load a distributor owned by this program
+fn load_distributor(program_id: &Pubkey, distributor_account: &AccountInfo) -> Result<Distributor, ProgramError> {
+    if !distributor_account.owner.eq(program_id) {
+        return Err(LaunchpadError::InvalidAccountOwner.into());
+    }
+
+    let distributor = Distributor::try_from_slice(&distributor_account.data.borrow())?;
+
+    let distributor_pda = Pubkey::create_program_address(
+        &[DISTRIBUTOR_SEED, distributor.mint.as_ref(), &[distributor.bump]],
+        program_id,
+    )?;
+    if !distributor_account.key.eq(&distributor_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    Ok(distributor)
+}
+
+/// Helper function to check the token accounts passed alongside a distributor
+fn check_distributor_accounts(
+    distributor: &Distributor,
+    vault: &AccountInfo,
+    mint: &AccountInfo,
+    token_program: &AccountInfo,
+) -> ProgramResult {
+    if !vault.key.eq(&distributor.vault) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if !mint.key.eq(&distributor.mint) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !token_program.key.eq(&distributor.token_program) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    Ok(())
+}
@@ -1,66 +1,269 @@
 use solana_program::{
-    account_info::{ next_account_info, AccountInfo }, entrypoint::ProgramResult,  msg, program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar
+    account_info::{ next_account_info, AccountInfo }, clock::Clock, entrypoint::ProgramResult,  msg, program::invoke, program_error::ProgramError, pubkey::Pubkey, system_instruction, sysvar::{self, Sysvar}
 };
 use crate::{
-    constants::{LAUNCHED_ASSET_SEED, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID}, cpi::{initialize_mint, initialize_token_account, mint_to, set_authority}, state::{ Asset, AssetType, LaunchConfig }, util::validate_launch_config
+    constants::{DETERMINISTIC_MINT_SEED, LAUNCHED_ASSET_SEED, MINT_AUTHORITY_SEED, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID}, cpi::{initialize_mint, initialize_token_account, mint_to, set_authority}, error::LaunchpadError, event::LaunchpadEvent, pod::pad, processor::{admin::load_global_config, creator::{next_launch_index, record_launch}, symbol::{claim_symbol, load_symbol_record}}, state::{ Asset, AssetType, LaunchBumps, LaunchConfig }, util::{create_canonical_program_address, create_pda_account, get_deterministic_mint_pda, validate_launch_config, validate_symbol_availability}
 };
 
-use borsh::BorshSerialize;
+/// Accounts of `LaunchAsset`, checked up front so nothing is created from a bad account list
+struct LaunchAssetAccounts<'a, 'b> {
+    payer: &'b AccountInfo<'a>,
+    mint: &'b AccountInfo<'a>,
+    token_account: &'b AccountInfo<'a>,
+    launched_asset: &'b AccountInfo<'a>,
+    // Launch index and bump of a deterministic mint
+    deterministic_mint: Option<(u64, u8)>,
+    system_program: &'b AccountInfo<'a>,
+    token_program: &'b AccountInfo<'a>,
+    global_config: &'b AccountInfo<'a>,
+    creator_profile: &'b AccountInfo<'a>,
+    launch_index: &'b AccountInfo<'a>,
+    symbol_record: &'b AccountInfo<'a>,
+    creator: &'b AccountInfo<'a>,
+}
+
+impl<'a, 'b> LaunchAssetAccounts<'a, 'b> {
+    fn parse(program_id: &Pubkey, accounts: &'b [AccountInfo<'a>], config: &LaunchConfig, bumps: &LaunchBumps) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let payer = next_account_info(accounts_iter)?;
+        let mint = next_account_info(accounts_iter)?;
+        let token_account = next_account_info(accounts_iter)?;
+        let launched_asset = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+        let token_program = next_account_info(accounts_iter)?;
+        let rent_sysvar = next_account_info(accounts_iter)?;
+        let global_config = next_account_info(accounts_iter)?;
+        let creator_profile = next_account_info(accounts_iter)?;
+        let launch_index = next_account_info(accounts_iter)?;
+        let symbol_record = next_account_info(accounts_iter)?;
+        let creator = next_account_info(accounts_iter)?;
+
+        check_signer(payer)?;
+        check_writable(payer)?;
+
+        // The record attributes the launch to its creator, so only the creator can vouch for it
+        check_signer(creator)?;
+        if !creator.key.eq(&config.creator) {
+            return Err(LaunchpadError::CreatorMismatch.into());
+        }
+
+        // A deterministic mint is signed for by the program, any other mint is a fresh keypair
+        let deterministic_mint = if config.deterministic_mint {
+            let index = next_launch_index(program_id, creator.key, creator_profile)?;
+            let (mint_pda, mint_bump) = get_deterministic_mint_pda(program_id, creator.key, index);
+            if !mint.key.eq(&mint_pda) {
+                msg!("Expected deterministic mint {} for launch {}", mint_pda, index);
+                return Err(LaunchpadError::InvalidPda.into());
+            }
+            Some((index, mint_bump))
+        } else {
+            check_signer(mint)?;
+            None
+        };
+        check_writable(mint)?;
+        if deterministic_mint.is_some() {
+            check_unallocated(mint)?;
+        } else {
+            check_empty(mint)?;
+        }
+
+        // The token account is only created when there is an initial supply, NFTs always have one
+        if config.total_supply > 0 || config.asset_type == AssetType::StandardNft {
+            check_signer(token_account)?;
+            check_writable(token_account)?;
+            check_empty(token_account)?;
+        }
+
+        let launched_asset_pda = create_canonical_program_address(
+            &[LAUNCHED_ASSET_SEED, mint.key.as_ref()],
+            bumps.launched_asset,
+            program_id,
+        )?;
+        if !launched_asset.key.eq(&launched_asset_pda) {
+            return Err(LaunchpadError::InvalidPda.into());
+        }
+        check_writable(launched_asset)?;
+        check_unallocated(launched_asset)?;
+
+        if !solana_program::system_program::check_id(system_program.key) {
+            return Err(LaunchpadError::IncorrectSystemProgram.into());
+        }
+
+        let token_program_matches = match config.asset_type {
+            AssetType::SplTokenLegacy => token_program.key.eq(&TOKEN_PROGRAM_ID),
+            AssetType::SplToken2022 => token_program.key.eq(&TOKEN_2022_PROGRAM_ID),
+            AssetType::StandardNft => token_program.key.eq(&TOKEN_PROGRAM_ID) || token_program.key.eq(&TOKEN_2022_PROGRAM_ID),
+        };
+        if !token_program_matches {
+            return Err(LaunchpadError::IncorrectTokenProgram.into());
+        }
+
+        if !sysvar::rent::check_id(rent_sysvar.key) {
+            return Err(LaunchpadError::IncorrectSysvar.into());
+        }
+
+        // Launchpad PDAs are either ours already or still unallocated, the loaders check their addresses
+        for account in [global_config, creator_profile, launch_index, symbol_record] {
+            check_writable(account)?;
+            if !account.owner.eq(program_id) && !solana_program::system_program::check_id(account.owner) {
+                return Err(LaunchpadError::InvalidAccountOwner.into());
+            }
+        }
+        check_unallocated(launch_index)?;
+
+        Ok(Self {
+            payer,
+            mint,
+            token_account,
+            launched_asset,
+            deterministic_mint,
+            system_program,
+            token_program,
+            global_config,
+            creator_profile,
+            launch_index,
+            symbol_record,
+            creator,
+        })
+    }
+}
+
+fn check_signer(account: &AccountInfo) -> ProgramResult {
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+fn check_writable(account: &AccountInfo) -> ProgramResult {
+    if !account.is_writable {
+        msg!("Account {} must be writable", account.key);
+        return Err(LaunchpadError::AccountNotWritable.into());
+    }
+    Ok(())
+}
+
+// Accounts created during the launch must not be funded or allocated yet
+fn check_empty(account: &AccountInfo) -> ProgramResult {
+    if account.lamports() > 0 || !account.data_is_empty() || !solana_program::system_program::check_id(account.owner) {
+        msg!("Account {} already exists", account.key);
+        return Err(LaunchpadError::AccountNotEmpty.into());
+    }
+    Ok(())
+}
+
+// Program derived addresses can be funded by anyone beforehand, only allocating them makes them exist
+fn check_unallocated(account: &AccountInfo) -> ProgramResult {
+    if !account.data_is_empty() || !solana_program::system_program::check_id(account.owner) {
+        msg!("Account {} already exists", account.key);
+        return Err(LaunchpadError::AccountNotEmpty.into());
+    }
+    Ok(())
+}
 
+/// Launch an asset, `bumps` are only checked against the accounts instead of searched for
 pub fn launch_asset(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    config: LaunchConfig
+    config: LaunchConfig,
+    bumps: LaunchBumps,
 ) -> ProgramResult {
     // Validate config
     validate_launch_config(&config)?;
 
-    let accounts_iter = &mut accounts.iter();
-    let payer = next_account_info(accounts_iter)?;
-    let mint_account = next_account_info(accounts_iter)?;
-    let token_account = next_account_info(accounts_iter)?;
-    let metadata_account = next_account_info(accounts_iter)?;
-    let system_program = next_account_info(accounts_iter)?;
-    let token_program = next_account_info(accounts_iter)?;
-    let rent= &Rent::get()?;
-
-    // Verify signer
-    if !payer.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
+    let LaunchAssetAccounts {
+        payer,
+        mint: mint_account,
+        token_account,
+        launched_asset: metadata_account,
+        deterministic_mint,
+        system_program,
+        token_program,
+        global_config: global_config_account,
+        creator_profile: creator_profile_account,
+        launch_index: launch_index_account,
+        symbol_record: symbol_record_account,
+        creator,
+    } = LaunchAssetAccounts::parse(program_id, accounts, &config, &bumps)?;
+
+    // Launches are free until the global config is initialized
+    if let Some(global_config) = load_global_config(program_id, global_config_account)? {
+        if !global_config.toggles.launches_enabled(&config.asset_type) {
+            return Err(LaunchpadError::AssetTypeDisabled.into());
+        }
+
+        let fee = global_config.launch_fees.fee(&config.asset_type);
+        if global_config.toggles.collect_fees && fee > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, global_config_account.key, fee),
+                &[
+                    payer.clone(),
+                    global_config_account.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+
+            verbose_msg!("Collected launch fee of {} lamports", fee);
+            LaunchpadEvent::LaunchFeeCollected { mint: *mint_account.key, payer: *payer.key, amount: fee }.emit()?;
+        }
     }
 
+    let symbol_record = load_symbol_record(program_id, &config.symbol, symbol_record_account)?;
+    validate_symbol_availability(&config, symbol_record.as_ref())?;
+
+    let mint_authority = create_canonical_program_address(
+        &[MINT_AUTHORITY_SEED, mint_account.key.as_ref()],
+        bumps.mint_authority,
+        program_id,
+    )?;
+
+    let mint_seed_parts = deterministic_mint.map(|(index, bump)| (index.to_le_bytes(), [bump]));
+    let mint_seeds: Vec<&[u8]> = match &mint_seed_parts {
+        Some((index, bump)) => vec![DETERMINISTIC_MINT_SEED, creator.key.as_ref(), index, bump],
+        None => Vec::new(),
+    };
+    let mint_signer_seeds: Vec<&[&[u8]]> = match deterministic_mint {
+        Some(_) => vec![&mint_seeds],
+        None => Vec::new(),
+    };
+
     match config.asset_type {
         AssetType::SplTokenLegacy => {
             launch_spl_token_legacy(
                 payer,
+                creator,
                 mint_account,
                 token_account,
                 system_program,
                 token_program,
-                rent,
                 &config,
+                &mint_authority,
+                &mint_signer_seeds,
             )?;
         }
         AssetType::SplToken2022 => {
             launch_spl_token_2022(
                 payer,
+                creator,
                 mint_account,
                 token_account,
                 system_program,
                 token_program,
-                rent,
                 &config,
+                &mint_authority,
+                &mint_signer_seeds,
             )?;
         }
         AssetType::StandardNft => {
             launch_standard_nft(
                 payer,
+                creator,
                 mint_account,
                 token_account,
                 system_program,
                 token_program,
-                rent,
                 &config,
+                &mint_signer_seeds,
             )?;
         }
     }
@@ -72,209 +275,272 @@ pub fn launch_asset(
         mint_account.key,
         metadata_account,
         system_program,
-        &rent,
-        &config
+        bumps.launched_asset,
+        &config,
+        bumps.mint_authority,
+    )?;
+
+    let launch_index = record_launch(
+        program_id,
+        payer,
+        &config.creator,
+        mint_account.key,
+        creator_profile_account,
+        launch_index_account,
+        system_program,
     )?;
 
-    msg!("Successfully launched {} asset: {}", format!("{:?}", config.asset_type), config.name);
+    if config.unique_symbol {
+        claim_symbol(
+            program_id,
+            payer,
+            &config.symbol,
+            mint_account.key,
+            symbol_record_account,
+            system_program,
+        )?;
+    }
+
+    verbose_msg!("Successfully launched {:?} asset: {}", config.asset_type, config.name);
+    LaunchpadEvent::AssetLaunched {
+        mint: *mint_account.key,
+        creator: *creator.key,
+        payer: *payer.key,
+        asset_type: config.asset_type,
+        launch_index,
+        total_supply: config.total_supply,
+        max_supply: config.max_supply,
+    }
+    .emit()?;
 
     Ok(())
 }
 
 /// Launch SPL Token (Legacy)
+#[allow(clippy::too_many_arguments)]
 fn launch_spl_token_legacy<'a>(
     payer: &AccountInfo<'a>,
+    creator: &AccountInfo<'a>,
     mint_account: &AccountInfo<'a>,
     token_account: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
-    rent: &Rent,
     config: &LaunchConfig,
+    mint_authority: &Pubkey,
+    mint_signer_seeds: &[&[&[u8]]],
 )  -> ProgramResult {
-    msg!("Launching SPL Token (Legacy): {}", config.name);
-
-    if !token_program.key.eq(&TOKEN_PROGRAM_ID) {
-        return Err(ProgramError::Custom(1));
-    }
+    verbose_msg!("Launching SPL Token (Legacy): {}", config.name);
 
     // Create and initialize mint account
     initialize_mint::process(
         payer,
-        payer,
+        creator.key,
+        creator.key,
         mint_account,
         system_program,
         token_program,
-        rent,
-        config.decimals
+        config.decimals,
+        &config.extensions,
+        mint_signer_seeds,
     )?;
 
     // Create and mint to token account if supply > 0
     if config.total_supply > 0 {
         create_and_mint_to_token_account(
             payer,
+            creator,
             mint_account,
             token_account,
-            rent,
             system_program,
             token_program,
             config.total_supply,
         )?;
     }
 
+    // Further minting goes through Emit and MintAdditional, which enforce the supply cap
+    set_authority::process(
+        mint_account,
+        creator,
+        token_program,
+        Some(mint_authority),
+    )?;
+
+    verbose_msg!("Mint authority transferred to {}", mint_authority);
+    LaunchpadEvent::MintAuthorityTransferred { mint: *mint_account.key, authority: *mint_authority }.emit()?;
+
     Ok(())
 }
 
 /// Launch SPL Token 2022
+#[allow(clippy::too_many_arguments)]
 fn launch_spl_token_2022<'a>(
     payer: &AccountInfo<'a>,
+    creator: &AccountInfo<'a>,
     mint_account: &AccountInfo<'a>,
     token_account: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
-    rent: &Rent,
     config: &LaunchConfig,
+    mint_authority: &Pubkey,
+    mint_signer_seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    msg!("Launching SPL Token 2022: {}", config.name);
+    verbose_msg!("Launching SPL Token 2022: {}", config.name);
 
-    if !token_program.key.eq(&TOKEN_2022_PROGRAM_ID) {
-        return Err(ProgramError::Custom(1));
-    }
-    
     // Create and initialize mint account
     initialize_mint::process(
         payer,
-        payer,
+        creator.key,
+        creator.key,
         mint_account,
         system_program,
         token_program,
-        rent,
-        config.decimals
+        config.decimals,
+        &config.extensions,
+        mint_signer_seeds,
     )?;
 
     // Create and mint to token account if supply > 0
     if config.total_supply > 0 {
         create_and_mint_to_token_account(
             payer,
+            creator,
             mint_account,
             token_account,
-            rent,
             system_program,
             token_program,
             config.total_supply,
         )?;
     }
+
+    // Further minting goes through Emit and MintAdditional, which enforce the supply cap
+    set_authority::process(
+        mint_account,
+        creator,
+        token_program,
+        Some(mint_authority),
+    )?;
+
+    verbose_msg!("Mint authority transferred to {}", mint_authority);
+    LaunchpadEvent::MintAuthorityTransferred { mint: *mint_account.key, authority: *mint_authority }.emit()?;
     Ok(())
 }
 
 /// Launch Standard NFT
+#[allow(clippy::too_many_arguments)]
 fn launch_standard_nft<'a>(
     payer: &AccountInfo<'a>,
+    creator: &AccountInfo<'a>,
     mint_account: &AccountInfo<'a>,
     token_account: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
-    rent: &Rent,
     config: &LaunchConfig,
+    mint_signer_seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    msg!("Launching Standard NFT: {} on {} program", config.name, token_program.key);
+    verbose_msg!("Launching Standard NFT: {} on {} program", config.name, token_program.key);
 
     // Create and initialize mint account
     initialize_mint::process(
         payer,
-        payer,
+        creator.key,
+        creator.key,
         mint_account,
         system_program,
         token_program,
-        rent,
         0 , // NFTs have 0 decimals
+        &[],
+        mint_signer_seeds,
     )?;
 
-    msg!("NFT Mint account initialized: {}", mint_account.key);
+    verbose_msg!("NFT Mint account initialized: {}", mint_account.key);
 
     // Create and mint nft to account
     create_and_mint_to_token_account(
         payer,
+        creator,
         mint_account,
         token_account,
-        rent,
         system_program,
         token_program,
         1, // NFTs have a supply of 1
     )?;
 
-    msg!("Minting NFT to token account: {}", token_account.key);
+    verbose_msg!("Minting NFT to token account: {}", token_account.key);
 
     set_authority::process(
         mint_account,
-        payer,
-        token_program
+        creator,
+        token_program,
+        None,
     )?;
 
-    msg!("Removed Mint Authority");
+    verbose_msg!("Removed Mint Authority");
+    LaunchpadEvent::MintAuthorityRevoked { mint: *mint_account.key }.emit()?;
 
     Ok(())
 }
 
 /// Helper function to create metadata account for asset
+#[allow(clippy::too_many_arguments)]
 fn create_launched_asset_account<'a>(
     program_id: &Pubkey,
     payer: &AccountInfo<'a>,
     mint: &Pubkey,
     metadata_account: &AccountInfo<'a>,
-    _system_program: &AccountInfo<'a>,
-    rent: &Rent,
+    system_program: &AccountInfo<'a>,
+    bump_seed: u8,
     config: &LaunchConfig,
+    mint_authority_bump: u8,
 ) -> ProgramResult {
-    let (launched_asset_pda, bump_seed) = Pubkey::find_program_address(
-        &[LAUNCHED_ASSET_SEED, mint.as_ref()],
-        program_id,
-    );
+    verbose_msg!("Creating launched asset account: {}", metadata_account.key);
 
-    // Ensure the PDA is not already initialized
-    if !metadata_account.key.eq(&launched_asset_pda) {
-        return Err(ProgramError::AccountAlreadyInitialized);
-    }
+    let clock = Clock::get()?;
 
-    msg!("Creating launched asset account: {}", launched_asset_pda);
-
-    let launched_asset = Asset {
-        asset_type: config.asset_type.clone(),
-        mint: *mint,
-        creator: config.creator,
-        name: config.name.clone(),
-        symbol: config.symbol.clone(),
-        total_supply: config.total_supply,
-        launch_timestamp: 0, // Use Clock sysvar in full impl
+    // NFTs are capped at their single token, the mint authority is revoked at launch
+    let max_supply = match config.asset_type {
+        AssetType::StandardNft => 1,
+        _ => config.max_supply,
     };
 
-    let serialized_data = launched_asset.try_to_vec()?;
-    let data_len = serialized_data.len();
-    let lamports = rent.minimum_balance(data_len);
-
-    invoke_signed(
-        &system_instruction::create_account(
-            payer.key,
-            &launched_asset_pda,
-            lamports,
-            data_len as u64,
-            program_id,
-        ),
-        &[
-            payer.clone(),
-            metadata_account.clone(),
-        ],
+    create_pda_account(
+        payer,
+        metadata_account,
+        system_program,
+        Asset::LEN,
+        program_id,
         &[&[LAUNCHED_ASSET_SEED, mint.as_ref(), &[bump_seed]]],
     )?;
 
+    // Lengths were validated with the config, which makes padding them infallible
+    let mut data = metadata_account.data.borrow_mut();
+    let launched_asset = Asset::init(&mut data)?;
+    launched_asset.asset_type = config.asset_type.clone() as u8;
+    launched_asset.creator = config.creator;
+    launched_asset.mint = *mint;
+    launched_asset.name = pad(&config.name).ok_or(ProgramError::InvalidArgument)?;
+    launched_asset.symbol = pad(&config.symbol).ok_or(ProgramError::InvalidArgument)?;
+    launched_asset.uri = pad(&config.metadata_uri).ok_or(ProgramError::InvalidArgument)?;
+    launched_asset.total_supply.set(config.total_supply);
+    launched_asset.circulating_supply.set(config.total_supply);
+    launched_asset.launch_timestamp.set(clock.unix_timestamp);
+    launched_asset.max_supply.set(max_supply);
+    if let Some(emission) = &config.emission {
+        launched_asset.emission_per_epoch.set(emission.amount_per_epoch);
+        launched_asset.emission_treasury = emission.treasury;
+    }
+    launched_asset.last_emission_epoch.set(clock.epoch);
+    launched_asset.mint_authority_bump = mint_authority_bump;
+
     Ok(())
 }
 
-/// Helper function to create token account and mint initial supply
+/// Helper function to create the creator's token account and mint the initial supply, `payer` funds the account
+#[allow(clippy::too_many_arguments)]
 fn create_and_mint_to_token_account<'a>(
     payer: &AccountInfo<'a>,
+    creator: &AccountInfo<'a>,
     mint: &AccountInfo<'a>,
     token_account: &AccountInfo<'a>,
-    rent: &Rent,
     system_program: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
     amount: u64,
@@ -284,23 +550,25 @@ fn create_and_mint_to_token_account<'a>(
         payer,
         token_account,
         mint,
+        creator.key,
         system_program,
         token_program,
-        rent
+        &[],
     )?;
 
-    msg!("Token account initialized: {}", token_account.key);
+    verbose_msg!("Token account initialized: {}", token_account.key);
 
     mint_to::process(
         token_program,
         mint,
         token_account,
-        payer,
+        creator,
         amount,
         &[],
+        &[],
     )?;
 
-    msg!("Minted {}", amount);
+    verbose_msg!("Minted {}", amount);
 
     Ok(())
 }
@@ -1,3 +1,18 @@
+pub mod admin;
+pub mod creator;
+pub mod distributor;
 pub mod launch_asset;
+pub mod presale;
+pub mod supply;
+pub mod symbol;
+pub mod voucher;
 
+pub use admin::{initialize_global_config, rotate_admin, set_paused, update_global_config, withdraw_fees};
+pub(crate) use admin::check_not_paused;
+pub use creator::update_creator_profile;
+pub use distributor::{claim, clawback, create_distributor};
 pub use launch_asset::launch_asset;
+pub use presale::{claim_presale_tokens, contribute, create_presale, refund, withdraw_presale};
+pub use supply::{burn_tracked, emit, mint_additional};
+pub use symbol::set_symbol_reserved;
+pub use voucher::{claim_with_voucher, reclaim_voucher_tokens, register_voucher_signer};
@@ -0,0 +1,499 @@
+use solana_program::{
+    account_info::{ next_account_info, AccountInfo }, clock::Clock, entrypoint::ProgramResult, msg, program::invoke, program_error::ProgramError, pubkey::Pubkey, system_instruction, sysvar::Sysvar
+};
+use crate::{
+    constants::{CONTRIBUTION_SEED, LAUNCHED_ASSET_SEED, PRESALE_SEED, PRESALE_VAULT_SEED, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID}, cpi::{initialize_token_account, transfer_checked}, error::LaunchpadError, event::LaunchpadEvent, state::{ Asset, Contribution, Presale }, util::{create_pda_account, get_mint_decimals}
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Open a presale and move the tokens for sale into the vault
+pub fn create_presale(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    soft_cap: u64,
+    tokens_for_sale: u64,
+    deadline: i64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let creator = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let launched_asset = next_account_info(accounts_iter)?;
+    let creator_token_account = next_account_info(accounts_iter)?;
+    let presale_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !token_program.key.eq(&TOKEN_PROGRAM_ID) && !token_program.key.eq(&TOKEN_2022_PROGRAM_ID) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    // Only tokens launched through this program can be sold, and only by their creator as there is one presale per mint
+    let (launched_asset_pda, _) = Pubkey::find_program_address(
+        &[LAUNCHED_ASSET_SEED, mint.key.as_ref()],
+        program_id,
+    );
+    if !launched_asset.key.eq(&launched_asset_pda) || !launched_asset.owner.eq(program_id) {
+        return Err(LaunchpadError::NotLaunchedAsset.into());
+    }
+    if !Asset::load(&launched_asset.data.borrow())?.creator.eq(creator.key) {
+        return Err(LaunchpadError::NotAssetCreator.into());
+    }
+
+    if deadline <= Clock::get()?.unix_timestamp {
+        return Err(LaunchpadError::InvalidDeadline.into());
+    }
+
+    if soft_cap == 0 || tokens_for_sale == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (presale_pda, presale_bump) = Pubkey::find_program_address(
+        &[PRESALE_SEED, mint.key.as_ref()],
+        program_id,
+    );
+    if !presale_account.key.eq(&presale_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    let (vault_pda, vault_bump) = Pubkey::find_program_address(
+        &[PRESALE_VAULT_SEED, presale_pda.as_ref()],
+        program_id,
+    );
+    if !vault.key.eq(&vault_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    msg!("Creating presale account: {}", presale_pda);
+
+    create_pda_account(
+        creator,
+        presale_account,
+        system_program,
+        Presale::LEN,
+        program_id,
+        &[&[PRESALE_SEED, mint.key.as_ref(), &[presale_bump]]],
+    )?;
+
+    // The vault is owned by the presale PDA so only this program can move the tokens
+    initialize_token_account::process(
+        creator,
+        vault,
+        mint,
+        &presale_pda,
+        system_program,
+        token_program,
+        &[&[PRESALE_VAULT_SEED, presale_pda.as_ref(), &[vault_bump]]],
+    )?;
+
+    transfer_checked::process(
+        token_program,
+        creator_token_account,
+        mint,
+        vault,
+        creator,
+        tokens_for_sale,
+        get_mint_decimals(mint)?,
+        &[],
+    )?;
+
+    let presale = Presale {
+        creator: *creator.key,
+        mint: *mint.key,
+        token_program: *token_program.key,
+        vault: vault_pda,
+        soft_cap,
+        tokens_for_sale,
+        deadline,
+        total_raised: 0,
+        claimed_raised: 0,
+        tokens_claimed: 0,
+        withdrawn: false,
+        bump: presale_bump,
+    };
+    presale.serialize(&mut &mut presale_account.data.borrow_mut()[..])?;
+
+    msg!("Presale opened for {} tokens until {}", tokens_for_sale, deadline);
+    LaunchpadEvent::PresaleCreated {
+        presale: *presale_account.key,
+        mint: *mint.key,
+        creator: *creator.key,
+        soft_cap,
+        tokens_for_sale,
+        deadline,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Escrow a SOL contribution in the presale account
+pub fn contribute(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let contributor = next_account_info(accounts_iter)?;
+    let presale_account = next_account_info(accounts_iter)?;
+    let contribution_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    if !contributor.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut presale = load_presale(program_id, presale_account)?;
+
+    if Clock::get()?.unix_timestamp >= presale.deadline {
+        return Err(LaunchpadError::PresaleEnded.into());
+    }
+
+    let (contribution_pda, contribution_bump) = Pubkey::find_program_address(
+        &[CONTRIBUTION_SEED, presale_account.key.as_ref(), contributor.key.as_ref()],
+        program_id,
+    );
+    if !contribution_account.key.eq(&contribution_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    let mut contribution = if contribution_account.data_is_empty() {
+        create_pda_account(
+            contributor,
+            contribution_account,
+            system_program,
+            Contribution::LEN,
+            program_id,
+            &[&[
+                CONTRIBUTION_SEED,
+                presale_account.key.as_ref(),
+                contributor.key.as_ref(),
+                &[contribution_bump],
+            ]],
+        )?;
+
+        Contribution {
+            presale: *presale_account.key,
+            contributor: *contributor.key,
+            amount: 0,
+            settled: false,
+            bump: contribution_bump,
+        }
+    } else {
+        load_contribution(program_id, contribution_account)?
+    };
+
+    invoke(
+        &system_instruction::transfer(contributor.key, presale_account.key, amount),
+        &[
+            contributor.clone(),
+            presale_account.clone(),
+            system_program.clone(),
+        ],
+    )?;
+
+    contribution.amount = contribution.amount
+        .checked_add(amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    presale.total_raised = presale.total_raised
+        .checked_add(amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    contribution.serialize(&mut &mut contribution_account.data.borrow_mut()[..])?;
+    presale.serialize(&mut &mut presale_account.data.borrow_mut()[..])?;
+
+    msg!("Contributed {} lamports, total raised {}", amount, presale.total_raised);
+    LaunchpadEvent::Contributed {
+        presale: *presale_account.key,
+        contributor: *contributor.key,
+        amount,
+        total_raised: presale.total_raised,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Pay out a contributor's pro rata token share after a successful presale
+pub fn claim_presale_tokens(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let contributor = next_account_info(accounts_iter)?;
+    let presale_account = next_account_info(accounts_iter)?;
+    let contribution_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let contributor_token_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !contributor.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut presale = load_presale(program_id, presale_account)?;
+    let mut contribution = load_contribution(program_id, contribution_account)?;
+    check_presale_accounts(&presale, vault, mint, token_program)?;
+    check_contribution(&contribution, presale_account, contributor)?;
+
+    if Clock::get()?.unix_timestamp < presale.deadline {
+        return Err(LaunchpadError::PresaleActive.into());
+    }
+
+    if !presale.soft_cap_reached() {
+        return Err(LaunchpadError::SoftCapNotReached.into());
+    }
+
+    let amount = presale
+        .token_share(contribution.amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    transfer_checked::process(
+        token_program,
+        vault,
+        mint,
+        contributor_token_account,
+        presale_account,
+        amount,
+        get_mint_decimals(mint)?,
+        &[&[PRESALE_SEED, presale.mint.as_ref(), &[presale.bump]]],
+    )?;
+
+    contribution.settled = true;
+    contribution.serialize(&mut &mut contribution_account.data.borrow_mut()[..])?;
+
+    presale.claimed_raised = presale.claimed_raised
+        .checked_add(contribution.amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    presale.tokens_claimed = presale.tokens_claimed
+        .checked_add(amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    presale.serialize(&mut &mut presale_account.data.borrow_mut()[..])?;
+
+    msg!("Claimed {} tokens", amount);
+    LaunchpadEvent::PresaleTokensClaimed { presale: *presale_account.key, contributor: *contributor.key, amount }.emit()?;
+
+    Ok(())
+}
+
+/// Settle the creator side of an ended presale
+pub fn withdraw_presale(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let creator = next_account_info(accounts_iter)?;
+    let presale_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let creator_token_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut presale = load_presale(program_id, presale_account)?;
+    check_presale_accounts(&presale, vault, mint, token_program)?;
+
+    if !creator.key.eq(&presale.creator) {
+        return Err(LaunchpadError::NotAssetCreator.into());
+    }
+
+    if Clock::get()?.unix_timestamp < presale.deadline {
+        return Err(LaunchpadError::PresaleActive.into());
+    }
+
+    // Shares are rounded down, so a successful presale may leave a few tokens behind once everyone has claimed
+    let remainder = match presale.unclaimed_remainder() {
+        Some(remainder) if presale.soft_cap_reached() && remainder > 0 => remainder,
+        _ => 0,
+    };
+
+    if presale.withdrawn && remainder == 0 {
+        return Err(LaunchpadError::AlreadyWithdrawn.into());
+    }
+
+    if presale.soft_cap_reached() {
+        // Raise succeeded, the creator takes the escrowed SOL
+        if !presale.withdrawn {
+            transfer_lamports(presale_account, creator, presale.total_raised)?;
+            msg!("Withdrew {} lamports", presale.total_raised);
+            LaunchpadEvent::PresaleWithdrawn { presale: *presale_account.key, lamports: presale.total_raised }.emit()?;
+        }
+
+        if remainder > 0 {
+            transfer_checked::process(
+                token_program,
+                vault,
+                mint,
+                creator_token_account,
+                presale_account,
+                remainder,
+                get_mint_decimals(mint)?,
+                &[&[PRESALE_SEED, presale.mint.as_ref(), &[presale.bump]]],
+            )?;
+            presale.tokens_claimed = presale.tokens_for_sale;
+            msg!("Returned {} unclaimed tokens to creator", remainder);
+            LaunchpadEvent::PresaleTokensReturned { presale: *presale_account.key, amount: remainder }.emit()?;
+        }
+    } else {
+        // Raise failed, the tokens for sale go back to the creator
+        transfer_checked::process(
+            token_program,
+            vault,
+            mint,
+            creator_token_account,
+            presale_account,
+            presale.tokens_for_sale,
+            get_mint_decimals(mint)?,
+            &[&[PRESALE_SEED, presale.mint.as_ref(), &[presale.bump]]],
+        )?;
+        msg!("Returned {} tokens to creator", presale.tokens_for_sale);
+        LaunchpadEvent::PresaleTokensReturned { presale: *presale_account.key, amount: presale.tokens_for_sale }.emit()?;
+    }
+
+    presale.withdrawn = true;
+    presale.serialize(&mut &mut presale_account.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+/// Return a contribution after a failed presale
+pub fn refund(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let contributor = next_account_info(accounts_iter)?;
+    let presale_account = next_account_info(accounts_iter)?;
+    let contribution_account = next_account_info(accounts_iter)?;
+
+    if !contributor.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let presale = load_presale(program_id, presale_account)?;
+    let mut contribution = load_contribution(program_id, contribution_account)?;
+    check_contribution(&contribution, presale_account, contributor)?;
+
+    if Clock::get()?.unix_timestamp < presale.deadline {
+        return Err(LaunchpadError::PresaleActive.into());
+    }
+
+    if presale.soft_cap_reached() {
+        return Err(LaunchpadError::SoftCapReached.into());
+    }
+
+    transfer_lamports(presale_account, contributor, contribution.amount)?;
+
+    contribution.settled = true;
+    contribution.serialize(&mut &mut contribution_account.data.borrow_mut()[..])?;
+
+    msg!("Refunded {} lamports", contribution.amount);
+    LaunchpadEvent::Refunded { presale: *presale_account.key, contributor: *contributor.key, amount: contribution.amount }.emit()?;
+
+    Ok(())
+}
+
+/// Helper function to load a presale owned by this program
+fn load_presale(program_id: &Pubkey, presale_account: &AccountInfo) -> Result<Presale, ProgramError> {
+    if !presale_account.owner.eq(program_id) {
+        return Err(LaunchpadError::InvalidAccountOwner.into());
+    }
+
+    let presale = Presale::try_from_slice(&presale_account.data.borrow())?;
+
+    let presale_pda = Pubkey::create_program_address(
+        &[PRESALE_SEED, presale.mint.as_ref(), &[presale.bump]],
+        program_id,
+    )?;
+    if !presale_account.key.eq(&presale_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    Ok(presale)
+}
+
+/// Helper function to load a contribution owned by this program
+fn load_contribution(program_id: &Pubkey, contribution_account: &AccountInfo) -> Result<Contribution, ProgramError> {
+    if !contribution_account.owner.eq(program_id) {
+        return Err(LaunchpadError::InvalidAccountOwner.into());
+    }
+
+    let contribution = Contribution::try_from_slice(&contribution_account.data.borrow())?;
+
+    let contribution_pda = Pubkey::create_program_address(
+        &[CONTRIBUTION_SEED, contribution.presale.as_ref(), contribution.contributor.as_ref(), &[contribution.bump]],
+        program_id,
+    ).map_err(|_| LaunchpadError::InvalidPda)?;
+    if !contribution_account.key.eq(&contribution_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    Ok(contribution)
+}
+
+/// Helper function to check the token accounts passed alongside a presale
+fn check_presale_accounts(
+    presale: &Presale,
+    vault: &AccountInfo,
+    mint: &AccountInfo,
+    token_program: &AccountInfo,
+) -> ProgramResult {
+    if !vault.key.eq(&presale.vault) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if !mint.key.eq(&presale.mint) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !token_program.key.eq(&presale.token_program) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    Ok(())
+}
+
+/// Helper function to check a contribution belongs to the presale and signer
+fn check_contribution(
+    contribution: &Contribution,
+    presale_account: &AccountInfo,
+    contributor: &AccountInfo,
+) -> ProgramResult {
+    if !contribution.presale.eq(presale_account.key) || !contribution.contributor.eq(contributor.key) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if contribution.settled {
+        return Err(LaunchpadError::AlreadySettled.into());
+    }
+
+    Ok(())
+}
+
+/// Helper function to move escrowed lamports out of a program owned account
+fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> ProgramResult {
+    let from_balance = from.lamports()
+        .checked_sub(amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    let to_balance = to.lamports()
+        .checked_add(amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    **from.try_borrow_mut_lamports()? = from_balance;
+    **to.try_borrow_mut_lamports()? = to_balance;
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use solana_program::{
+    account_info::{ next_account_info, AccountInfo }, clock::Clock, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar
+};
+use crate::{
+    constants::{LAUNCHED_ASSET_SEED, MINT_AUTHORITY_SEED}, cpi::{burn, mint_to}, error::LaunchpadError, event::LaunchpadEvent, state::Asset
+};
+
+/// Mint the emission accrued since the last emitted epoch to the treasury
+pub fn emit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let launched_asset_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let treasury = next_account_info(accounts_iter)?;
+    let mint_authority = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    let mut data = launched_asset_account.data.borrow_mut();
+    let launched_asset = load_launched_asset(program_id, launched_asset_account, mint, &mut data)?;
+
+    let schedule = launched_asset
+        .emission()
+        .ok_or(LaunchpadError::NoEmissionSchedule)?;
+
+    if !treasury.key.eq(&schedule.treasury) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let epoch = Clock::get()?.epoch;
+    let accrued = launched_asset
+        .accrued_emission(epoch)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    // Emission stops at the cap, epochs past it are simply forfeited
+    let amount = accrued.min(launched_asset.mintable_supply());
+    if amount == 0 {
+        return Err(LaunchpadError::NothingToEmit.into());
+    }
+
+    mint_with_authority(program_id, launched_asset, mint, treasury, mint_authority, token_program, amount)?;
+
+    launched_asset.record_mint(amount).ok_or(LaunchpadError::MathOverflow)?;
+    launched_asset.last_emission_epoch.set(epoch);
+
+    msg!("Emitted {} tokens to treasury {}", amount, treasury.key);
+    LaunchpadEvent::Emitted { mint: *mint.key, treasury: *treasury.key, amount, epoch }.emit()?;
+
+    Ok(())
+}
+
+/// Let the creator mint extra tokens up to the supply cap
+pub fn mint_additional(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let creator = next_account_info(accounts_iter)?;
+    let launched_asset_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let destination = next_account_info(accounts_iter)?;
+    let mint_authority = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut data = launched_asset_account.data.borrow_mut();
+    let launched_asset = load_launched_asset(program_id, launched_asset_account, mint, &mut data)?;
+
+    if !creator.key.eq(&launched_asset.creator) {
+        return Err(LaunchpadError::NotAssetCreator.into());
+    }
+
+    if amount > launched_asset.mintable_supply() {
+        return Err(LaunchpadError::SupplyCapExceeded.into());
+    }
+
+    mint_with_authority(program_id, launched_asset, mint, destination, mint_authority, token_program, amount)?;
+
+    launched_asset.record_mint(amount).ok_or(LaunchpadError::MathOverflow)?;
+
+    msg!("Minted {} additional tokens to {}", amount, destination.key);
+    LaunchpadEvent::MintedAdditional { mint: *mint.key, destination: *destination.key, amount }.emit()?;
+
+    Ok(())
+}
+
+/// Burn the caller's tokens and keep the launched asset's supply accounting in step
+pub fn burn_tracked(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let owner = next_account_info(accounts_iter)?;
+    let launched_asset_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let token_account = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !owner.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut data = launched_asset_account.data.borrow_mut();
+    let launched_asset = load_launched_asset(program_id, launched_asset_account, mint, &mut data)?;
+
+    if !token_program.key.eq(mint.owner) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    burn::process(token_program, token_account, mint, owner, amount)?;
+
+    launched_asset.record_burn(amount).ok_or(LaunchpadError::MathOverflow)?;
+
+    msg!("Burned {} tokens, circulating supply {}", amount, launched_asset.circulating_supply.get());
+    LaunchpadEvent::Burned {
+        mint: *mint.key,
+        owner: *owner.key,
+        amount,
+        circulating_supply: launched_asset.circulating_supply.get(),
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Helper function to borrow the launched asset record of a mint from its account data
+fn load_launched_asset<'d>(
+    program_id: &Pubkey,
+    launched_asset_account: &AccountInfo,
+    mint: &AccountInfo,
+    data: &'d mut [u8],
+) -> Result<&'d mut Asset, ProgramError> {
+    if !launched_asset_account.owner.eq(program_id) {
+        return Err(LaunchpadError::InvalidAccountOwner.into());
+    }
+
+    let (launched_asset_pda, _) = Pubkey::find_program_address(
+        &[LAUNCHED_ASSET_SEED, mint.key.as_ref()],
+        program_id,
+    );
+    if !launched_asset_account.key.eq(&launched_asset_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    Asset::load_mut(data)
+}
+
+/// Helper function to mint through the mint authority PDA
+fn mint_with_authority<'a>(
+    program_id: &Pubkey,
+    launched_asset: &Asset,
+    mint: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    mint_authority: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    if !token_program.key.eq(mint.owner) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    let bump_seed = [launched_asset.mint_authority_bump];
+    let seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, launched_asset.mint.as_ref(), &bump_seed];
+
+    let mint_authority_pda = Pubkey::create_program_address(seeds, program_id)?;
+    if !mint_authority.key.eq(&mint_authority_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    mint_to::process(
+        token_program,
+        mint,
+        destination,
+        mint_authority,
+        amount,
+        &[],
+        &[seeds],
+    )
+}
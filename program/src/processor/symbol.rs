@@ -0,0 +1,135 @@
+use solana_program::{
+    account_info::{ next_account_info, AccountInfo }, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+use crate::{
+    constants::{MAX_SYMBOL_LENGTH, SYMBOL_RECORD_SEED}, error::LaunchpadError, event::LaunchpadEvent, processor::admin::load_global_config_as_admin, state::SymbolRecord, util::{create_pda_account, normalize_symbol}
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Add a symbol to the reserved denylist or take it off, restricted to the admin
+pub fn set_symbol_reserved(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    symbol: String,
+    reserved: bool,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let admin = next_account_info(accounts_iter)?;
+    let global_config_account = next_account_info(accounts_iter)?;
+    let symbol_record_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    load_global_config_as_admin(program_id, global_config_account, admin)?;
+
+    let normalized = normalize_symbol(&symbol);
+    if normalized.is_empty() || normalized.len() > MAX_SYMBOL_LENGTH {
+        msg!("Invalid symbol: {:?}", symbol);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut symbol_record = load_or_create_symbol_record(
+        program_id,
+        admin,
+        &normalized,
+        symbol_record_account,
+        system_program,
+    )?;
+
+    symbol_record.reserved = reserved;
+    symbol_record.serialize(&mut &mut symbol_record_account.data.borrow_mut()[..])?;
+
+    if reserved {
+        msg!("Symbol {} reserved", normalized);
+    } else {
+        msg!("Symbol {} released", normalized);
+    }
+    LaunchpadEvent::SymbolReservationChanged { symbol: normalized, reserved }.emit()?;
+
+    Ok(())
+}
+
+/// Helper function to load the registry entry of a launch's symbol, `None` while nobody claimed or reserved it
+pub(crate) fn load_symbol_record(
+    program_id: &Pubkey,
+    symbol: &str,
+    symbol_record_account: &AccountInfo,
+) -> Result<Option<SymbolRecord>, ProgramError> {
+    let (symbol_record_pda, _) = Pubkey::find_program_address(
+        &[SYMBOL_RECORD_SEED, normalize_symbol(symbol).as_bytes()],
+        program_id,
+    );
+    if !symbol_record_account.key.eq(&symbol_record_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if !symbol_record_account.owner.eq(program_id) {
+        return Ok(None);
+    }
+
+    // The account is sized for the longest symbol, so trailing bytes are expected
+    Ok(Some(SymbolRecord::deserialize(&mut &symbol_record_account.data.borrow()[..])?))
+}
+
+/// Record `mint` as the owner of its symbol, called by `launch_asset` for unique launches
+pub(crate) fn claim_symbol<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    symbol: &str,
+    mint: &Pubkey,
+    symbol_record_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let normalized = normalize_symbol(symbol);
+    let mut symbol_record = load_or_create_symbol_record(
+        program_id,
+        payer,
+        &normalized,
+        symbol_record_account,
+        system_program,
+    )?;
+
+    symbol_record.mint = *mint;
+    symbol_record.serialize(&mut &mut symbol_record_account.data.borrow_mut()[..])?;
+
+    verbose_msg!("Symbol {} claimed by {}", normalized, mint);
+    LaunchpadEvent::SymbolClaimed { symbol: normalized, mint: *mint }.emit()?;
+
+    Ok(())
+}
+
+/// Helper function to load a symbol record, `payer` funds it on first use
+fn load_or_create_symbol_record<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    normalized: &str,
+    symbol_record_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<SymbolRecord, ProgramError> {
+    if let Some(symbol_record) = load_symbol_record(program_id, normalized, symbol_record_account)? {
+        return Ok(symbol_record);
+    }
+
+    let (symbol_record_pda, bump) = Pubkey::find_program_address(
+        &[SYMBOL_RECORD_SEED, normalized.as_bytes()],
+        program_id,
+    );
+
+    verbose_msg!("Creating symbol record account: {}", symbol_record_pda);
+
+    create_pda_account(
+        payer,
+        symbol_record_account,
+        system_program,
+        SymbolRecord::LEN,
+        program_id,
+        &[&[SYMBOL_RECORD_SEED, normalized.as_bytes(), &[bump]]],
+    )?;
+
+    Ok(SymbolRecord {
+        symbol: normalized.to_string(),
+        mint: Pubkey::default(),
+        reserved: false,
+        bump,
+    })
+}
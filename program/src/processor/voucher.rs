@@ -0,0 +1,310 @@
+use solana_program::{
+    account_info::{ next_account_info, AccountInfo }, clock::Clock, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey, sysvar::{instructions, Sysvar}
+};
+use crate::{
+    constants::{LAUNCHED_ASSET_SEED, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, VOUCHER_CONFIG_SEED, VOUCHER_NONCE_SEED, VOUCHER_VAULT_SEED}, cpi::{initialize_token_account, transfer_checked}, ed25519::parse_ed25519_instruction, error::LaunchpadError, event::LaunchpadEvent, state::{ Asset, Voucher, VoucherConfig }, util::{create_pda_account, get_mint_decimals, get_token_account_amount}
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Register the voucher signer for a mint and escrow the voucher allocation
+pub fn register_voucher_signer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    voucher_signer: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let creator = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let launched_asset = next_account_info(accounts_iter)?;
+    let creator_token_account = next_account_info(accounts_iter)?;
+    let voucher_config_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !token_program.key.eq(&TOKEN_PROGRAM_ID) && !token_program.key.eq(&TOKEN_2022_PROGRAM_ID) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    // Only tokens launched through this program, and only by their creator as there is one voucher config per mint
+    let (launched_asset_pda, _) = Pubkey::find_program_address(
+        &[LAUNCHED_ASSET_SEED, mint.key.as_ref()],
+        program_id,
+    );
+    if !launched_asset.key.eq(&launched_asset_pda) || !launched_asset.owner.eq(program_id) {
+        return Err(LaunchpadError::NotLaunchedAsset.into());
+    }
+    if !Asset::load(&launched_asset.data.borrow())?.creator.eq(creator.key) {
+        return Err(LaunchpadError::NotAssetCreator.into());
+    }
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (voucher_config_pda, voucher_config_bump) = Pubkey::find_program_address(
+        &[VOUCHER_CONFIG_SEED, mint.key.as_ref()],
+        program_id,
+    );
+    if !voucher_config_account.key.eq(&voucher_config_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    let (vault_pda, vault_bump) = Pubkey::find_program_address(
+        &[VOUCHER_VAULT_SEED, voucher_config_pda.as_ref()],
+        program_id,
+    );
+    if !vault.key.eq(&vault_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    msg!("Creating voucher config account: {}", voucher_config_pda);
+
+    create_pda_account(
+        creator,
+        voucher_config_account,
+        system_program,
+        VoucherConfig::LEN,
+        program_id,
+        &[&[VOUCHER_CONFIG_SEED, mint.key.as_ref(), &[voucher_config_bump]]],
+    )?;
+
+    // The vault is owned by the voucher config PDA so only this program can move the tokens
+    initialize_token_account::process(
+        creator,
+        vault,
+        mint,
+        &voucher_config_pda,
+        system_program,
+        token_program,
+        &[&[VOUCHER_VAULT_SEED, voucher_config_pda.as_ref(), &[vault_bump]]],
+    )?;
+
+    transfer_checked::process(
+        token_program,
+        creator_token_account,
+        mint,
+        vault,
+        creator,
+        amount,
+        get_mint_decimals(mint)?,
+        &[],
+    )?;
+
+    let voucher_config = VoucherConfig {
+        creator: *creator.key,
+        mint: *mint.key,
+        token_program: *token_program.key,
+        vault: vault_pda,
+        voucher_signer,
+        reclaimed: false,
+        bump: voucher_config_bump,
+    };
+    voucher_config.serialize(&mut &mut voucher_config_account.data.borrow_mut()[..])?;
+
+    msg!("Registered voucher signer {} for {} tokens", voucher_signer, amount);
+    LaunchpadEvent::VoucherSignerRegistered {
+        voucher_config: *voucher_config_account.key,
+        mint: *mint.key,
+        voucher_signer,
+        amount,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Pay out a voucher signed by the registered signer
+pub fn claim_with_voucher(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    voucher: Voucher,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let claimant = next_account_info(accounts_iter)?;
+    let voucher_config_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let claimant_token_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let nonce_account = next_account_info(accounts_iter)?;
+    let instructions_sysvar = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !claimant.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let voucher_config = load_voucher_config(program_id, voucher_config_account)?;
+
+    if !vault.key.eq(&voucher_config.vault) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if !token_program.key.eq(&voucher_config.token_program) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    if voucher_config.reclaimed {
+        return Err(LaunchpadError::VoucherTokensReclaimed.into());
+    }
+
+    if !voucher.mint.eq(&voucher_config.mint) || !mint.key.eq(&voucher_config.mint) {
+        return Err(LaunchpadError::InvalidVoucher.into());
+    }
+
+    if !voucher.wallet.eq(claimant.key) || voucher.amount == 0 {
+        return Err(LaunchpadError::InvalidVoucher.into());
+    }
+
+    if Clock::get()?.unix_timestamp >= voucher.expiry {
+        return Err(LaunchpadError::VoucherExpired.into());
+    }
+
+    // The Ed25519 program must have verified this exact voucher in the previous instruction
+    let current_index = instructions::load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(LaunchpadError::VoucherSignatureMissing.into());
+    }
+    let signature_ix = instructions::load_instruction_at_checked(
+        current_index as usize - 1,
+        instructions_sysvar,
+    )?;
+    let (signer, message) = parse_ed25519_instruction(&signature_ix.program_id, &signature_ix.data)?;
+
+    if !signer.eq(&voucher_config.voucher_signer) || message != voucher.try_to_vec()? {
+        return Err(LaunchpadError::InvalidVoucher.into());
+    }
+
+    // Assigning the nonce account to this program marks the voucher as spent
+    let nonce_bytes = voucher.nonce.to_le_bytes();
+    let (nonce_pda, nonce_bump) = Pubkey::find_program_address(
+        &[VOUCHER_NONCE_SEED, voucher_config_account.key.as_ref(), &nonce_bytes],
+        program_id,
+    );
+    if !nonce_account.key.eq(&nonce_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    // Lamports alone don't spend it, anyone can send them to the nonce address ahead of the claim
+    if nonce_account.owner.eq(program_id) {
+        return Err(LaunchpadError::VoucherNonceUsed.into());
+    }
+
+    create_pda_account(
+        claimant,
+        nonce_account,
+        system_program,
+        0,
+        program_id,
+        &[&[VOUCHER_NONCE_SEED, voucher_config_account.key.as_ref(), &nonce_bytes, &[nonce_bump]]],
+    )?;
+
+    transfer_checked::process(
+        token_program,
+        vault,
+        mint,
+        claimant_token_account,
+        voucher_config_account,
+        voucher.amount,
+        get_mint_decimals(mint)?,
+        &[&[VOUCHER_CONFIG_SEED, voucher_config.mint.as_ref(), &[voucher_config.bump]]],
+    )?;
+
+    msg!("Claimed {} tokens with voucher nonce {}", voucher.amount, voucher.nonce);
+    LaunchpadEvent::VoucherClaimed {
+        voucher_config: *voucher_config_account.key,
+        claimant: *claimant.key,
+        nonce: voucher.nonce,
+        amount: voucher.amount,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Return the tokens left in the voucher vault to the creator, retiring the voucher signer
+pub fn reclaim_voucher_tokens(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let creator = next_account_info(accounts_iter)?;
+    let voucher_config_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let creator_token_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut voucher_config = load_voucher_config(program_id, voucher_config_account)?;
+
+    if !vault.key.eq(&voucher_config.vault) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    if !mint.key.eq(&voucher_config.mint) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !token_program.key.eq(&voucher_config.token_program) {
+        return Err(LaunchpadError::IncorrectTokenProgram.into());
+    }
+
+    if !creator.key.eq(&voucher_config.creator) {
+        return Err(LaunchpadError::NotAssetCreator.into());
+    }
+
+    if voucher_config.reclaimed {
+        return Err(LaunchpadError::VoucherTokensReclaimed.into());
+    }
+
+    let remaining = get_token_account_amount(vault)?;
+
+    transfer_checked::process(
+        token_program,
+        vault,
+        mint,
+        creator_token_account,
+        voucher_config_account,
+        remaining,
+        get_mint_decimals(mint)?,
+        &[&[VOUCHER_CONFIG_SEED, voucher_config.mint.as_ref(), &[voucher_config.bump]]],
+    )?;
+
+    voucher_config.reclaimed = true;
+    voucher_config.serialize(&mut &mut voucher_config_account.data.borrow_mut()[..])?;
+
+    msg!("Reclaimed {} unclaimed voucher tokens", remaining);
+    LaunchpadEvent::VoucherTokensReclaimed { voucher_config: *voucher_config_account.key, amount: remaining }.emit()?;
+
+    Ok(())
+}
+
+/// Helper function to load a voucher config owned by this program
+fn load_voucher_config(program_id: &Pubkey, voucher_config_account: &AccountInfo) -> Result<VoucherConfig, ProgramError> {
+    if !voucher_config_account.owner.eq(program_id) {
+        return Err(LaunchpadError::InvalidAccountOwner.into());
+    }
+
+    let voucher_config = VoucherConfig::try_from_slice(&voucher_config_account.data.borrow())?;
+
+    let voucher_config_pda = Pubkey::create_program_address(
+        &[VOUCHER_CONFIG_SEED, voucher_config.mint.as_ref(), &[voucher_config.bump]],
+        program_id,
+    )?;
+    if !voucher_config_account.key.eq(&voucher_config_pda) {
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    Ok(voucher_config)
+}
@@ -1,20 +1,367 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use bytemuck::{Pod, Zeroable};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::{MAX_NAME_LENGTH, MAX_SOCIALS_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH, MAX_WEBSITE_LENGTH},
+    extension::ExtensionType,
+    pod::{unpad, PodI64, PodU64},
+};
 
 // Instructions supported by the program
+// Every instruction with a pause class must pass the global config account, it's looked up by key
 #[derive(BorshDeserialize, BorshSerialize)]
 pub enum LaunchpadInstruction {
     /// Launch a new asset
     /// Accounts:
-    /// 0. /[signer] Payer account
-    /// 1. [writable] Mint account to be created
-    /// 2. [writable] Token account for initial supply (if applicable)
-    /// 3. [writable] Metadata account (if applicable)
+    /// 0. [signer, writable] Payer account, funds every new account and the launch fee
+    /// 1. [writable] Mint account to be created, a signer unless `deterministic_mint` makes it the PDA of (creator, launch index)
+    /// 2. [signer, writable] Token account for the initial supply, only created when there is one
+    /// 3. [writable] Launched asset account (PDA)
     /// 4. [] System program
     /// 5. [] Token program (legacy or 2022)
-    /// 6. [] Associated token program (if applicable)
+    /// 6. [] Rent sysvar
+    /// 7. [writable] Global config account (PDA), collects the launch fee once initialized
+    /// 8. [writable] Creator profile account (PDA), created on the creator's first launch
+    /// 9. [writable] Launch index account (PDA) for the creator's next launch
+    /// 10. [writable] Symbol record account (PDA) for the normalized symbol, claimed when `unique_symbol` is set
+    /// 11. [signer] Creator, must match `config.creator`
+    ///
+    /// The creator owns the initial supply and is the freeze authority, so a relayer may pay for the launch.
+    /// Fungible mints are handed to the mint authority PDA once the initial supply is minted.
+    /// `bumps` are the canonical bumps of the launched asset and mint authority PDAs, see `util::get_launch_bumps`
+    /// `instruction::launch_asset` builds it with the `client` feature
     LaunchAsset {
         config: LaunchConfig,
+        bumps: LaunchBumps,
+    },
+
+    /// Open a soft-cap presale of a launched token and escrow the tokens for sale
+    /// Accounts:
+    /// 0. [signer, writable] Creator account
+    /// 1. [] Mint of the token being sold
+    /// 2. [] Launched asset account (PDA)
+    /// 3. [writable] Creator token account holding the tokens for sale
+    /// 4. [writable] Presale account (PDA)
+    /// 5. [writable] Presale token vault (PDA)
+    /// 6. [] System program
+    /// 7. [] Token program (legacy or 2022)
+    /// 8. [] Global config account (PDA)
+    CreatePresale {
+        soft_cap: u64,
+        tokens_for_sale: u64,
+        deadline: i64,
+    },
+
+    /// Contribute SOL to a running presale
+    /// Accounts:
+    /// 0. [signer, writable] Contributor account
+    /// 1. [writable] Presale account (PDA)
+    /// 2. [writable] Contribution account (PDA)
+    /// 3. [] System program
+    /// 4. [] Global config account (PDA)
+    Contribute {
+        amount: u64,
+    },
+
+    /// Claim the pro rata share of tokens once the soft cap is reached
+    /// Accounts:
+    /// 0. [signer] Contributor account
+    /// 1. [writable] Presale account (PDA)
+    /// 2. [writable] Contribution account (PDA)
+    /// 3. [writable] Presale token vault (PDA)
+    /// 4. [writable] Contributor token account
+    /// 5. [] Mint
+    /// 6. [] Token program (legacy or 2022)
+    /// 7. [] Global config account (PDA)
+    ClaimPresaleTokens,
+
+    /// Withdraw the raised SOL, or take the tokens back if the soft cap was missed
+    /// Once every contributor has claimed, the rounding remainder of the tokens is swept back as well
+    /// Accounts:
+    /// 0. [signer, writable] Creator account
+    /// 1. [writable] Presale account (PDA)
+    /// 2. [writable] Presale token vault (PDA)
+    /// 3. [writable] Creator token account
+    /// 4. [] Mint
+    /// 5. [] Token program (legacy or 2022)
+    /// 6. [] Global config account (PDA)
+    WithdrawPresale,
+
+    /// Refund a contribution when the soft cap was missed
+    /// Accounts:
+    /// 0. [signer, writable] Contributor account
+    /// 1. [writable] Presale account (PDA)
+    /// 2. [writable] Contribution account (PDA)
+    /// 3. [] Global config account (PDA)
+    Refund,
+
+    /// Escrow an airdrop allocation of a launched token under a merkle root
+    /// Accounts:
+    /// 0. [signer, writable] Creator account
+    /// 1. [] Mint of the launched token
+    /// 2. [] Launched asset account (PDA)
+    /// 3. [writable] Creator token account holding the allocation
+    /// 4. [writable] Distributor account (PDA)
+    /// 5. [writable] Distributor token vault (PDA)
+    /// 6. [] System program
+    /// 7. [] Token program (legacy or 2022)
+    /// 8. [] Global config account (PDA)
+    CreateDistributor {
+        merkle_root: [u8; 32],
+        total_amount: u64,
+        num_leaves: u64,
+        expiry: i64,
+    },
+
+    /// Claim an airdrop entry by proving (index, wallet, amount) against the root
+    /// Accounts:
+    /// 0. [signer] Claimant account
+    /// 1. [writable] Distributor account (PDA)
+    /// 2. [writable] Distributor token vault (PDA)
+    /// 3. [writable] Claimant token account
+    /// 4. [] Mint
+    /// 5. [] Token program (legacy or 2022)
+    /// 6. [] Global config account (PDA)
+    Claim {
+        index: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    },
+
+    /// Return unclaimed tokens to the creator once the distributor has expired
+    /// Accounts:
+    /// 0. [signer] Creator account
+    /// 1. [writable] Distributor account (PDA)
+    /// 2. [writable] Distributor token vault (PDA)
+    /// 3. [writable] Creator token account
+    /// 4. [] Mint
+    /// 5. [] Token program (legacy or 2022)
+    /// 6. [] Global config account (PDA)
+    Clawback,
+
+    /// Register the key that signs claim vouchers of a launched token and escrow the tokens they pay out
+    /// Accounts:
+    /// 0. [signer, writable] Creator account
+    /// 1. [] Mint
+    /// 2. [] Launched asset account (PDA)
+    /// 3. [writable] Creator token account holding the voucher allocation
+    /// 4. [writable] Voucher config account (PDA)
+    /// 5. [writable] Voucher token vault (PDA)
+    /// 6. [] System program
+    /// 7. [] Token program (legacy or 2022)
+    /// 8. [] Global config account (PDA)
+    RegisterVoucherSigner {
+        voucher_signer: Pubkey,
+        amount: u64,
+    },
+
+    /// Claim tokens with a voucher verified by the preceding Ed25519 program instruction
+    /// Accounts:
+    /// 0. [signer, writable] Claimant account
+    /// 1. [] Voucher config account (PDA)
+    /// 2. [writable] Voucher token vault (PDA)
+    /// 3. [writable] Claimant token account
+    /// 4. [] Mint
+    /// 5. [writable] Voucher nonce account (PDA)
+    /// 6. [] Instructions sysvar
+    /// 7. [] System program
+    /// 8. [] Token program (legacy or 2022)
+    /// 9. [] Global config account (PDA)
+    ClaimWithVoucher {
+        voucher: Voucher,
+    },
+
+    /// Mint the emission accrued since the last emitted epoch to the treasury, anyone may call
+    /// Accounts:
+    /// 0. [writable] Launched asset account (PDA)
+    /// 1. [writable] Mint
+    /// 2. [writable] Treasury token account from the emission schedule
+    /// 3. [] Mint authority (PDA)
+    /// 4. [] Token program (legacy or 2022)
+    /// 5. [] Global config account (PDA)
+    Emit,
+
+    /// Mint extra tokens up to the supply cap
+    /// Accounts:
+    /// 0. [signer] Creator account
+    /// 1. [writable] Launched asset account (PDA)
+    /// 2. [writable] Mint
+    /// 3. [writable] Destination token account
+    /// 4. [] Mint authority (PDA)
+    /// 5. [] Token program (legacy or 2022)
+    /// 6. [] Global config account (PDA)
+    MintAdditional {
+        amount: u64,
+    },
+
+    /// Burn the caller's tokens and record it against the launched asset's supply
+    /// Accounts:
+    /// 0. [signer] Token account owner
+    /// 1. [writable] Launched asset account (PDA)
+    /// 2. [writable] Mint
+    /// 3. [writable] Token account to burn from
+    /// 4. [] Token program (legacy or 2022)
+    /// 5. [] Global config account (PDA)
+    BurnTracked {
+        amount: u64,
+    },
+
+    /// Create the global config, only the program's upgrade authority may call this
+    /// Accounts:
+    /// 0. [signer, writable] Upgrade authority
+    /// 1. [writable] Global config account (PDA)
+    /// 2. [] Program data account of this program
+    /// 3. [] System program
+    InitializeGlobalConfig {
+        admin: Pubkey,
+        guardian: Pubkey,
+        treasury: Pubkey,
+        launch_fees: LaunchFees,
+        toggles: FeatureToggles,
+    },
+
+    /// Change the launch fees, treasury, feature toggles or guardian, fields left as `None` are kept
+    /// Accounts:
+    /// 0. [signer] Admin
+    /// 1. [writable] Global config account (PDA)
+    UpdateGlobalConfig {
+        launch_fees: Option<LaunchFees>,
+        treasury: Option<Pubkey>,
+        toggles: Option<FeatureToggles>,
+        guardian: Option<Pubkey>,
+    },
+
+    /// Hand the admin role to another key
+    /// Accounts:
+    /// 0. [signer] Admin
+    /// 1. [writable] Global config account (PDA)
+    RotateAdmin {
+        new_admin: Pubkey,
+    },
+
+    /// Send collected launch fees to the treasury
+    /// Accounts:
+    /// 0. [signer] Admin
+    /// 1. [writable] Global config account (PDA)
+    /// 2. [writable] Treasury
+    WithdrawFees {
+        amount: u64,
+    },
+
+    /// Set the display info of the signer's creator profile, creating it if needed
+    /// Accounts:
+    /// 0. [signer, writable] Creator
+    /// 1. [writable] Creator profile account (PDA)
+    /// 2. [] System program
+    /// 3. [] Global config account (PDA)
+    UpdateCreatorProfile {
+        name: String,
+        website: String,
+        socials: String,
+    },
+
+    /// Stop every instruction of a class until it is unpaused
+    /// Accounts:
+    /// 0. [signer] Guardian
+    /// 1. [writable] Global config account (PDA)
+    Pause {
+        class: PauseClass,
+    },
+
+    /// Resume a paused instruction class
+    /// Accounts:
+    /// 0. [signer] Guardian
+    /// 1. [writable] Global config account (PDA)
+    Unpause {
+        class: PauseClass,
+    },
+
+    /// Add a symbol to the reserved denylist or remove it, launches of a reserved symbol fail
+    /// Accounts:
+    /// 0. [signer, writable] Admin, funds the symbol record if needed
+    /// 1. [] Global config account (PDA)
+    /// 2. [writable] Symbol record account (PDA)
+    /// 3. [] System program
+    SetSymbolReserved {
+        symbol: String,
+        reserved: bool,
+    },
+
+    /// Take back the tokens left in the voucher vault, no voucher can be claimed afterwards
+    /// Accounts:
+    /// 0. [signer] Creator account
+    /// 1. [writable] Voucher config account (PDA)
+    /// 2. [writable] Voucher token vault (PDA)
+    /// 3. [writable] Creator token account
+    /// 4. [] Mint
+    /// 5. [] Token program (legacy or 2022)
+    /// 6. [] Global config account (PDA)
+    ReclaimVoucherTokens,
+}
+
+impl LaunchpadInstruction {
+    /// Class an instruction is paused under, admin instructions can't be paused
+    pub fn pause_class(&self) -> Option<PauseClass> {
+        match self {
+            LaunchpadInstruction::LaunchAsset { .. } => Some(PauseClass::Launch),
+            LaunchpadInstruction::CreatePresale { .. }
+            | LaunchpadInstruction::Contribute { .. }
+            | LaunchpadInstruction::ClaimPresaleTokens
+            | LaunchpadInstruction::WithdrawPresale
+            | LaunchpadInstruction::Refund
+            | LaunchpadInstruction::CreateDistributor { .. }
+            | LaunchpadInstruction::Claim { .. }
+            | LaunchpadInstruction::Clawback
+            | LaunchpadInstruction::RegisterVoucherSigner { .. }
+            | LaunchpadInstruction::ClaimWithVoucher { .. }
+            | LaunchpadInstruction::ReclaimVoucherTokens => Some(PauseClass::Sale),
+            LaunchpadInstruction::Emit
+            | LaunchpadInstruction::MintAdditional { .. }
+            | LaunchpadInstruction::BurnTracked { .. }
+            | LaunchpadInstruction::UpdateCreatorProfile { .. } => Some(PauseClass::Update),
+            LaunchpadInstruction::InitializeGlobalConfig { .. }
+            | LaunchpadInstruction::UpdateGlobalConfig { .. }
+            | LaunchpadInstruction::RotateAdmin { .. }
+            | LaunchpadInstruction::WithdrawFees { .. }
+            | LaunchpadInstruction::Pause { .. }
+            | LaunchpadInstruction::Unpause { .. }
+            | LaunchpadInstruction::SetSymbolReserved { .. } => None,
+        }
+    }
+}
+
+// Groups of instructions the guardian can pause together
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum PauseClass {
+    Launch,
+    Sale,
+    Update,
+}
+
+// Which instruction classes are currently paused
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq)]
+pub struct PauseFlags {
+    pub launch: bool,
+    pub sale: bool,
+    pub update: bool,
+}
+
+impl PauseFlags {
+    pub fn is_paused(&self, class: PauseClass) -> bool {
+        match class {
+            PauseClass::Launch => self.launch,
+            PauseClass::Sale => self.sale,
+            PauseClass::Update => self.update,
+        }
+    }
+
+    pub fn set(&mut self, class: PauseClass, paused: bool) {
+        match class {
+            PauseClass::Launch => self.launch = paused,
+            PauseClass::Sale => self.sale = paused,
+            PauseClass::Update => self.update = paused,
+        }
     }
 }
 
@@ -26,6 +373,19 @@ pub enum AssetType {
     StandardNft,
 }
 
+impl TryFrom<u8> for AssetType {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AssetType::SplTokenLegacy),
+            1 => Ok(AssetType::SplToken2022),
+            2 => Ok(AssetType::StandardNft),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
 // Configuration
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct LaunchConfig {
@@ -36,17 +396,450 @@ pub struct LaunchConfig {
     pub total_supply: u64,
     pub metadata_uri: String,
     pub creator: Pubkey,
-    pub is_mutable: bool
+    pub is_mutable: bool,
+    pub max_supply: u64,
+    pub emission: Option<EmissionSchedule>,
+    pub unique_symbol: bool,
+    pub deterministic_mint: bool,
+    // Token-2022 extensions initialized on the mint, only SPL Token 2022 launches may have any
+    pub extensions: Vec<MintExtension>,
+}
+
+// Token-2022 mint extension a launch can enable, any authority it has goes to the creator
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub enum MintExtension {
+    TransferFee { basis_points: u16, maximum_fee: u64 },
+    MintCloseAuthority,
+    NonTransferable,
+    PermanentDelegate,
+    InterestBearing { rate: i16 },
+}
+
+impl MintExtension {
+    /// Extension type the mint carries once this is initialized
+    pub fn extension_type(&self) -> ExtensionType {
+        match self {
+            MintExtension::TransferFee { .. } => ExtensionType::TransferFeeConfig,
+            MintExtension::MintCloseAuthority => ExtensionType::MintCloseAuthority,
+            MintExtension::NonTransferable => ExtensionType::NonTransferable,
+            MintExtension::PermanentDelegate => ExtensionType::PermanentDelegate,
+            MintExtension::InterestBearing { .. } => ExtensionType::InterestBearingConfig,
+        }
+    }
+}
+
+// Bumps of the PDAs a launch creates, derived by the client so the program only has to check them
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct LaunchBumps {
+    pub launched_asset: u8,
+    pub mint_authority: u8,
 }
 
-// Program state to track assets
+// Launch fee in lamports for each asset type
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct LaunchFees {
+    pub spl_token_legacy: u64,
+    pub spl_token_2022: u64,
+    pub standard_nft: u64,
+}
+
+impl LaunchFees {
+    pub fn fee(&self, asset_type: &AssetType) -> u64 {
+        match asset_type {
+            AssetType::SplTokenLegacy => self.spl_token_legacy,
+            AssetType::SplToken2022 => self.spl_token_2022,
+            AssetType::StandardNft => self.standard_nft,
+        }
+    }
+}
+
+// Switches the admin can flip without redeploying
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct FeatureToggles {
+    pub collect_fees: bool,
+    pub spl_token_legacy_launches: bool,
+    pub spl_token_2022_launches: bool,
+    pub standard_nft_launches: bool,
+}
+
+impl FeatureToggles {
+    pub fn launches_enabled(&self, asset_type: &AssetType) -> bool {
+        match asset_type {
+            AssetType::SplTokenLegacy => self.spl_token_legacy_launches,
+            AssetType::SplToken2022 => self.spl_token_2022_launches,
+            AssetType::StandardNft => self.standard_nft_launches,
+        }
+    }
+}
+
+impl Default for FeatureToggles {
+    fn default() -> Self {
+        FeatureToggles {
+            collect_fees: true,
+            spl_token_legacy_launches: true,
+            spl_token_2022_launches: true,
+            standard_nft_launches: true,
+        }
+    }
+}
+
+// Program-wide settings, also holds the collected launch fees
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct GlobalConfig {
+    pub admin: Pubkey,
+    pub guardian: Pubkey,
+    pub treasury: Pubkey,
+    pub launch_fees: LaunchFees,
+    pub toggles: FeatureToggles,
+    pub paused: PauseFlags,
+    pub bump: u8,
+}
+
+impl GlobalConfig {
+    pub const LEN: usize = 32 * 3 + 8 * 3 + 4 + 3 + 1;
+}
+
+// Tokens minted to a treasury every epoch until the supply cap is reached
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct EmissionSchedule {
+    pub amount_per_epoch: u64,
+    pub treasury: Pubkey,
+}
+
+// Program state to track assets, a fixed layout read in place so off-chain filters can match fields at their offsets
+#[derive(Pod, Zeroable, Clone, Copy, Debug)]
+#[repr(C)]
 pub struct Asset {
-    pub asset_type: AssetType,
+    // Tells the record apart from other program accounts of the same size
+    pub discriminator: [u8; 8],
+    pub asset_type: u8,
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub name: [u8; MAX_NAME_LENGTH],
+    pub symbol: [u8; MAX_SYMBOL_LENGTH],
+    pub uri: [u8; MAX_URI_LENGTH],
+    pub total_supply: PodU64,
+    pub burned: PodU64,
+    pub circulating_supply: PodU64,
+    pub launch_timestamp: PodI64,
+    pub max_supply: PodU64,
+    // Zero without an emission schedule, launches can't schedule an empty emission
+    pub emission_per_epoch: PodU64,
+    pub emission_treasury: Pubkey,
+    pub last_emission_epoch: PodU64,
+    pub mint_authority_bump: u8,
+}
+
+impl Asset {
+    pub const LEN: usize = std::mem::size_of::<Asset>();
+    pub const DISCRIMINATOR: [u8; 8] = *b"LAUNCHED";
+    pub const DISCRIMINATOR_OFFSET: usize = std::mem::offset_of!(Asset, discriminator);
+    pub const ASSET_TYPE_OFFSET: usize = std::mem::offset_of!(Asset, asset_type);
+    pub const CREATOR_OFFSET: usize = std::mem::offset_of!(Asset, creator);
+    pub const MINT_OFFSET: usize = std::mem::offset_of!(Asset, mint);
+
+    /// Borrow the record in account data
+    pub fn load(data: &[u8]) -> Result<&Asset, ProgramError> {
+        let asset: &Asset = bytemuck::try_from_bytes(data).map_err(|_| ProgramError::InvalidAccountData)?;
+        if asset.discriminator != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(asset)
+    }
+
+    /// Mutably borrow the record in account data
+    pub fn load_mut(data: &mut [u8]) -> Result<&mut Asset, ProgramError> {
+        let asset: &mut Asset = bytemuck::try_from_bytes_mut(data).map_err(|_| ProgramError::InvalidAccountData)?;
+        if asset.discriminator != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(asset)
+    }
+
+    /// Tag freshly allocated account data as a record and borrow it
+    pub fn init(data: &mut [u8]) -> Result<&mut Asset, ProgramError> {
+        let asset: &mut Asset = bytemuck::try_from_bytes_mut(data).map_err(|_| ProgramError::InvalidAccountData)?;
+        asset.discriminator = Self::DISCRIMINATOR;
+        Ok(asset)
+    }
+
+    pub fn asset_type(&self) -> Result<AssetType, ProgramError> {
+        AssetType::try_from(self.asset_type)
+    }
+
+    pub fn name(&self) -> &str {
+        unpad(&self.name)
+    }
+
+    pub fn symbol(&self) -> &str {
+        unpad(&self.symbol)
+    }
+
+    pub fn uri(&self) -> &str {
+        unpad(&self.uri)
+    }
+
+    pub fn emission(&self) -> Option<EmissionSchedule> {
+        match self.emission_per_epoch.get() {
+            0 => None,
+            amount_per_epoch => Some(EmissionSchedule { amount_per_epoch, treasury: self.emission_treasury }),
+        }
+    }
+
+    /// Record newly minted tokens
+    pub fn record_mint(&mut self, amount: u64) -> Option<()> {
+        self.total_supply.set(self.total_supply.get().checked_add(amount)?);
+        self.circulating_supply.set(self.circulating_supply.get().checked_add(amount)?);
+        Some(())
+    }
+
+    /// Record tokens burned through the program
+    pub fn record_burn(&mut self, amount: u64) -> Option<()> {
+        self.burned.set(self.burned.get().checked_add(amount)?);
+        self.circulating_supply.set(self.circulating_supply.get().checked_sub(amount)?);
+        Some(())
+    }
+
+    /// Tokens that can still be minted without exceeding the cap, burns don't give room back
+    pub fn mintable_supply(&self) -> u64 {
+        self.max_supply.get().saturating_sub(self.total_supply.get())
+    }
+
+    /// Emission accrued between the last emitted epoch and `epoch`
+    pub fn accrued_emission(&self, epoch: u64) -> Option<u64> {
+        let schedule = self.emission()?;
+        schedule
+            .amount_per_epoch
+            .checked_mul(epoch.saturating_sub(self.last_emission_epoch.get()))
+    }
+}
+
+// Presale state, also the escrow holding contributed SOL
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Presale {
+    pub creator: Pubkey,
     pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub vault: Pubkey,
+    pub soft_cap: u64,
+    pub tokens_for_sale: u64,
+    pub deadline: i64,
+    pub total_raised: u64,
+    /// Lamports of the contributions whose tokens were claimed
+    pub claimed_raised: u64,
+    /// Tokens paid out of the vault, to contributors or swept back to the creator
+    pub tokens_claimed: u64,
+    pub withdrawn: bool,
+    pub bump: u8,
+}
+
+impl Presale {
+    pub const LEN: usize = 32 * 4 + 8 * 6 + 1 + 1;
+
+    /// Whether the presale raised enough to go ahead
+    pub fn soft_cap_reached(&self) -> bool {
+        self.total_raised >= self.soft_cap
+    }
+
+    /// Tokens owed to a contributor, pro rata to their share of the raise
+    pub fn token_share(&self, contribution: u64) -> Option<u64> {
+        if self.total_raised == 0 {
+            return Some(0);
+        }
+        let share = (self.tokens_for_sale as u128)
+            .checked_mul(contribution as u128)?
+            / self.total_raised as u128;
+        u64::try_from(share).ok()
+    }
+
+    /// Tokens left in the vault by rounding down the shares, `None` until every contributor has claimed
+    pub fn unclaimed_remainder(&self) -> Option<u64> {
+        if self.claimed_raised < self.total_raised {
+            return None;
+        }
+        self.tokens_for_sale.checked_sub(self.tokens_claimed)
+    }
+}
+
+// Per-contributor presale record
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Contribution {
+    pub presale: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    pub settled: bool,
+    pub bump: u8,
+}
+
+impl Contribution {
+    pub const LEN: usize = 32 * 2 + 8 + 1 + 1;
+}
+
+// Merkle airdrop distributor, with one claim bit per leaf
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Distributor {
     pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub vault: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub total_claimed: u64,
+    pub num_leaves: u64,
+    pub num_claimed: u64,
+    pub expiry: i64,
+    pub clawed_back: bool,
+    pub bump: u8,
+    pub claimed_bitmap: Vec<u8>,
+}
+
+impl Distributor {
+    /// Account size for a distributor with `num_leaves` entries
+    pub fn space(num_leaves: u64) -> usize {
+        32 * 5 + 8 * 5 + 1 + 1 + 4 + Self::bitmap_len(num_leaves)
+    }
+
+    pub fn bitmap_len(num_leaves: u64) -> usize {
+        num_leaves.div_ceil(8) as usize
+    }
+
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.claimed_bitmap[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u64) {
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    }
+}
+
+// Off-chain claim right, its Borsh encoding is the message the voucher signer signs
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct Voucher {
+    pub mint: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+// Voucher signer registered by a creator for a mint
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct VoucherConfig {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub vault: Pubkey,
+    pub voucher_signer: Pubkey,
+    pub reclaimed: bool,
+    pub bump: u8,
+}
+
+impl VoucherConfig {
+    pub const LEN: usize = 32 * 5 + 1 + 1;
+}
+
+// Per-creator registry, launch `n` is indexed by the launch index PDA `(creator, n)`
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default)]
+pub struct CreatorProfile {
+    pub creator: Pubkey,
+    pub launch_count: u64,
     pub name: String,
+    pub website: String,
+    pub socials: String,
+    pub bump: u8,
+}
+
+impl CreatorProfile {
+    // Sized for the longest display info, shorter values leave trailing zeroes
+    pub const LEN: usize = 32 + 8 + (4 + MAX_NAME_LENGTH) + (4 + MAX_WEBSITE_LENGTH) + (4 + MAX_SOCIALS_LENGTH) + 1;
+}
+
+// Points a creator's `n`th launch at its mint
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct LaunchIndex {
+    pub creator: Pubkey,
+    pub index: u64,
+    pub mint: Pubkey,
+    pub bump: u8,
+}
+
+impl LaunchIndex {
+    pub const LEN: usize = 32 * 2 + 8 + 1;
+}
+
+// Registry entry for a normalized symbol, either claimed by a unique launch or reserved by the admin
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SymbolRecord {
     pub symbol: String,
-    pub total_supply: u64,
-    pub launch_timestamp: i64,
+    pub mint: Pubkey,
+    pub reserved: bool,
+    pub bump: u8,
+}
+
+impl SymbolRecord {
+    pub const LEN: usize = (4 + MAX_SYMBOL_LENGTH) + 32 + 1 + 1;
+
+    /// Whether a unique launch already claimed the symbol
+    pub fn is_claimed(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Off-chain filters depend on these, moving a field is a breaking change for every reader
+    #[test]
+    fn test_asset_layout() {
+        assert_eq!(Asset::DISCRIMINATOR_OFFSET, 0);
+        assert_eq!(Asset::ASSET_TYPE_OFFSET, 8);
+        assert_eq!(Asset::CREATOR_OFFSET, 9);
+        assert_eq!(Asset::MINT_OFFSET, 41);
+        assert_eq!(Asset::LEN, 404);
+        assert_eq!(std::mem::align_of::<Asset>(), 1);
+    }
+
+    #[test]
+    fn test_asset_borrows_fields_in_place() {
+        let mut data = vec![0u8; Asset::LEN];
+        let creator = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        {
+            let asset = Asset::init(&mut data).unwrap();
+            asset.asset_type = AssetType::SplToken2022 as u8;
+            asset.creator = creator;
+            asset.name = crate::pod::pad("Test Token").unwrap();
+            asset.total_supply.set(1_000);
+            asset.burned.set(400);
+            asset.max_supply.set(5_000);
+            asset.emission_per_epoch.set(10);
+            asset.emission_treasury = treasury;
+        }
+
+        assert_eq!(data[..8], Asset::DISCRIMINATOR);
+        assert_eq!(data[Asset::ASSET_TYPE_OFFSET], 1);
+        assert_eq!(&data[Asset::CREATOR_OFFSET..Asset::CREATOR_OFFSET + 32], creator.as_ref());
+
+        let asset = Asset::load(&data).unwrap();
+        assert_eq!(asset.asset_type().unwrap(), AssetType::SplToken2022);
+        assert_eq!(asset.name(), "Test Token");
+        assert_eq!(asset.symbol(), "");
+        assert_eq!(asset.mintable_supply(), 4_000);
+        assert_eq!(asset.emission(), Some(EmissionSchedule { amount_per_epoch: 10, treasury }));
+        assert_eq!(asset.accrued_emission(3), Some(30));
+
+        assert!(Asset::load(&data[1..]).is_err());
+    }
+
+    // Without the tag a distributor of the same size would pass for a record
+    #[test]
+    fn test_asset_requires_discriminator() {
+        let mut data = vec![0u8; Asset::LEN];
+        assert!(Asset::load(&data).is_err());
+        assert!(Asset::load_mut(&mut data).is_err());
+
+        Asset::init(&mut data).unwrap();
+        assert!(Asset::load(&data).is_ok());
+    }
 }
@@ -1,6 +1,6 @@
-use solana_program::{entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, bpf_loader_upgradeable, entrypoint::ProgramResult, msg, program::{invoke, invoke_signed}, program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar};
 
-use crate::{constants::{LAUNCHED_ASSET_SEED, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH}, LaunchConfig};
+use crate::{constants::{CONTRIBUTION_SEED, CREATOR_PROFILE_SEED, DETERMINISTIC_MINT_SEED, LAUNCH_INDEX_SEED, DISTRIBUTOR_SEED, GLOBAL_CONFIG_SEED, DISTRIBUTOR_VAULT_SEED, LAUNCHED_ASSET_SEED, MAX_NAME_LENGTH, MINT_AUTHORITY_SEED, MAX_SYMBOL_LENGTH, MAX_TRANSFER_FEE_BASIS_POINTS, MAX_URI_LENGTH, PRESALE_SEED, PRESALE_VAULT_SEED, SYMBOL_RECORD_SEED, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, VOUCHER_CONFIG_SEED, VOUCHER_NONCE_SEED, VOUCHER_VAULT_SEED}, error::LaunchpadError, state::{LaunchBumps, MintExtension, SymbolRecord}, AssetType, LaunchConfig};
 
 /// Validate launch configuration
 pub fn validate_launch_config(config: &LaunchConfig) -> ProgramResult {
@@ -32,18 +32,275 @@ pub fn validate_launch_config(config: &LaunchConfig) -> ProgramResult {
         return Err(ProgramError::InvalidArgument);
     }
 
+    // The record keeps the config's supply, so an NFT launch has to describe the single token it mints
+    if config.asset_type == AssetType::StandardNft {
+        if config.decimals != 0 {
+            msg!("NFTs have 0 decimals: {}", config.decimals);
+            return Err(ProgramError::InvalidArgument);
+        }
+        if config.total_supply != 1 {
+            msg!("NFTs have a supply of 1: {}", config.total_supply);
+            return Err(ProgramError::InvalidArgument);
+        }
+        if config.emission.is_some() {
+            msg!("NFTs can't have an emission schedule");
+            return Err(ProgramError::InvalidArgument);
+        }
+    } else if config.max_supply < config.total_supply {
+        msg!(
+            "Max supply below initial supply: {} < {}",
+            config.max_supply,
+            config.total_supply
+        );
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Extensions are initialized through Token-2022, each one once
+    if !config.extensions.is_empty() && config.asset_type != AssetType::SplToken2022 {
+        msg!("Only SPL Token 2022 launches can enable extensions");
+        return Err(ProgramError::InvalidArgument);
+    }
+    for (i, extension) in config.extensions.iter().enumerate() {
+        if config.extensions[..i].iter().any(|other| other.extension_type() == extension.extension_type()) {
+            msg!("{:?} enabled twice", extension.extension_type());
+            return Err(ProgramError::InvalidArgument);
+        }
+        if let MintExtension::TransferFee { basis_points, .. } = extension {
+            if *basis_points > MAX_TRANSFER_FEE_BASIS_POINTS {
+                msg!("Transfer fee too high: {} > {} basis points", basis_points, MAX_TRANSFER_FEE_BASIS_POINTS);
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
+    }
+
+    if let Some(emission) = &config.emission {
+        if emission.amount_per_epoch == 0 {
+            msg!("Emission amount per epoch must be greater than 0");
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    if config.unique_symbol && normalize_symbol(&config.symbol).is_empty() {
+        msg!("A blank symbol can't be claimed");
+        return Err(ProgramError::InvalidArgument);
+    }
+
     Ok(())
 }
 
+/// Form a symbol is registered under, so "usdc " collides with "USDC"
+pub fn normalize_symbol(symbol: &str) -> String {
+    symbol.trim().to_ascii_uppercase()
+}
+
+/// Validate a launch against the registry entry for its normalized symbol, if there is one
+pub fn validate_symbol_availability(config: &LaunchConfig, record: Option<&SymbolRecord>) -> ProgramResult {
+    match record {
+        Some(record) if record.reserved => {
+            msg!("Symbol {} is reserved", record.symbol);
+            Err(LaunchpadError::SymbolReserved.into())
+        }
+        Some(record) if config.unique_symbol && record.is_claimed() => {
+            msg!("Symbol {} is already taken by {}", record.symbol, record.mint);
+            Err(LaunchpadError::SymbolTaken.into())
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Helper function to get launched asset PDA
 pub fn get_launched_asset_pda(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[LAUNCHED_ASSET_SEED, mint.as_ref()], program_id)
 }
 
+/// Helper function to get the bumps a launch of `mint` has to pass
+pub fn get_launch_bumps(program_id: &Pubkey, mint: &Pubkey) -> LaunchBumps {
+    LaunchBumps {
+        launched_asset: get_launched_asset_pda(program_id, mint).1,
+        mint_authority: get_mint_authority_pda(program_id, mint).1,
+    }
+}
+
+/// Helper function to get presale PDA
+pub fn get_presale_pda(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRESALE_SEED, mint.as_ref()], program_id)
+}
+
+/// Helper function to get the presale token vault PDA
+pub fn get_presale_vault_pda(program_id: &Pubkey, presale: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRESALE_VAULT_SEED, presale.as_ref()], program_id)
+}
+
+/// Helper function to get a contributor's presale contribution PDA
+pub fn get_contribution_pda(program_id: &Pubkey, presale: &Pubkey, contributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[CONTRIBUTION_SEED, presale.as_ref(), contributor.as_ref()],
+        program_id,
+    )
+}
+
+/// Helper function to get the airdrop distributor PDA
+pub fn get_distributor_pda(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DISTRIBUTOR_SEED, mint.as_ref()], program_id)
+}
+
+/// Helper function to get the distributor token vault PDA
+pub fn get_distributor_vault_pda(program_id: &Pubkey, distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DISTRIBUTOR_VAULT_SEED, distributor.as_ref()], program_id)
+}
+
+/// Helper function to get the voucher config PDA
+pub fn get_voucher_config_pda(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOUCHER_CONFIG_SEED, mint.as_ref()], program_id)
+}
+
+/// Helper function to get the voucher token vault PDA
+pub fn get_voucher_vault_pda(program_id: &Pubkey, voucher_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOUCHER_VAULT_SEED, voucher_config.as_ref()], program_id)
+}
+
+/// Helper function to get the PDA marking a voucher nonce as used
+pub fn get_voucher_nonce_pda(program_id: &Pubkey, voucher_config: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VOUCHER_NONCE_SEED, voucher_config.as_ref(), &nonce.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Helper function to get the global config PDA
+pub fn get_global_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED], program_id)
+}
+
+/// Helper function to get the program data account of an upgradeable program
+pub fn get_program_data_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0
+}
+
+/// Helper function to get the PDA holding mint authority over launched fungible tokens
+pub fn get_mint_authority_pda(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_AUTHORITY_SEED, mint.as_ref()], program_id)
+}
+
+/// Helper function to get the PDA holding a creator's profile and launch counter
+pub fn get_creator_profile_pda(program_id: &Pubkey, creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREATOR_PROFILE_SEED, creator.as_ref()], program_id)
+}
+
+/// Helper function to get the PDA pointing at a creator's `index`th launch
+pub fn get_launch_index_pda(program_id: &Pubkey, creator: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LAUNCH_INDEX_SEED, creator.as_ref(), &index.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Helper function to get the mint of a creator's `index`th launch when it uses a deterministic mint
+pub fn get_deterministic_mint_pda(program_id: &Pubkey, creator: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DETERMINISTIC_MINT_SEED, creator.as_ref(), &index.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Helper function to get the symbol registry PDA, seeded by the normalized symbol
+pub fn get_symbol_record_pda(program_id: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SYMBOL_RECORD_SEED, normalize_symbol(symbol).as_bytes()],
+        program_id,
+    )
+}
+
+/// Read the balance of a token account owned by either token program
+pub fn get_token_account_amount(token_account: &AccountInfo) -> Result<u64, ProgramError> {
+    if !token_account.owner.eq(&TOKEN_PROGRAM_ID) && !token_account.owner.eq(&TOKEN_2022_PROGRAM_ID) {
+        return Err(LaunchpadError::InvalidAccountOwner.into());
+    }
+
+    // Amount sits after the mint and owner (64 bytes)
+    let data = token_account.try_borrow_data()?;
+    data.get(64..72)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Read the decimals of a mint owned by either token program
+pub fn get_mint_decimals(mint: &AccountInfo) -> Result<u8, ProgramError> {
+    if !mint.owner.eq(&TOKEN_PROGRAM_ID) && !mint.owner.eq(&TOKEN_2022_PROGRAM_ID) {
+        return Err(LaunchpadError::InvalidAccountOwner.into());
+    }
+
+    // Decimals sit after the mint authority option (36 bytes) and supply (8 bytes)
+    let data = mint.try_borrow_data()?;
+    data.get(44).copied().ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Program address of `seeds` under a client-supplied `bump`, which has to be the canonical one
+/// Every other lookup derives the canonical address, so a record at any other bump would be unreachable
+pub(crate) fn create_canonical_program_address(seeds: &[&[u8]], bump: u8, program_id: &Pubkey) -> Result<Pubkey, ProgramError> {
+    let address_with_bump = |bump: u8| {
+        let bump_seed = [bump];
+        let mut seeds_with_bump = seeds.to_vec();
+        seeds_with_bump.push(&bump_seed);
+        Pubkey::create_program_address(&seeds_with_bump, program_id)
+    };
+
+    let address = address_with_bump(bump).map_err(|_| LaunchpadError::InvalidPda)?;
+
+    // The canonical bump is the highest one off the curve, so every bump above it must be on it
+    if let Some(higher) = (bump..=u8::MAX).skip(1).find(|higher| address_with_bump(*higher).is_ok()) {
+        msg!("Bump {} is not canonical, bump {} is off the curve too", bump, higher);
+        return Err(LaunchpadError::InvalidPda.into());
+    }
+
+    Ok(address)
+}
+
+/// Create an account at a program derived address owned by `owner`, `payer` funds it
+/// Anyone can send lamports to a predictable address first, which `create_account` refuses,
+/// so a funded address is topped up to rent exemption and then allocated and assigned
+pub(crate) fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let lamports = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(payer.key, account.key, lamports, space as u64, owner),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            signer_seeds,
+        );
+    }
+
+    let top_up = lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, top_up),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(account.key, space as u64),
+        &[account.clone(), system_program.clone()],
+        signer_seeds,
+    )?;
+    invoke_signed(
+        &system_instruction::assign(account.key, owner),
+        &[account.clone(), system_program.clone()],
+        signer_seeds,
+    )
+}
+
 #[cfg(test)]
 mod validation_tests {
     use super::*;
-    use crate::{LaunchConfig, state::AssetType};
+    use crate::{LaunchConfig, error::LaunchpadError, state::{AssetType, EmissionSchedule, MintExtension, SymbolRecord}};
     use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
     // Helper function to create a valid config for testing
@@ -57,6 +314,11 @@ mod validation_tests {
             metadata_uri: "https://example.com/metadata.json".to_string(),
             creator: Pubkey::new_unique(),
             is_mutable: false,
+            max_supply: 2_000_000,
+            emission: None,
+            unique_symbol: false,
+            deterministic_mint: false,
+            extensions: Vec::new(),
         }
     }
 
@@ -103,6 +365,58 @@ mod validation_tests {
         assert_eq!(result, Err(ProgramError::InvalidArgument));
     }
 
+    #[test]
+    fn test_validate_launch_config_nft_is_a_single_token() {
+        let mut config = create_valid_config();
+        config.asset_type = AssetType::StandardNft;
+        config.decimals = 0;
+        config.total_supply = 1;
+        assert!(validate_launch_config(&config).is_ok());
+
+        config.decimals = 6;
+        assert_eq!(validate_launch_config(&config), Err(ProgramError::InvalidArgument));
+
+        config.decimals = 0;
+        config.total_supply = 1_000_000;
+        assert_eq!(validate_launch_config(&config), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_validate_launch_config_extensions() {
+        let mut config = create_valid_config();
+        config.extensions = vec![MintExtension::MintCloseAuthority];
+        assert_eq!(validate_launch_config(&config), Err(ProgramError::InvalidArgument));
+
+        config.asset_type = AssetType::SplToken2022;
+        config.extensions.push(MintExtension::TransferFee { basis_points: MAX_TRANSFER_FEE_BASIS_POINTS, maximum_fee: 1 });
+        assert!(validate_launch_config(&config).is_ok());
+
+        config.extensions[1] = MintExtension::TransferFee { basis_points: MAX_TRANSFER_FEE_BASIS_POINTS + 1, maximum_fee: 1 };
+        assert_eq!(validate_launch_config(&config), Err(ProgramError::InvalidArgument));
+
+        // The same extension type can't be initialized twice, whatever its settings
+        config.extensions = vec![MintExtension::InterestBearing { rate: 1 }, MintExtension::InterestBearing { rate: 2 }];
+        assert_eq!(validate_launch_config(&config), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_validate_launch_config_max_supply_below_total_supply() {
+        let mut config = create_valid_config();
+        config.max_supply = config.total_supply - 1;
+
+        let result = validate_launch_config(&config);
+        assert_eq!(result, Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_validate_launch_config_zero_emission() {
+        let mut config = create_valid_config();
+        config.emission = Some(EmissionSchedule { amount_per_epoch: 0, treasury: Pubkey::new_unique() });
+
+        let result = validate_launch_config(&config);
+        assert_eq!(result, Err(ProgramError::InvalidArgument));
+    }
+
     #[test]
     fn test_get_launched_asset_pda_deterministic() {
         let program_id = Pubkey::new_unique();
@@ -129,4 +443,61 @@ mod validation_tests {
         // Different mints should produce different PDAs
         assert_ne!(pda1, pda2, "Different mints should produce different PDAs");
     }
-}
\ No newline at end of file
+
+    fn symbol_record(mint: Pubkey, reserved: bool) -> SymbolRecord {
+        SymbolRecord {
+            symbol: "TEST".to_string(),
+            mint,
+            reserved,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_normalize_symbol() {
+        assert_eq!(normalize_symbol(" usdc "), "USDC");
+        assert_eq!(normalize_symbol("UsDc"), normalize_symbol("usdc"));
+    }
+
+    #[test]
+    fn test_get_symbol_record_pda_ignores_case_and_whitespace() {
+        let program_id = Pubkey::new_unique();
+        assert_eq!(
+            get_symbol_record_pda(&program_id, "usdc ").0,
+            get_symbol_record_pda(&program_id, "USDC").0
+        );
+    }
+
+    #[test]
+    fn test_validate_symbol_availability() {
+        let mut config = create_valid_config();
+        let claimed = symbol_record(Pubkey::new_unique(), false);
+        let reserved = symbol_record(Pubkey::default(), true);
+
+        // Without uniqueness only reserved symbols are blocked
+        assert!(validate_symbol_availability(&config, None).is_ok());
+        assert!(validate_symbol_availability(&config, Some(&claimed)).is_ok());
+        assert_eq!(
+            validate_symbol_availability(&config, Some(&reserved)),
+            Err(LaunchpadError::SymbolReserved.into())
+        );
+
+        config.unique_symbol = true;
+        assert!(validate_symbol_availability(&config, None).is_ok());
+        assert!(validate_symbol_availability(&config, Some(&symbol_record(Pubkey::default(), false))).is_ok());
+        assert_eq!(
+            validate_symbol_availability(&config, Some(&claimed)),
+            Err(LaunchpadError::SymbolTaken.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_launch_config_blank_unique_symbol() {
+        let mut config = create_valid_config();
+        config.symbol = "  ".to_string();
+        assert!(validate_launch_config(&config).is_ok(), "Blank symbols are fine without uniqueness");
+
+        config.unique_symbol = true;
+        assert_eq!(validate_launch_config(&config), Err(ProgramError::InvalidArgument));
+    }
+}
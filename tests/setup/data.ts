@@ -5,6 +5,15 @@ export enum AssetType {
   StandardNft = 2,
 }
 
+export class EmissionSchedule {
+  amount_per_epoch: bigint;
+  treasury: Uint8Array;
+
+  constructor(fields: { amount_per_epoch: bigint; treasury: Uint8Array }) {
+    Object.assign(this, fields);
+  }
+}
+
 export class LaunchConfig {
   asset_type: number;
   name: string;
@@ -14,6 +23,12 @@ export class LaunchConfig {
   metadata_uri: string;
   creator: Uint8Array;
   is_mutable: number;
+  max_supply: bigint;
+  emission: EmissionSchedule | null;
+  unique_symbol: number;
+  deterministic_mint: number;
+  // Always empty here, an empty list encodes as its zero length whatever the element type
+  extensions: number[];
 
   constructor(fields: {
     asset_type: number;
@@ -24,6 +39,11 @@ export class LaunchConfig {
     metadata_uri: string;
     creator: Uint8Array;
     is_mutable: number;
+    max_supply: bigint;
+    emission: EmissionSchedule | null;
+    unique_symbol: number;
+    deterministic_mint: number;
+    extensions: number[];
   }) {
     Object.assign(this, fields);
   }
@@ -43,6 +63,21 @@ export const LaunchConfigSchema = new Map([
         ["metadata_uri", "string"],
         ["creator", [32]],
         ["is_mutable", "u8"],
+        ["max_supply", "u64"],
+        ["emission", { kind: "option", type: EmissionSchedule }],
+        ["unique_symbol", "u8"],
+        ["deterministic_mint", "u8"],
+        ["extensions", ["u8"]],
+      ],
+    },
+  ],
+  [
+    EmissionSchedule,
+    {
+      kind: "struct",
+      fields: [
+        ["amount_per_epoch", "u64"],
+        ["treasury", [32]],
       ],
     },
   ],
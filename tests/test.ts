@@ -10,7 +10,7 @@ import {
 import * as borsh from "borsh";
 import { loadKeypair, sendLaunchTransaction } from "./setup/utils";
 import { LaunchConfig, LaunchConfigSchema, AssetType } from "./setup/data";
-import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 // Test suite
 describe("launch_it", () => {
@@ -19,7 +19,75 @@ describe("launch_it", () => {
     const connection = new Connection("https://api.devnet.solana.com", "confirmed");
     const payer = loadKeypair(); // Use CLI wallet
     const programId = new PublicKey("4n6ByGTtLj4fTgLApV2aigC3XzWZhCmYkNbcfVheGzd8");
-    
+    const globalConfig = PublicKey.findProgramAddressSync([Buffer.from("global_config")], programId)[0];
+    const creatorProfile = PublicKey.findProgramAddressSync(
+      [Buffer.from("creator_profile"), payer.publicKey.toBuffer()],
+      programId
+    )[0];
+
+    // Symbol registry PDA, seeded by the trimmed and uppercased symbol
+    const symbolRecord = (symbol: string): PublicKey =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("symbol_record"), Buffer.from(symbol.trim().toUpperCase())],
+        programId
+      )[0];
+
+    // Launched asset PDA, seeded by the mint
+    const launchedAsset = (mint: PublicKey): PublicKey =>
+      PublicKey.findProgramAddressSync([Buffer.from("launched_asset"), mint.toBuffer()], programId)[0];
+
+    // Bumps of the launched asset and mint authority PDAs, passed after the config so the program doesn't search for them
+    const launchBumps = (mint: PublicKey): Buffer =>
+      Buffer.from([
+        PublicKey.findProgramAddressSync([Buffer.from("launched_asset"), mint.toBuffer()], programId)[1],
+        PublicKey.findProgramAddressSync([Buffer.from("mint_authority"), mint.toBuffer()], programId)[1],
+      ]);
+
+    // Launch index PDA for the payer's next launch, the count sits after the creator key in the profile
+    const nextLaunchIndex = async (): Promise<PublicKey> => {
+      const profile = await connection.getAccountInfo(creatorProfile);
+      const launchCount = profile ? profile.data.readBigUInt64LE(32) : BigInt(0);
+      const index = Buffer.alloc(8);
+      index.writeBigUInt64LE(launchCount);
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("launch_index"), payer.publicKey.toBuffer(), index],
+        programId
+      )[0];
+    };
+
+    // LaunchAsset instruction, keys in the order of `instruction::launch_asset` in the program crate
+    const launchInstruction = async (
+      config: LaunchConfig,
+      mint: PublicKey,
+      tokenAccount: PublicKey,
+      tokenProgram: PublicKey
+    ): Promise<TransactionInstruction> => {
+      // Serialize instruction data: [0, ...borsh(LaunchConfig), ...bumps]
+      const variant = Buffer.from([0]); // 0 = LaunchAsset
+      const configBuf = Buffer.from(borsh.serialize(LaunchConfigSchema, config));
+
+      const keys = [
+        { pubkey: payer.publicKey, isSigner: true, isWritable: true }, // payer
+        { pubkey: mint, isSigner: true, isWritable: true }, // mint
+        { pubkey: tokenAccount, isSigner: true, isWritable: true }, // token
+        { pubkey: launchedAsset(mint), isSigner: false, isWritable: true }, // launched asset
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system program
+        { pubkey: tokenProgram, isSigner: false, isWritable: false }, // token program
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false }, // rent sysvar
+        { pubkey: globalConfig, isSigner: false, isWritable: true }, // global config
+        { pubkey: creatorProfile, isSigner: false, isWritable: true }, // creator profile
+        { pubkey: await nextLaunchIndex(), isSigner: false, isWritable: true }, // launch index
+        { pubkey: symbolRecord(config.symbol), isSigner: false, isWritable: true }, // symbol record
+        { pubkey: payer.publicKey, isSigner: true, isWritable: false }, // creator
+      ];
+
+      return new TransactionInstruction({
+        keys,
+        programId,
+        data: Buffer.concat([variant, configBuf, launchBumps(mint)]),
+      });
+    };
+
     it("Launch SPL Token (Legacy)", async function () {
       this.timeout(15000);
       
@@ -33,46 +101,23 @@ describe("launch_it", () => {
         metadata_uri: "https://example.com/metadata.json",
         creator: payer.publicKey.toBytes(),
         is_mutable: 1,
+        max_supply: BigInt(1_000_000_000),
+        emission: null,
+        unique_symbol: 0,
+        deterministic_mint: 0,
+        extensions: [],
       });
 
-      // Serialize instruction data: [0, ...borsh(LaunchConfig)]
-      const variant = Buffer.from([0]); // 0 = LaunchAsset
-      const configBuf = Buffer.from(borsh.serialize(LaunchConfigSchema, config));
-      const instructionData = Buffer.concat([variant, configBuf]);
-
-      // Accounts required by the instruction (order must match Rust)
       const mintAccount = Keypair.generate();
       const tokenAccount = Keypair.generate();
-      const metadataAccount = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("launched_asset"),
-          Buffer.from(mintAccount.publicKey.toBuffer()),
-        ],
-        programId
-      )[0];
+      const metadataAccount = launchedAsset(mintAccount.publicKey);
 
       console.log("SPL Token (Legacy)");
       console.log("Mint Account:", mintAccount.publicKey.toBase58());
       console.log("Token Account:", tokenAccount.publicKey.toBase58());
       console.log("Metadata Account:", metadataAccount.toBase58());
 
-      const keys = [
-        { pubkey: payer.publicKey, isSigner: true, isWritable: true }, // payer
-        { pubkey: mintAccount.publicKey, isSigner: true, isWritable: true }, // mint
-        { pubkey: tokenAccount.publicKey, isSigner: true, isWritable: true }, // token
-        { pubkey: metadataAccount, isSigner: false, isWritable: true }, // metadata
-        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system program
-        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false }, // token program
-        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false }, // associated token program
-        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false }, // rent sysvar
-      ];
-
-      // Create instruction
-      const ix = new TransactionInstruction({
-        keys,
-        programId,
-        data: instructionData,
-      });
+      const ix = await launchInstruction(config, mintAccount.publicKey, tokenAccount.publicKey, TOKEN_PROGRAM_ID);
 
       await sendLaunchTransaction(
         connection,
@@ -95,46 +140,23 @@ describe("launch_it", () => {
         metadata_uri: "https://example.com/metadata.json",
         creator: payer.publicKey.toBytes(),
         is_mutable: 1,
+        max_supply: BigInt(1_000_000_000),
+        emission: null,
+        unique_symbol: 0,
+        deterministic_mint: 0,
+        extensions: [],
       });
 
-      // Serialize instruction data: [0, ...borsh(LaunchConfig)]
-      const variant = Buffer.from([0]); // 0 = LaunchAsset
-      const configBuf = Buffer.from(borsh.serialize(LaunchConfigSchema, config));
-      const instructionData = Buffer.concat([variant, configBuf]);
-
-      // Accounts required by the instruction (order must match Rust)
       const mintAccount = Keypair.generate();
       const tokenAccount = Keypair.generate();
-      const metadataAccount = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("launched_asset"),
-          Buffer.from(mintAccount.publicKey.toBuffer()),
-        ],
-        programId
-      )[0];
+      const metadataAccount = launchedAsset(mintAccount.publicKey);
 
       console.log("SPL Token 2022");
       console.log("Mint Account:", mintAccount.publicKey.toBase58());
       console.log("Token Account:", tokenAccount.publicKey.toBase58());
       console.log("Metadata Account:", metadataAccount.toBase58());
 
-      const keys = [
-        { pubkey: payer.publicKey, isSigner: true, isWritable: true }, // payer
-        { pubkey: mintAccount.publicKey, isSigner: true, isWritable: true }, // mint
-        { pubkey: tokenAccount.publicKey, isSigner: true, isWritable: true }, // token
-        { pubkey: metadataAccount, isSigner: false, isWritable: true }, // metadata
-        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system program
-        { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false }, // token program
-        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false }, // associated token program
-        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false }, // rent sysvar
-      ];
-
-      // Create instruction
-      const ix = new TransactionInstruction({
-        keys,
-        programId,
-        data: instructionData,
-      });
+      const ix = await launchInstruction(config, mintAccount.publicKey, tokenAccount.publicKey, TOKEN_2022_PROGRAM_ID);
 
       await sendLaunchTransaction(
         connection,
@@ -157,46 +179,23 @@ describe("launch_it", () => {
         metadata_uri: "https://example.com/metadata.json",
         creator: payer.publicKey.toBytes(),
         is_mutable: 1,
+        max_supply: BigInt(1),
+        emission: null,
+        unique_symbol: 0,
+        deterministic_mint: 0,
+        extensions: [],
       });
 
-      // Serialize instruction data: [0, ...borsh(LaunchConfig)]
-      const variant = Buffer.from([0]); // 0 = LaunchAsset
-      const configBuf = Buffer.from(borsh.serialize(LaunchConfigSchema, config));
-      const instructionData = Buffer.concat([variant, configBuf]);
-
-      // Accounts required by the instruction (order must match Rust)
       const mintAccount = Keypair.generate();
       const tokenAccount = Keypair.generate();
-      const metadataAccount = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("launched_asset"),
-          Buffer.from(mintAccount.publicKey.toBuffer()),
-        ],
-        programId
-      )[0];
+      const metadataAccount = launchedAsset(mintAccount.publicKey);
 
       console.log("Standard NFT");
       console.log("NFT Mint Account:", mintAccount.publicKey.toBase58());
       console.log("Token Account:", tokenAccount.publicKey.toBase58());
       console.log("Metadata Account:", metadataAccount.toBase58());
 
-      const keys = [
-        { pubkey: payer.publicKey, isSigner: true, isWritable: true }, // payer
-        { pubkey: mintAccount.publicKey, isSigner: true, isWritable: true }, // mint
-        { pubkey: tokenAccount.publicKey, isSigner: true, isWritable: true }, // token
-        { pubkey: metadataAccount, isSigner: false, isWritable: true }, // metadata
-        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system program
-        { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false }, // token program
-        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false }, // associated token program
-        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false }, // rent sysvar
-      ];
-
-      // Create instruction
-      const ix = new TransactionInstruction({
-        keys,
-        programId,
-        data: instructionData,
-      });
+      const ix = await launchInstruction(config, mintAccount.publicKey, tokenAccount.publicKey, TOKEN_2022_PROGRAM_ID);
 
       await sendLaunchTransaction(
         connection,